@@ -16,4 +16,5 @@ pub use self::presentation_validator::WasmPresentationValidator;
 pub use self::validation_options::WasmCredentialValidationOptions;
 pub use self::validation_options::WasmFailFast;
 pub use self::validation_options::WasmPresentationValidationOptions;
+pub use self::validation_options::WasmStatusCheck;
 pub use self::validation_options::WasmSubjectHolderRelationship;
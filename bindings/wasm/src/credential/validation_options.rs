@@ -6,6 +6,7 @@ use crate::error::WasmResult;
 use identity::iota::CredentialValidationOptions;
 use identity::iota::FailFast;
 use identity::iota::PresentationValidationOptions;
+use identity::iota::StatusCheck;
 use identity::iota::SubjectHolderRelationship;
 use serde_repr::Deserialize_repr;
 use serde_repr::Serialize_repr;
@@ -137,6 +138,32 @@ impl From<WasmSubjectHolderRelationship> for SubjectHolderRelationship {
   }
 }
 
+/// Declares how the `credentialStatus` of a credential is checked during validation.
+#[wasm_bindgen(js_name = StatusCheck)]
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum WasmStatusCheck {
+  /// Check the `RevocationBitmap2022` statuses against the issuer's DID Document and fail on any status of another
+  /// type.
+  Strict = 0,
+  /// Check the `RevocationBitmap2022` statuses against the issuer's DID Document and ignore statuses of other types.
+  /// This variant is the default used if no other variant is specified when constructing a new
+  /// `CredentialValidationOptions`.
+  SkipUnsupported = 1,
+  /// Do not check the status of the credential.
+  SkipAll = 2,
+}
+
+impl From<WasmStatusCheck> for StatusCheck {
+  fn from(status_check: WasmStatusCheck) -> Self {
+    match status_check {
+      WasmStatusCheck::Strict => Self::Strict,
+      WasmStatusCheck::SkipUnsupported => Self::SkipUnsupported,
+      WasmStatusCheck::SkipAll => Self::SkipAll,
+    }
+  }
+}
+
 // Interface to allow creating `CredentialValidationOptions` easily.
 #[wasm_bindgen]
 extern "C" {
@@ -162,6 +189,12 @@ interface ICredentialValidationOptions {
     /** Options which affect the verification of the signature on the credential. */
     readonly verifierOptions?: VerifierOptions;
 
+    /** Declare how the status of the credential is checked.
+     *
+     * Default: StatusCheck.SkipUnsupported
+     */
+    readonly status?: StatusCheck;
+
 }"#;
 
 #[wasm_bindgen(typescript_custom_section)]
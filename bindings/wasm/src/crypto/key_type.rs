@@ -11,6 +11,12 @@ pub enum WasmKeyType {
   Ed25519 = 1,
   #[serde(rename = "x25519")]
   X25519 = 2,
+  #[serde(rename = "secp256k1")]
+  Secp256k1 = 3,
+  #[serde(rename = "p256")]
+  P256 = 4,
+  #[serde(rename = "bls12381g2")]
+  Bls12381G2 = 5,
 }
 
 impl From<WasmKeyType> for KeyType {
@@ -18,6 +24,9 @@ impl From<WasmKeyType> for KeyType {
     match other {
       WasmKeyType::Ed25519 => KeyType::Ed25519,
       WasmKeyType::X25519 => KeyType::X25519,
+      WasmKeyType::Secp256k1 => KeyType::Secp256k1,
+      WasmKeyType::P256 => KeyType::P256,
+      WasmKeyType::Bls12381G2 => KeyType::Bls12381G2,
     }
   }
 }
@@ -27,6 +36,9 @@ impl From<KeyType> for WasmKeyType {
     match other {
       KeyType::Ed25519 => WasmKeyType::Ed25519,
       KeyType::X25519 => WasmKeyType::X25519,
+      KeyType::Secp256k1 => WasmKeyType::Secp256k1,
+      KeyType::P256 => WasmKeyType::P256,
+      KeyType::Bls12381G2 => WasmKeyType::Bls12381G2,
     }
   }
 }
@@ -24,6 +24,21 @@ impl WasmMethodType {
     WasmMethodType(MethodType::X25519KeyAgreementKey2019)
   }
 
+  #[wasm_bindgen(js_name = EcdsaSecp256k1VerificationKey2019)]
+  pub fn ecdsa_secp256k1_verification_key_2019() -> WasmMethodType {
+    WasmMethodType(MethodType::EcdsaSecp256k1VerificationKey2019)
+  }
+
+  #[wasm_bindgen(js_name = JsonWebKey2020)]
+  pub fn json_web_key_2020() -> WasmMethodType {
+    WasmMethodType(MethodType::JsonWebKey2020)
+  }
+
+  #[wasm_bindgen(js_name = Bls12381G2Key2020)]
+  pub fn bls12381_g2_key_2020() -> WasmMethodType {
+    WasmMethodType(MethodType::Bls12381G2Key2020)
+  }
+
   /// Serializes a `MethodType` object as a JSON object.
   #[wasm_bindgen(js_name = toJSON)]
   pub fn to_json(&self) -> Result<JsValue> {
@@ -12,6 +12,8 @@ use wasm_bindgen::prelude::*;
 pub enum WasmDIDMessageEncoding {
   Json = 0,
   JsonBrotli = 1,
+  Cbor = 2,
+  CborZstd = 3,
 }
 
 impl From<DIDMessageEncoding> for WasmDIDMessageEncoding {
@@ -19,6 +21,8 @@ impl From<DIDMessageEncoding> for WasmDIDMessageEncoding {
     match encoding {
       DIDMessageEncoding::Json => Self::Json,
       DIDMessageEncoding::JsonBrotli => Self::JsonBrotli,
+      DIDMessageEncoding::Cbor => Self::Cbor,
+      DIDMessageEncoding::CborZstd => Self::CborZstd,
     }
   }
 }
@@ -28,6 +32,8 @@ impl From<WasmDIDMessageEncoding> for DIDMessageEncoding {
     match encoding {
       WasmDIDMessageEncoding::Json => Self::Json,
       WasmDIDMessageEncoding::JsonBrotli => Self::JsonBrotli,
+      WasmDIDMessageEncoding::Cbor => Self::Cbor,
+      WasmDIDMessageEncoding::CborZstd => Self::CborZstd,
     }
   }
 }
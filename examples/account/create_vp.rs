@@ -29,6 +29,7 @@ use identity::iota::CredentialValidationOptions;
 use identity::iota::FailFast;
 use identity::iota::PresentationValidationOptions;
 
+use identity::iota::ResolvedIotaDocument;
 use identity::iota::Resolver;
 use identity::iota::SubjectHolderRelationship;
 
@@ -178,8 +179,8 @@ async fn main() -> Result<()> {
       &presentation,
       &presentation_validation_options,
       FailFast::FirstError,
-      None,
-      None,
+      None::<&ResolvedIotaDocument>,
+      None::<&[ResolvedIotaDocument]>,
     )
     .await?;
 
@@ -6,7 +6,7 @@
 use std::path::PathBuf;
 
 use identity::account::Account;
-use identity::account::AccountBuilder;
+use identity::account::AccountManager;
 use identity::account::IdentitySetup;
 use identity::account::MethodContent;
 use identity::account::Result;
@@ -26,60 +26,70 @@ async fn main() -> Result<()> {
   let password: String = "my-password".to_owned();
   let stronghold: Stronghold = Stronghold::new(&stronghold_path, password, None).await?;
 
-  // Create an AccountBuilder to make it easier to create multiple identities.
-  // Every account created from the builder will use the same storage - stronghold in this case.
-  let mut builder: AccountBuilder = Account::builder().storage(stronghold);
+  // Create an AccountManager to handle multiple identities.
+  // Every account managed by it uses the same storage - stronghold in this case.
+  let mut manager: AccountManager = Account::builder().storage(stronghold).build_manager().await?;
 
   // The creation step generates a keypair, builds an identity
   // and publishes it to the IOTA mainnet.
-  let account1: Account = builder.create_identity(IdentitySetup::default()).await?;
+  let iota_did1: IotaDID = manager
+    .create_identity(IdentitySetup::default())
+    .await?
+    .did()
+    .to_owned();
 
   // Create a second identity which uses the same storage.
-  let mut account2: Account = builder.create_identity(IdentitySetup::default()).await?;
+  let iota_did2: IotaDID = manager
+    .create_identity(IdentitySetup::default())
+    .await?
+    .did()
+    .to_owned();
 
-  // Retrieve the did of the identity that account1 manages.
-  let iota_did1: IotaDID = account1.did().to_owned();
+  // The manager lists all identities in the storage.
+  println!("[Example] Identities = {:?}", manager.dids().await?);
 
-  // Suppose we're done with account1 and drop it.
-  std::mem::drop(account1);
+  // Suppose we're done with the first identity and unload its account.
+  manager.unload(&iota_did1);
 
   // Now we want to modify the iota_did1 identity - how do we do that?
-  // We can load the identity from storage into an account using the builder.
-  let mut account1: Account = builder.load_identity(iota_did1).await?;
+  // The manager loads the identity from storage when it is accessed again.
+  // To modify identities concurrently, we can take their accounts out of the manager and spawn tasks.
+  // While an account is taken out, the manager refuses to load the same identity a second time.
+  let mut account1: Account = manager.take_account(&iota_did1).await?;
+  let mut account2: Account = manager.take_account(&iota_did2).await?;
 
-  let account1_did: IotaDID = account1.did().clone();
-
-  // Now we can make modifications to the identity.
-  // We can even do so concurrently by spawning tasks.
   let task1 = tokio::spawn(async move {
-    account1
+    let result: Result<()> = account1
       .update_identity()
       .create_method()
       .content(MethodContent::GenerateEd25519)
       .fragment("my-key")
       .apply()
-      .await
+      .await;
+    result.map(|_| account1)
   });
 
   let task2 = tokio::spawn(async move {
-    account2
+    let result: Result<()> = account2
       .update_identity()
       .create_method()
       .content(MethodContent::GenerateX25519)
       .fragment("my-other-key")
       .apply()
-      .await
+      .await;
+    result.map(|_| account2)
   });
 
-  task1.await.expect("task1 failed to execute to completion")?;
-  task2.await.expect("task2 failed to execute to completion")?;
+  // Hand the accounts back to the manager once the tasks are done.
+  manager.return_account(task1.await.expect("task1 failed to execute to completion")?)?;
+  manager.return_account(task2.await.expect("task2 failed to execute to completion")?)?;
 
   // Prints the Identity Resolver Explorer URL.
   // The entire history can be observed on this page by clicking "Loading History".
   let explorer: &ExplorerUrl = ExplorerUrl::mainnet();
   println!(
     "[Example] Explore the DID Document = {}",
-    explorer.resolver_url(&account1_did)?
+    explorer.resolver_url(&iota_did1)?
   );
 
   Ok(())
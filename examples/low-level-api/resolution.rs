@@ -11,6 +11,7 @@
 use identity::did::resolution;
 use identity::did::resolution::Dereference;
 use identity::did::resolution::InputMetadata;
+use identity::did::resolution::RepresentationResolution;
 use identity::did::resolution::Resolution;
 use identity::did::resolution::Resource;
 use identity::did::resolution::SecondaryResource;
@@ -53,6 +54,18 @@ async fn main() -> Result<()> {
       .map(CoreDID::from, |properties| properties)
   );
 
+  // Retrieve the published DID Document as a JSON-LD representation.
+  let input: InputMetadata = InputMetadata {
+    accept: Some(resolution::MIME_DID_LD.to_owned()),
+    ..Default::default()
+  };
+  let output: RepresentationResolution = resolution::resolve_representation(did_url, input, &client).await?;
+
+  println!(
+    "Representation > {}",
+    String::from_utf8_lossy(&output.document_stream.unwrap_or_default())
+  );
+
   // ===========================================================================
   // DID Dereferencing
   // ===========================================================================
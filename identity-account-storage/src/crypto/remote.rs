@@ -36,28 +36,77 @@ impl RemoteEd25519 {
   where
     U: Serialize + SetSignature,
   {
-    let signature: Proof = Proof::new_with_options(Self::NAME, method, options);
-    data.set_signature(signature);
+    create_remote_signature::<Self, U>(data, method, secret, options).await
+  }
 
-    let value: ProofValue = Self::sign(&data, secret).await?;
-    let write: &mut Proof = data.signature_mut().ok_or(Error::MissingSignature)?;
+  pub async fn sign<X>(data: &X, remote_key: &RemoteKey<'_>) -> Result<ProofValue>
+  where
+    X: Serialize,
+  {
+    remote_sign_jcs(data, remote_key).await
+  }
+}
+
+/// Creates `JcsEcdsaSecp256k1Signature2022` proofs with a secp256k1 key held by a storage implementation.
+pub struct RemoteEcdsaSecp256k1;
 
-    write.set_value(value);
+impl Named for RemoteEcdsaSecp256k1 {
+  const NAME: &'static str = "JcsEcdsaSecp256k1Signature2022";
+}
 
-    Ok(())
+impl RemoteEcdsaSecp256k1 {
+  pub async fn create_signature<U>(
+    data: &mut U,
+    method: impl Into<String>,
+    secret: &RemoteKey<'_>,
+    options: ProofOptions,
+  ) -> Result<()>
+  where
+    U: Serialize + SetSignature,
+  {
+    create_remote_signature::<Self, U>(data, method, secret, options).await
   }
 
   pub async fn sign<X>(data: &X, remote_key: &RemoteKey<'_>) -> Result<ProofValue>
   where
     X: Serialize,
   {
-    let message: Vec<u8> = data.to_jcs()?;
-    let signature: Vec<u8> = RemoteSign::sign(&message, remote_key).await?.into();
-    let signature: String = encode_b58(&signature);
-    Ok(ProofValue::Signature(signature))
+    remote_sign_jcs(data, remote_key).await
   }
 }
 
+async fn create_remote_signature<N, U>(
+  data: &mut U,
+  method: impl Into<String>,
+  secret: &RemoteKey<'_>,
+  options: ProofOptions,
+) -> Result<()>
+where
+  N: Named,
+  U: Serialize + SetSignature,
+{
+  let signature: Proof = Proof::new_with_options(N::NAME, method, options);
+  data.set_signature(signature);
+
+  let value: ProofValue = remote_sign_jcs(&data, secret).await?;
+  let write: &mut Proof = data.signature_mut().ok_or(Error::MissingSignature)?;
+
+  write.set_value(value);
+
+  Ok(())
+}
+
+// Signs the JCS canonicalization of `data` with the remote key.
+async fn remote_sign_jcs<X>(data: &X, remote_key: &RemoteKey<'_>) -> Result<ProofValue>
+where
+  X: Serialize,
+{
+  let message: Vec<u8> = data.to_jcs()?;
+  let signature: Vec<u8> = RemoteSign::sign(&message, remote_key).await?.into();
+  let signature: String = encode_b58(&signature);
+  Ok(ProofValue::Signature(signature))
+}
+
 /// A reference to a storage instance and identity key location.
 #[derive(Debug)]
 pub struct RemoteKey<'a> {
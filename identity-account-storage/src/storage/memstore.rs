@@ -12,7 +12,9 @@ use identity_core::crypto::KeyPair;
 use identity_core::crypto::KeyType;
 use identity_core::crypto::PrivateKey;
 use identity_core::crypto::PublicKey;
+use identity_core::crypto::Secp256k1;
 use identity_core::crypto::Sign;
+use identity_core::crypto::P256;
 use identity_iota_core::did::IotaDID;
 use identity_iota_core::document::IotaDocument;
 use identity_iota_core::tangle::NetworkName;
@@ -153,6 +155,33 @@ impl Storage for MemStore {
 
         vault.insert(location.to_owned(), keypair);
 
+        Ok(())
+      }
+      KeyType::Secp256k1 => {
+        let keypair: KeyPair = KeyPair::try_from_private_key_bytes(KeyType::Secp256k1, private_key.as_ref())
+          .map_err(|err| Error::InvalidPrivateKey(err.to_string()))?;
+        private_key.zeroize();
+
+        vault.insert(location.to_owned(), keypair);
+
+        Ok(())
+      }
+      KeyType::P256 => {
+        let keypair: KeyPair = KeyPair::try_from_private_key_bytes(KeyType::P256, private_key.as_ref())
+          .map_err(|err| Error::InvalidPrivateKey(err.to_string()))?;
+        private_key.zeroize();
+
+        vault.insert(location.to_owned(), keypair);
+
+        Ok(())
+      }
+      KeyType::Bls12381G2 => {
+        let keypair: KeyPair = KeyPair::try_from_private_key_bytes(KeyType::Bls12381G2, private_key.as_ref())
+          .map_err(|err| Error::InvalidPrivateKey(err.to_string()))?;
+        private_key.zeroize();
+
+        vault.insert(location.to_owned(), keypair);
+
         Ok(())
       }
     }
@@ -199,6 +228,24 @@ impl Storage for MemStore {
       KeyType::X25519 => {
         return Err(identity_did::Error::InvalidMethodType.into());
       }
+      KeyType::Bls12381G2 => {
+        // BBS+ signatures cover a list of messages rather than a single byte string.
+        return Err(identity_did::Error::InvalidMethodType.into());
+      }
+      KeyType::Secp256k1 => {
+        assert_eq!(keypair.type_(), KeyType::Secp256k1);
+
+        let signature: [u8; 64] = Secp256k1::sign(&data, keypair.private())?;
+        let signature: Signature = Signature::new(signature.to_vec());
+        Ok(signature)
+      }
+      KeyType::P256 => {
+        assert_eq!(keypair.type_(), KeyType::P256);
+
+        let signature: [u8; 64] = P256::sign(&data, keypair.private())?;
+        let signature: Signature = Signature::new(signature.to_vec());
+        Ok(signature)
+      }
     }
   }
 
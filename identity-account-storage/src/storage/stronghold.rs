@@ -209,6 +209,9 @@ impl Storage for Stronghold {
       KeyType::Ed25519 | KeyType::X25519 => {
         generate_private_key(&vault, &tmp_location).await?;
       }
+      KeyType::Secp256k1 | KeyType::P256 | KeyType::Bls12381G2 => {
+        return Err(identity_did::Error::InvalidMethodType.into())
+      }
     }
 
     let public_key: PublicKey = self.key_public(did, &tmp_location).await?;
@@ -220,6 +223,9 @@ impl Storage for Stronghold {
   }
 
   async fn key_insert(&self, did: &IotaDID, location: &KeyLocation, private_key: PrivateKey) -> Result<()> {
+    // Secp256k1, P-256 and BLS12-381 keys are not supported by the Stronghold procedures.
+    let _: procedures::KeyType = location_key_type(location)?;
+
     let vault: Vault<'_> = self.vault(did);
 
     let stronghold_location: Location = location.into();
@@ -236,6 +242,7 @@ impl Storage for Stronghold {
 
     match location.key_type {
       KeyType::Ed25519 | KeyType::X25519 => retrieve_public_key(&vault, location).await,
+      KeyType::Secp256k1 | KeyType::P256 | KeyType::Bls12381G2 => Err(identity_did::Error::InvalidMethodType.into()),
     }
   }
 
@@ -265,7 +272,9 @@ impl Storage for Stronghold {
 
     match location.key_type {
       KeyType::Ed25519 => sign_ed25519(&vault, data, location).await,
-      KeyType::X25519 => Err(identity_did::Error::InvalidMethodType.into()),
+      KeyType::X25519 | KeyType::Secp256k1 | KeyType::P256 | KeyType::Bls12381G2 => {
+        Err(identity_did::Error::InvalidMethodType.into())
+      }
     }
   }
 
@@ -337,7 +346,7 @@ impl Drop for Stronghold {
 
 async fn generate_private_key(vault: &Vault<'_>, location: &KeyLocation) -> Result<()> {
   let generate_key: procedures::GenerateKey = procedures::GenerateKey {
-    ty: location_key_type(location),
+    ty: location_key_type(location)?,
     output: location.into(),
     hint: default_hint(),
   };
@@ -349,7 +358,7 @@ async fn generate_private_key(vault: &Vault<'_>, location: &KeyLocation) -> Resu
 
 async fn retrieve_public_key(vault: &Vault<'_>, location: &KeyLocation) -> Result<PublicKey> {
   let procedure: procedures::PublicKey = procedures::PublicKey {
-    ty: location_key_type(location),
+    ty: location_key_type(location)?,
     private_key: location.into(),
   };
 
@@ -409,10 +418,11 @@ impl From<&KeyLocation> for Location {
   }
 }
 
-fn location_key_type(location: &KeyLocation) -> procedures::KeyType {
+fn location_key_type(location: &KeyLocation) -> Result<procedures::KeyType> {
   match location.key_type {
-    KeyType::Ed25519 => procedures::KeyType::Ed25519,
-    KeyType::X25519 => procedures::KeyType::X25519,
+    KeyType::Ed25519 => Ok(procedures::KeyType::Ed25519),
+    KeyType::X25519 => Ok(procedures::KeyType::X25519),
+    KeyType::Secp256k1 | KeyType::P256 | KeyType::Bls12381G2 => Err(identity_did::Error::InvalidMethodType.into()),
   }
 }
 
@@ -7,7 +7,6 @@ use core::fmt::Formatter;
 use core::fmt::Result;
 use identity_core::crypto::KeyType;
 use identity_did::verification::MethodData;
-use identity_did::verification::MethodType;
 use identity_iota_core::document::IotaVerificationMethod;
 use seahash::SeaHasher;
 use std::hash::Hash;
@@ -55,10 +54,7 @@ impl KeyLocation {
       .ok_or(crate::Error::DIDError(identity_did::Error::MissingIdFragment))?;
     let method_data: &MethodData = method.data();
 
-    let key_type: KeyType = match method.type_() {
-      MethodType::Ed25519VerificationKey2018 => KeyType::Ed25519,
-      MethodType::X25519KeyAgreementKey2019 => KeyType::X25519,
-    };
+    let key_type: KeyType = method.key_type()?;
 
     let public_key: Vec<u8> = method_data.try_decode()?;
 
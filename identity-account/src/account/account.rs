@@ -7,6 +7,7 @@ use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use identity_account_storage::crypto::RemoteEcdsaSecp256k1;
 use identity_account_storage::crypto::RemoteEd25519;
 use identity_account_storage::crypto::RemoteKey;
 use serde::Serialize;
@@ -14,9 +15,23 @@ use serde::Serialize;
 use identity_account_storage::identity::ChainState;
 use identity_account_storage::storage::Storage;
 use identity_account_storage::types::KeyLocation;
+use identity_core::common::Url;
+use identity_core::crypto::Ed25519;
 use identity_core::crypto::KeyType;
+use identity_core::crypto::Named;
+use identity_core::crypto::Proof;
 use identity_core::crypto::ProofOptions;
+use identity_core::crypto::ProofPurpose;
+use identity_core::crypto::ProofValue;
+use identity_core::crypto::Secp256k1;
 use identity_core::crypto::SetSignature;
+use identity_core::utils::encode_b58;
+use identity_credential::credential::Credential;
+use identity_credential::credential::CredentialBuilder;
+use identity_credential::presentation::Presentation;
+use identity_credential::presentation::PresentationBuilder;
+use identity_did::did::DID;
+use identity_did::verification::MethodScope;
 use identity_iota::chain::DocumentChain;
 use identity_iota::document::ResolvedIotaDocument;
 use identity_iota::tangle::Client;
@@ -31,16 +46,22 @@ use identity_iota_core::tangle::MessageId;
 use identity_iota_core::tangle::MessageIdExt;
 
 use crate::account::AccountBuilder;
+use crate::account::PublishMessage;
 use crate::account::PublishOptions;
+use crate::account::PublishPreview;
+use crate::account::Transaction;
 use crate::types::IdentitySetup;
 use crate::types::IdentityUpdater;
 use crate::updates::create_identity;
 use crate::updates::Update;
+use crate::updates::UpdateKeys;
 use crate::Error;
 use crate::Result;
 
 use super::config::AccountSetup;
 use super::config::AutoSave;
+use super::publish_preview::summarize_changes;
+use super::transaction::TransactionState;
 use super::AccountConfig;
 
 /// An account manages one identity.
@@ -58,6 +79,9 @@ where
   actions: AtomicUsize,
   chain_state: ChainState,
   document: IotaDocument,
+  transaction: Option<TransactionState>,
+  /// The keys of the methods removed by updates which are not published yet.
+  removed_keys: Vec<KeyLocation>,
 }
 
 impl<C> Account<C>
@@ -82,6 +106,8 @@ where
       actions: AtomicUsize::new(0),
       chain_state,
       document,
+      transaction: None,
+      removed_keys: Vec::new(),
     })
   }
 
@@ -208,6 +234,11 @@ where
     IdentityUpdater::new(self)
   }
 
+  /// Starts a [`Transaction`] to apply a batch of updates to this identity and publish them at once.
+  pub fn transaction(&mut self) -> Transaction<'_, C> {
+    Transaction::new(self)
+  }
+
   /// Overwrites the [`IotaDocument`] this account manages, **without doing any validation**.
   ///
   /// # WARNING
@@ -255,6 +286,71 @@ where
     Ok(())
   }
 
+  /// Issues a [`Credential`] built from the given `builder` and signs it with the verification
+  /// method identified by `fragment`.
+  ///
+  /// The issuer of the credential is set to the DID of the account and the signing method must be
+  /// an `assertionMethod` of the DID Document. The proof purpose is
+  /// [`ProofPurpose::AssertionMethod`]; a different purpose set in `options` is rejected.
+  pub async fn issue_credential<T>(
+    &self,
+    fragment: &str,
+    builder: CredentialBuilder<T>,
+    mut options: ProofOptions,
+  ) -> Result<Credential<T>>
+  where
+    T: Serialize,
+  {
+    let method: &IotaVerificationMethod = self.resolve_method_with_scope(fragment, MethodScope::assertion_method())?;
+
+    let mut credential: Credential<T> = builder.issuer(Url::parse(self.did().as_str())?).build()?;
+    enforce_purpose(&mut options, ProofPurpose::AssertionMethod)?;
+    self
+      .remote_sign_data(self.did(), method, &mut credential, options)
+      .await?;
+
+    Ok(credential)
+  }
+
+  /// Creates a [`Presentation`] built from the given `builder` and signs it with the verification
+  /// method identified by `fragment`.
+  ///
+  /// The holder of the presentation is set to the DID of the account and the signing method must be
+  /// an `authentication` method of the DID Document. The proof purpose is
+  /// [`ProofPurpose::Authentication`]; a different purpose set in `options` is rejected. The
+  /// `options` should contain the challenge and domain requested by the verifier.
+  pub async fn create_presentation<T, U>(
+    &self,
+    fragment: &str,
+    builder: PresentationBuilder<T, U>,
+    mut options: ProofOptions,
+  ) -> Result<Presentation<T, U>>
+  where
+    T: Serialize,
+    U: Serialize,
+  {
+    let method: &IotaVerificationMethod = self.resolve_method_with_scope(fragment, MethodScope::authentication())?;
+
+    let mut presentation: Presentation<T, U> = builder.holder(Url::parse(self.did().as_str())?).build()?;
+    enforce_purpose(&mut options, ProofPurpose::Authentication)?;
+    self
+      .remote_sign_data(self.did(), method, &mut presentation, options)
+      .await?;
+
+    Ok(presentation)
+  }
+
+  /// Returns whether the DID document has changes which have not been published to the Tangle.
+  pub async fn has_unpublished_changes(&self) -> Result<bool> {
+    if self.chain_state().is_new_identity() {
+      return Ok(true);
+    }
+
+    let old_doc: IotaDocument = self.load_document().await?;
+
+    Ok(&old_doc != self.document())
+  }
+
   /// Push all unpublished changes to the tangle in a single message.
   pub async fn publish(&mut self) -> Result<()> {
     self.publish_internal(true, PublishOptions::default()).await?;
@@ -272,6 +368,50 @@ where
     Ok(())
   }
 
+  /// Computes the message that [`Account::publish_with_options`] would publish to the Tangle, without
+  /// signing or publishing anything.
+  ///
+  /// Returns `None` if there are no unpublished changes.
+  pub async fn publish_preview(&self, options: PublishOptions) -> Result<Option<PublishPreview>> {
+    let (message, changes, signing_doc): (PublishMessage, Vec<String>, IotaDocument) =
+      if self.chain_state().is_new_identity() {
+        let changes: Vec<String> = vec![format!("create identity {}", self.did())];
+        (
+          PublishMessage::Integration(self.integration_document()),
+          changes,
+          self.document().to_owned(),
+        )
+      } else {
+        let old_doc: IotaDocument = self.load_document().await?;
+        let new_doc: &IotaDocument = self.document();
+
+        let message: PublishMessage = match self.publish_type(&old_doc, &options) {
+          Some(PublishType::Integration) => PublishMessage::Integration(self.integration_document()),
+          Some(PublishType::Diff) => {
+            PublishMessage::Diff(DiffMessage::new(&old_doc, new_doc, self.diff_previous_message_id())?)
+          }
+          None => return Ok(None),
+        };
+
+        let changes: Vec<String> = summarize_changes(&old_doc, new_doc);
+        (message, changes, old_doc)
+      };
+
+    // The size includes the proof added when publishing, with a placeholder signature.
+    let size: usize = match message.clone() {
+      PublishMessage::Integration(mut document) => {
+        set_placeholder_proof(&signing_doc, &options.sign_with, &mut document)?;
+        self.client.deref().message_size(&document)?
+      }
+      PublishMessage::Diff(mut diff) => {
+        set_placeholder_proof(&signing_doc, &options.sign_with, &mut diff)?;
+        self.client.deref().message_size(&diff)?
+      }
+    };
+
+    Ok(Some(PublishPreview { message, size, changes }))
+  }
+
   /// Fetches the latest document from the tangle and **overwrites** the local document.
   ///
   /// If a DID is managed from distributed accounts, this should be called before making changes
@@ -321,7 +461,21 @@ where
 
   pub(crate) async fn process_update(&mut self, update: Update) -> Result<()> {
     let did = self.did().to_owned();
-    update.process(&did, &mut self.document, self.storage.deref()).await?;
+
+    if let Some(transaction) = self.transaction.as_mut() {
+      // Apply the update to a copy, so a failing update leaves the draft unchanged.
+      let mut draft: IotaDocument = self.document.clone();
+      let keys: UpdateKeys = update.process(&did, &mut draft, self.storage.deref()).await?;
+
+      transaction.key_locations.extend(keys.added);
+      transaction.removed_key_locations.extend(keys.removed);
+      self.document = draft;
+
+      return Ok(());
+    }
+
+    let keys: UpdateKeys = update.process(&did, &mut self.document, self.storage.deref()).await?;
+    self.removed_keys.extend(keys.removed);
 
     self.increment_actions();
 
@@ -330,6 +484,88 @@ where
     Ok(())
   }
 
+  pub(crate) fn begin_transaction(&mut self) {
+    if self.transaction.is_none() {
+      self.transaction = Some(TransactionState {
+        document: self.document.clone(),
+        chain_state: self.chain_state.clone(),
+        key_locations: Vec::new(),
+        removed_key_locations: Vec::new(),
+      });
+    }
+  }
+
+  pub(crate) async fn commit_transaction(&mut self, options: PublishOptions) -> Result<()> {
+    let transaction: TransactionState = match self.transaction.take() {
+      Some(transaction) => transaction,
+      None => return Ok(()),
+    };
+
+    self.increment_actions();
+
+    // A transaction is always published, regardless of the autopublish configuration.
+    if let Err(error) = self.publish_internal(true, options).await {
+      // Publishing a message updates the chain state before the state is stored.
+      if self.chain_state == transaction.chain_state {
+        // Nothing was published, so the storage still holds the state before the transaction.
+        self.revert_transaction(transaction).await?;
+        return Err(error);
+      }
+
+      // The message was published, so the generated keys are in use and only storing failed.
+      self.store_state().await?;
+    }
+
+    // The removed methods are no longer part of the published document.
+    self.delete_keys(&transaction.removed_key_locations).await
+  }
+
+  pub(crate) async fn rollback_transaction(&mut self) -> Result<()> {
+    match self.transaction.take() {
+      Some(transaction) => self.revert_transaction(transaction).await,
+      None => Ok(()),
+    }
+  }
+
+  /// Discards the draft of an unfinished transaction, without removing the generated keys.
+  pub(crate) fn abort_transaction(&mut self) {
+    if let Some(transaction) = self.transaction.take() {
+      if !transaction.key_locations.is_empty() {
+        log::warn!(
+          "[abort_transaction] {} generated key(s) left in storage",
+          transaction.key_locations.len()
+        );
+      }
+
+      self.document = transaction.document;
+      self.chain_state = transaction.chain_state;
+    }
+  }
+
+  /// Restores the state before the `transaction` and deletes the keys it generated, attempting to
+  /// delete every key before returning the first error.
+  async fn revert_transaction(&mut self, transaction: TransactionState) -> Result<()> {
+    self.document = transaction.document;
+    self.chain_state = transaction.chain_state;
+
+    self.delete_keys(&transaction.key_locations).await
+  }
+
+  /// Deletes the keys at the given `locations` from the storage, attempting to delete every key
+  /// before returning the first error.
+  async fn delete_keys(&self, locations: &[KeyLocation]) -> Result<()> {
+    let mut result: Result<()> = Ok(());
+    for location in locations {
+      if let Err(error) = self.storage.key_delete(self.did(), location).await {
+        if result.is_ok() {
+          result = Err(error.into());
+        }
+      }
+    }
+
+    result
+  }
+
   async fn sign_self(
     &self,
     old_doc: &IotaDocument,
@@ -367,23 +603,8 @@ where
     } else {
       // Existing identity
       let old_doc: IotaDocument = self.load_document().await?;
-      let new_doc: &IotaDocument = self.document();
-
-      // NOTE: always publish an integration update (if needed); diff chain slated for removal.
-      let publish_type: Option<PublishType> = if options.force_integration_update {
-        Some(PublishType::Integration)
-      } else if let Some(publish_type) = PublishType::new(&old_doc, new_doc) {
-        if self.config.testmode {
-          // Allow tests to pass as normal.
-          Some(publish_type)
-        } else {
-          Some(PublishType::Integration)
-        }
-      } else {
-        None
-      };
 
-      match publish_type {
+      match self.publish_type(&old_doc, &options) {
         Some(PublishType::Integration) => {
           self
             .publish_integration_change(Some(&old_doc), &options.sign_with)
@@ -401,7 +622,49 @@ where
 
     self.store_state().await?;
 
-    Ok(())
+    // The keys of removed methods are only deleted once the removal is published.
+    let removed_keys: Vec<KeyLocation> = std::mem::take(&mut self.removed_keys);
+    self.delete_keys(&removed_keys).await
+  }
+
+  /// Determines how the changes from `old_doc` to the current document are published, if at all.
+  fn publish_type(&self, old_doc: &IotaDocument, options: &PublishOptions) -> Option<PublishType> {
+    // NOTE: always publish an integration update (if needed); diff chain slated for removal.
+    if options.force_integration_update {
+      Some(PublishType::Integration)
+    } else if let Some(publish_type) = PublishType::new(old_doc, self.document()) {
+      if self.config.testmode {
+        // Allow tests to pass as normal.
+        Some(publish_type)
+      } else {
+        Some(PublishType::Integration)
+      }
+    } else {
+      None
+    }
+  }
+
+  /// Returns the current document, unsigned, as it is published in an integration message.
+  fn integration_document(&self) -> IotaDocument {
+    let mut document: IotaDocument = self.document().to_owned();
+    document.metadata.previous_message_id = *self.chain_state().last_integration_message_id();
+    document
+  }
+
+  /// Returns the message id which a diff message published next refers to.
+  fn diff_previous_message_id(&self) -> MessageId {
+    let mut previous_message_id: &MessageId = self.chain_state().last_diff_message_id();
+
+    // If there was no previous diff message, use the previous int message.
+    if previous_message_id.is_null() {
+      if !self.chain_state.last_integration_message_id().is_null() {
+        previous_message_id = self.chain_state.last_integration_message_id();
+      } else {
+        // TODO: Return a fatal error about the invalid chain state.
+      }
+    }
+
+    *previous_message_id
   }
 
   async fn store_state(&self) -> Result<()> {
@@ -421,9 +684,7 @@ where
     log::debug!("[publish_integration_change] publishing {:?}", self.document().id());
 
     let new_doc_ref: &IotaDocument = self.document();
-    let mut new_doc: IotaDocument = new_doc_ref.to_owned();
-
-    new_doc.metadata.previous_message_id = *self.chain_state().last_integration_message_id();
+    let mut new_doc: IotaDocument = self.integration_document();
 
     self
       .sign_self(
@@ -457,18 +718,7 @@ where
 
     let new_doc: &IotaDocument = &self.document;
 
-    let mut previous_message_id: &MessageId = self.chain_state().last_diff_message_id();
-
-    // If there was no previous diff message, use the previous int message.
-    if previous_message_id.is_null() {
-      if !self.chain_state.last_integration_message_id().is_null() {
-        previous_message_id = self.chain_state.last_integration_message_id();
-      } else {
-        // TODO: Return a fatal error about the invalid chain state.
-      }
-    }
-
-    let mut diff: DiffMessage = DiffMessage::new(old_doc, new_doc, *previous_message_id)?;
+    let mut diff: DiffMessage = DiffMessage::new(old_doc, new_doc, self.diff_previous_message_id())?;
 
     let signing_method: &IotaVerificationMethod = match signing_method_query {
       Some(fragment) => old_doc.resolve_signing_method(fragment)?,
@@ -537,9 +787,64 @@ where
       KeyType::Ed25519 => {
         RemoteEd25519::create_signature(data, method_url.to_string(), &private, options).await?;
       }
-      KeyType::X25519 => return Err(identity_did::Error::InvalidMethodType.into()),
+      KeyType::Secp256k1 => {
+        RemoteEcdsaSecp256k1::create_signature(data, method_url.to_string(), &private, options).await?;
+      }
+      KeyType::X25519 | KeyType::P256 | KeyType::Bls12381G2 => {
+        return Err(identity_did::Error::InvalidMethodType.into())
+      }
     }
 
     Ok(())
   }
+
+  /// Resolves the verification method identified by `fragment`, which must have the given `scope`.
+  fn resolve_method_with_scope(&self, fragment: &str, scope: MethodScope) -> Result<&IotaVerificationMethod> {
+    match self.document().resolve_method(fragment, Some(scope)) {
+      Some(method) => Ok(method),
+      None if self.document().resolve_method(fragment, None).is_some() => Err(Error::InvalidMethodScope(scope)),
+      None => Err(Error::DIDError(identity_did::Error::MethodNotFound)),
+    }
+  }
+}
+
+/// Sets the proof `purpose` in `options`, rejecting a different purpose set by the caller.
+fn enforce_purpose(options: &mut ProofOptions, purpose: ProofPurpose) -> Result<()> {
+  match options.purpose {
+    Some(requested) if requested != purpose => Err(Error::InvalidProofPurpose(requested)),
+    _ => {
+      options.purpose = Some(purpose);
+      Ok(())
+    }
+  }
+}
+
+/// Sets the proof that signing `data` with the method of `signing_doc` selected by
+/// `signing_method_query` would add, with a placeholder signature of the maximum encoded length.
+fn set_placeholder_proof<D>(
+  signing_doc: &IotaDocument,
+  signing_method_query: &Option<String>,
+  data: &mut D,
+) -> Result<()>
+where
+  D: SetSignature,
+{
+  let signing_method: &IotaVerificationMethod = match signing_method_query {
+    Some(fragment) => signing_doc.resolve_signing_method(fragment)?,
+    None => signing_doc.default_signing_method()?,
+  };
+
+  let key_type: KeyType = KeyLocation::from_verification_method(signing_method)?.key_type;
+  let (proof_type, signature_length): (&str, usize) = match key_type {
+    KeyType::Ed25519 => (RemoteEd25519::NAME, Ed25519::SIGNATURE_LENGTH),
+    KeyType::Secp256k1 => (RemoteEcdsaSecp256k1::NAME, Secp256k1::SIGNATURE_LENGTH),
+    KeyType::X25519 | KeyType::P256 | KeyType::Bls12381G2 => return Err(identity_did::Error::InvalidMethodType.into()),
+  };
+
+  // The base58 encoding of a signature is longest if all of its bits are set.
+  let mut proof: Proof = Proof::new_with_options(proof_type, signing_method.id().to_string(), ProofOptions::default());
+  proof.set_value(ProofValue::Signature(encode_b58(&vec![u8::MAX; signature_length])));
+  data.set_signature(proof);
+
+  Ok(())
 }
@@ -11,6 +11,7 @@ use identity_iota::tangle::SharedPtr;
 use identity_iota_core::did::IotaDID;
 
 use crate::account::Account;
+use crate::account::AccountManager;
 use crate::error::Result;
 use crate::types::IdentitySetup;
 
@@ -169,11 +170,19 @@ where
   /// # Warning
   ///
   /// Callers are expected **not** to load the same [`IotaDID`] into more than one account,
-  /// as that would cause race conditions when updating the identity.
+  /// including the accounts of an [`AccountManager`], as that would cause race conditions when
+  /// updating the identity.
   pub async fn load_identity(&mut self, did: IotaDID) -> Result<Account<C>> {
     let setup: AccountSetup<C> = self.build_setup().await?;
     Account::load_identity(setup, did).await
   }
+
+  /// Creates an [`AccountManager`] for all identities in the configured [`Storage`], using the
+  /// current builder configuration.
+  pub async fn build_manager(&mut self) -> Result<AccountManager<C>> {
+    let setup: AccountSetup<C> = self.build_setup().await?;
+    Ok(AccountManager::new(setup))
+  }
 }
 
 impl<C> Default for AccountBuilder<C>
@@ -0,0 +1,197 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use identity_account_storage::storage::Storage;
+use identity_iota::tangle::Client;
+use identity_iota::tangle::SharedPtr;
+use identity_iota_core::did::IotaDID;
+
+use crate::account::Account;
+use crate::error::Error;
+use crate::error::Result;
+use crate::types::IdentitySetup;
+
+use super::config::AccountSetup;
+
+/// Manages the [`Account`]s of all identities in one [`Storage`].
+///
+/// Accounts are loaded from storage when first accessed and cached afterwards. All accounts share
+/// the [`Storage`] and [`Client`] of the manager, and the configuration it was built with.
+///
+/// The manager ensures that it hands out at most one [`Account`] for each identity at a time. An
+/// account can be taken out of the manager with [`take_account`](AccountManager::take_account), e.g.
+/// to update it from another task, in which case the identity cannot be loaded again through the
+/// manager until the account is handed back with [`return_account`](AccountManager::return_account),
+/// or released with [`release_account`](AccountManager::release_account) if it is lost.
+///
+/// # Warning
+///
+/// This guarantee only covers the accounts of this manager, as the [`Storage`] is not locked.
+/// Callers are expected **not** to load an identity of the manager elsewhere, e.g. with
+/// [`AccountBuilder::load_identity`](crate::account::AccountBuilder::load_identity) or a second
+/// manager over the same [`Storage`], as that would cause race conditions when updating the identity.
+///
+/// Create a manager with [`AccountBuilder::build_manager`](crate::account::AccountBuilder::build_manager).
+#[derive(Debug)]
+pub struct AccountManager<C = Arc<Client>>
+where
+  C: SharedPtr<Client>,
+{
+  setup: AccountSetup<C>,
+  accounts: HashMap<IotaDID, Account<C>>,
+  taken: HashSet<IotaDID>,
+}
+
+impl<C> AccountManager<C>
+where
+  C: SharedPtr<Client>,
+{
+  pub(crate) fn new(setup: AccountSetup<C>) -> Self {
+    Self {
+      setup,
+      accounts: HashMap::new(),
+      taken: HashSet::new(),
+    }
+  }
+
+  /// Returns a reference counter to the [`Storage`] implementation.
+  pub fn storage(&self) -> &Arc<dyn Storage> {
+    &self.setup.storage
+  }
+
+  /// Returns the DIDs of all identities in the [`Storage`].
+  pub async fn dids(&self) -> Result<Vec<IotaDID>> {
+    self.setup.storage.did_list().await.map_err(Into::into)
+  }
+
+  /// Returns whether the account of the identity with the given `did` is loaded.
+  pub fn is_loaded(&self, did: &IotaDID) -> bool {
+    self.accounts.contains_key(did)
+  }
+
+  /// Creates a new identity and returns the [`Account`] managing it.
+  ///
+  /// See [`AccountBuilder::create_identity`](crate::account::AccountBuilder::create_identity).
+  pub async fn create_identity(&mut self, input: IdentitySetup) -> Result<&mut Account<C>> {
+    let account: Account<C> = Account::create_identity(self.setup.clone(), input).await?;
+    let did: IotaDID = account.did().to_owned();
+
+    Ok(self.accounts.entry(did).or_insert(account))
+  }
+
+  /// Returns the [`Account`] of the identity with the given `did`, loading it from storage if
+  /// needed.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the identity does not exist in storage, or if its account was taken out of
+  /// the manager.
+  pub async fn account(&mut self, did: &IotaDID) -> Result<&mut Account<C>> {
+    if self.taken.contains(did) {
+      return Err(Error::IdentityInUse(did.to_owned()));
+    }
+
+    if !self.accounts.contains_key(did) {
+      let account: Account<C> = Account::load_identity(self.setup.clone(), did.to_owned()).await?;
+      self.accounts.insert(did.to_owned(), account);
+    }
+
+    self.accounts.get_mut(did).ok_or(Error::IdentityNotFound)
+  }
+
+  /// Takes the [`Account`] of the identity with the given `did` out of the manager, loading it from
+  /// storage if needed.
+  ///
+  /// Until the account is handed back with [`return_account`](AccountManager::return_account), the
+  /// identity cannot be accessed through the manager.
+  pub async fn take_account(&mut self, did: &IotaDID) -> Result<Account<C>> {
+    self.account(did).await?;
+
+    let account: Account<C> = self.accounts.remove(did).ok_or(Error::IdentityNotFound)?;
+    self.taken.insert(did.to_owned());
+
+    Ok(account)
+  }
+
+  /// Hands an [`Account`] taken out with [`take_account`](AccountManager::take_account) back to the
+  /// manager.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the account is not currently taken out of this manager, e.g. because it
+  /// was already returned or released with [`release_account`](AccountManager::release_account).
+  pub fn return_account(&mut self, account: Account<C>) -> Result<()> {
+    let did: IotaDID = account.did().to_owned();
+    if !self.taken.remove(&did) {
+      return Err(Error::IdentityNotTaken(did));
+    }
+
+    self.accounts.insert(did, account);
+
+    Ok(())
+  }
+
+  /// Releases the identity with the given `did` whose [`Account`] was taken out with
+  /// [`take_account`](AccountManager::take_account) but will not be handed back, e.g. because it
+  /// was dropped or the task holding it panicked.
+  ///
+  /// The identity is loaded from storage again when next accessed. Returns whether the account was
+  /// taken out of the manager.
+  pub fn release_account(&mut self, did: &IotaDID) -> bool {
+    self.taken.remove(did)
+  }
+
+  /// Removes the [`Account`] of the identity with the given `did` from the cache.
+  ///
+  /// The identity is loaded from storage again when next accessed.
+  pub fn unload(&mut self, did: &IotaDID) -> bool {
+    self.accounts.remove(did).is_some()
+  }
+
+  /// Publishes the unpublished changes of all loaded accounts.
+  ///
+  /// Returns the result of publishing for each account with unpublished changes.
+  pub async fn publish_all(&mut self) -> HashMap<IotaDID, Result<()>> {
+    let mut results: HashMap<IotaDID, Result<()>> = HashMap::new();
+
+    for (did, account) in self.accounts.iter_mut() {
+      let result: Result<()> = match account.has_unpublished_changes().await {
+        Ok(true) => account.publish().await,
+        Ok(false) => continue,
+        Err(error) => Err(error),
+      };
+      results.insert(did.to_owned(), result);
+    }
+
+    results
+  }
+
+  /// Fetches the latest documents of all identities in the [`Storage`] from the Tangle, loading
+  /// their accounts if needed.
+  ///
+  /// Accounts taken out of the manager are skipped. Returns the result of fetching for each
+  /// identity.
+  ///
+  /// See [`Account::fetch_document`].
+  pub async fn fetch_all(&mut self) -> Result<HashMap<IotaDID, Result<()>>> {
+    let mut results: HashMap<IotaDID, Result<()>> = HashMap::new();
+
+    for did in self.dids().await? {
+      if self.taken.contains(&did) {
+        continue;
+      }
+
+      let result: Result<()> = match self.account(&did).await {
+        Ok(account) => account.fetch_document().await,
+        Err(error) => Err(error),
+      };
+      results.insert(did, result);
+    }
+
+    Ok(results)
+  }
+}
@@ -6,9 +6,15 @@
 mod account;
 mod builder;
 mod config;
+mod manager;
 mod publish_options;
+mod publish_preview;
+mod transaction;
 
 pub use self::account::*;
 pub use self::builder::*;
 pub use self::config::*;
+pub use self::manager::*;
 pub use self::publish_options::*;
+pub use self::publish_preview::*;
+pub use self::transaction::*;
@@ -0,0 +1,133 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::OrderedSet;
+use identity_did::verification::MethodRef;
+use identity_did::verification::MethodRelationship;
+use identity_iota::tangle::PublishType;
+use identity_iota_core::did::IotaDID;
+use identity_iota_core::diff::DiffMessage;
+use identity_iota_core::document::IotaDocument;
+
+/// The unsigned message which would be published to the Tangle.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PublishMessage {
+  /// The full document, published as an integration message.
+  Integration(IotaDocument),
+  /// The changes to the last published document, published as a diff message.
+  Diff(DiffMessage),
+}
+
+/// A preview of the pending changes of an [`Account`](crate::account::Account), as they would be
+/// published to the Tangle.
+///
+/// See [`Account::publish_preview`](crate::account::Account::publish_preview).
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublishPreview {
+  pub(crate) message: PublishMessage,
+  pub(crate) size: usize,
+  pub(crate) changes: Vec<String>,
+}
+
+impl PublishPreview {
+  /// Returns whether the changes would be published as an integration or diff message.
+  pub fn publish_type(&self) -> PublishType {
+    match self.message {
+      PublishMessage::Integration(_) => PublishType::Integration,
+      PublishMessage::Diff(_) => PublishType::Diff,
+    }
+  }
+
+  /// Returns the unsigned message which would be published.
+  pub fn message(&self) -> &PublishMessage {
+    &self.message
+  }
+
+  /// Returns the size in bytes of the message as it would be published, encoded with the
+  /// [`DIDMessageEncoding`](identity_iota::tangle::DIDMessageEncoding) of the client.
+  ///
+  /// The size includes the proof added when publishing, with a placeholder of the maximum length
+  /// of the signature, so the size of the published message may differ slightly.
+  pub fn size(&self) -> usize {
+    self.size
+  }
+
+  /// Returns a human-readable summary of the changes to the last published document.
+  pub fn changes(&self) -> &[String] {
+    &self.changes
+  }
+}
+
+/// Summarizes the changes from the `old` to the `new` document, line by line.
+pub(crate) fn summarize_changes(old: &IotaDocument, new: &IotaDocument) -> Vec<String> {
+  let mut changes: Vec<String> = Vec::new();
+
+  for method in new.methods() {
+    match old.methods().find(|old_method| old_method.id() == method.id()) {
+      None => changes.push(format!("add verification method {}", method.id())),
+      Some(old_method) if old_method != method => changes.push(format!("update verification method {}", method.id())),
+      Some(_) => {}
+    }
+  }
+  for method in old.methods() {
+    if !new.methods().any(|new_method| new_method.id() == method.id()) {
+      changes.push(format!("remove verification method {}", method.id()));
+    }
+  }
+
+  for relationship in MethodRelationship::ALL {
+    let name: &'static str = relationship.into();
+    let old_refs: &OrderedSet<MethodRef<IotaDID>> = relationship_set(old, relationship);
+    let new_refs: &OrderedSet<MethodRef<IotaDID>> = relationship_set(new, relationship);
+
+    for method_ref in new_refs.iter() {
+      if !old_refs.iter().any(|old_ref| old_ref.id() == method_ref.id()) {
+        changes.push(format!("attach {} relationship to {}", name, method_ref.id()));
+      }
+    }
+    for method_ref in old_refs.iter() {
+      if !new_refs.iter().any(|new_ref| new_ref.id() == method_ref.id()) {
+        changes.push(format!("detach {} relationship from {}", name, method_ref.id()));
+      }
+    }
+  }
+
+  for service in new.service().iter() {
+    match old
+      .service()
+      .iter()
+      .find(|old_service| old_service.id() == service.id())
+    {
+      None => changes.push(format!("add service {}", service.id())),
+      Some(old_service) if old_service != service => changes.push(format!("update service {}", service.id())),
+      Some(_) => {}
+    }
+  }
+  for service in old.service().iter() {
+    if !new.service().iter().any(|new_service| new_service.id() == service.id()) {
+      changes.push(format!("remove service {}", service.id()));
+    }
+  }
+
+  if old.controller() != new.controller() {
+    changes.push("update controller".to_owned());
+  }
+  if old.also_known_as() != new.also_known_as() {
+    changes.push("update alsoKnownAs".to_owned());
+  }
+  if old.properties() != new.properties() {
+    changes.push("update properties".to_owned());
+  }
+
+  changes
+}
+
+fn relationship_set(document: &IotaDocument, relationship: MethodRelationship) -> &OrderedSet<MethodRef<IotaDID>> {
+  match relationship {
+    MethodRelationship::Authentication => document.core_document().authentication(),
+    MethodRelationship::AssertionMethod => document.core_document().assertion_method(),
+    MethodRelationship::KeyAgreement => document.core_document().key_agreement(),
+    MethodRelationship::CapabilityDelegation => document.core_document().capability_delegation(),
+    MethodRelationship::CapabilityInvocation => document.core_document().capability_invocation(),
+  }
+}
@@ -0,0 +1,98 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_account_storage::identity::ChainState;
+use identity_account_storage::types::KeyLocation;
+use identity_iota::tangle::Client;
+use identity_iota::tangle::SharedPtr;
+use identity_iota_core::document::IotaDocument;
+
+use crate::account::Account;
+use crate::account::PublishOptions;
+use crate::types::IdentityUpdater;
+use crate::Result;
+
+/// A batch of updates to an identity which is published at once.
+///
+/// Updates applied through [`Transaction::update_identity`] only modify a draft of the DID document.
+/// The draft is published in a single message on [`commit`](Transaction::commit). If publishing
+/// fails, the keys generated by the updates are removed from the
+/// [`Storage`](identity_account_storage::storage::Storage) and the document and [`ChainState`] of
+/// the account are restored.
+///
+/// Dropping a transaction without committing it discards the draft, but leaves any generated keys
+/// in storage. Call [`rollback`](Transaction::rollback) to remove them as well.
+#[derive(Debug)]
+pub struct Transaction<'account, C>
+where
+  C: SharedPtr<Client>,
+{
+  account: &'account mut Account<C>,
+}
+
+impl<'account, C> Transaction<'account, C>
+where
+  C: SharedPtr<Client>,
+{
+  pub(crate) fn new(account: &'account mut Account<C>) -> Self {
+    account.begin_transaction();
+    Self { account }
+  }
+
+  /// Returns the [`IdentityUpdater`] to add updates to the transaction.
+  ///
+  /// Each update is validated against the draft when it is applied. An update which fails leaves
+  /// the draft unchanged.
+  pub fn update_identity(&mut self) -> IdentityUpdater<'_, C> {
+    self.account.update_identity()
+  }
+
+  /// Returns the draft of the DID document with all updates of the transaction applied.
+  pub fn document(&self) -> &IotaDocument {
+    self.account.document()
+  }
+
+  /// Publishes the draft and stores the new state of the account, even if auto-publish is
+  /// disabled.
+  ///
+  /// If publishing fails, the transaction is rolled back and the error is returned. If only storing
+  /// the state fails after publishing, the published state and the generated keys are kept and
+  /// storing is retried once. The keys of the methods removed by the transaction are deleted from
+  /// storage after publishing.
+  pub async fn commit(self) -> Result<()> {
+    self.account.commit_transaction(PublishOptions::default()).await
+  }
+
+  /// Publishes the draft like [`Transaction::commit`], optionally choosing the signing key used or
+  /// forcing an integration chain update.
+  ///
+  /// See [`PublishOptions`].
+  pub async fn commit_with_options(self, options: PublishOptions) -> Result<()> {
+    self.account.commit_transaction(options).await
+  }
+
+  /// Discards the draft and removes the keys generated by the updates of the transaction from
+  /// storage.
+  pub async fn rollback(self) -> Result<()> {
+    self.account.rollback_transaction().await
+  }
+}
+
+impl<'account, C> Drop for Transaction<'account, C>
+where
+  C: SharedPtr<Client>,
+{
+  fn drop(&mut self) {
+    self.account.abort_transaction();
+  }
+}
+
+/// The state of an [`Account`] before a [`Transaction`] started, the keys generated since and the
+/// keys of the methods removed since.
+#[derive(Debug)]
+pub(crate) struct TransactionState {
+  pub(crate) document: IotaDocument,
+  pub(crate) chain_state: ChainState,
+  pub(crate) key_locations: Vec<KeyLocation>,
+  pub(crate) removed_key_locations: Vec<KeyLocation>,
+}
@@ -30,11 +30,25 @@ pub enum Error {
   /// Caused by attempting to find an identity that does not exist.
   #[error("Identity not found")]
   IdentityNotFound,
+  /// Caused by attempting to load an identity whose account is already taken out of an
+  /// [`AccountManager`](crate::account::AccountManager).
+  #[error("Identity already in use: {0}")]
+  IdentityInUse(identity_iota_core::did::IotaDID),
+  /// Caused by returning an account to a manager which it was not taken out of.
+  #[error("Identity not taken out of the manager: {0}")]
+  IdentityNotTaken(identity_iota_core::did::IotaDID),
   /// Caused by attempting to perform an upate in an invalid context.
   #[error("Update Error: {0}")]
   UpdateError(#[from] crate::updates::UpdateError),
   #[error("method missing fragment")]
   MethodMissingFragment,
+  /// Caused by attempting to sign with a verification method which does not have the required
+  /// verification relationship.
+  #[error("verification method does not have the required scope: {}", .0.as_str())]
+  InvalidMethodScope(identity_did::verification::MethodScope),
+  /// Caused by requesting a proof purpose which does not match the scope of the signing method.
+  #[error("proof purpose does not match the scope of the signing method: {0}")]
+  InvalidProofPurpose(identity_core::crypto::ProofPurpose),
 }
 
 impl From<identity_did::did::DIDError> for Error {
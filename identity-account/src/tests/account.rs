@@ -11,12 +11,25 @@ use identity_account_storage::storage::MemStore;
 use identity_account_storage::storage::Stronghold;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
+use identity_core::convert::FromJson;
+use identity_core::crypto::Proof;
 use identity_core::crypto::ProofOptions;
+use identity_core::crypto::ProofPurpose;
+use identity_core::json;
+use identity_credential::credential::Credential;
+use identity_credential::credential::CredentialBuilder;
+use identity_credential::credential::Subject;
+use identity_credential::presentation::Presentation;
+use identity_credential::presentation::PresentationBuilder;
+use identity_did::did::DID;
 use identity_did::utils::Queryable;
+use identity_did::verifiable::VerifierOptions;
 use identity_did::verification::MethodScope;
 use identity_iota::chain::DocumentChain;
 use identity_iota::tangle::Client;
 use identity_iota::tangle::ClientBuilder;
+use identity_iota::tangle::DIDMessageEncoding;
+use identity_iota::tangle::PublishType;
 use identity_iota_core::did::IotaDID;
 use identity_iota_core::diff::DiffMessage;
 use identity_iota_core::document::IotaDocument;
@@ -29,7 +42,9 @@ use crate::account::AccountBuilder;
 use crate::account::AccountConfig;
 use crate::account::AccountSetup;
 use crate::account::AutoSave;
+use crate::account::PublishMessage;
 use crate::account::PublishOptions;
+use crate::account::PublishPreview;
 use crate::types::IdentitySetup;
 use crate::types::MethodContent;
 use crate::Error;
@@ -544,6 +559,305 @@ async fn network_resilient_test(
   Ok(())
 }
 
+#[tokio::test]
+async fn test_account_sign_secp256k1() -> Result<()> {
+  let mut account: Account =
+    Account::create_identity(account_setup(Network::Mainnet).await, IdentitySetup::default()).await?;
+
+  account
+    .update_identity()
+    .create_method()
+    .content(MethodContent::GenerateSecp256k1)
+    .fragment("secp256k1-key")
+    .scope(MethodScope::assertion_method())
+    .apply()
+    .await?;
+
+  let mut credential: Credential = CredentialBuilder::default()
+    .issuer(Url::parse(account.did().as_str()).unwrap())
+    .subject(Subject::from_json_value(json!({ "name": "Alice" })).unwrap())
+    .build()
+    .unwrap();
+
+  account
+    .sign("secp256k1-key", &mut credential, ProofOptions::default())
+    .await?;
+
+  assert_eq!(
+    credential.proof.as_ref().unwrap().type_(),
+    "JcsEcdsaSecp256k1Signature2022"
+  );
+  assert!(account
+    .document()
+    .verify_data(&credential, &VerifierOptions::default())
+    .is_ok());
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn test_account_issue_credential() -> Result<()> {
+  let mut account: Account =
+    Account::create_identity(account_setup(Network::Mainnet).await, IdentitySetup::default()).await?;
+  let builder = || {
+    CredentialBuilder::default()
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .subject(Subject::from_json_value(json!({ "name": "Alice" })).unwrap())
+  };
+
+  // The default signing method is not an assertion method.
+  let fragment: String = account
+    .document()
+    .default_signing_method()?
+    .id()
+    .fragment()
+    .unwrap()
+    .to_owned();
+  assert!(matches!(
+    account
+      .issue_credential(&fragment, builder(), ProofOptions::default())
+      .await
+      .unwrap_err(),
+    Error::InvalidMethodScope(_)
+  ));
+  assert!(matches!(
+    account
+      .issue_credential("missing-key", builder(), ProofOptions::default())
+      .await
+      .unwrap_err(),
+    Error::DIDError(identity_did::Error::MethodNotFound)
+  ));
+
+  account
+    .update_identity()
+    .create_method()
+    .content(MethodContent::GenerateEd25519)
+    .fragment("assertion-key")
+    .scope(MethodScope::assertion_method())
+    .apply()
+    .await?;
+
+  // The proof purpose must match the scope of the signing method.
+  assert!(matches!(
+    account
+      .issue_credential(
+        "assertion-key",
+        builder(),
+        ProofOptions::new().purpose(ProofPurpose::Authentication)
+      )
+      .await
+      .unwrap_err(),
+    Error::InvalidProofPurpose(ProofPurpose::Authentication)
+  ));
+
+  let expires: Timestamp = Timestamp::from_unix(Timestamp::now_utc().to_unix() + 3600)?;
+  let credential: Credential = account
+    .issue_credential("assertion-key", builder(), ProofOptions::new().expires(expires))
+    .await?;
+
+  assert_eq!(credential.issuer.url().as_str(), account.did().as_str());
+  let proof: &Proof = credential.proof.as_ref().unwrap();
+  assert_eq!(proof.purpose, Some(ProofPurpose::AssertionMethod));
+  assert_eq!(proof.expires, Some(expires));
+  assert!(account
+    .document()
+    .verify_data(
+      &credential,
+      &VerifierOptions::new()
+        .method_scope(MethodScope::assertion_method())
+        .purpose(ProofPurpose::AssertionMethod)
+    )
+    .is_ok());
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn test_account_create_presentation() -> Result<()> {
+  let mut account: Account =
+    Account::create_identity(account_setup(Network::Mainnet).await, IdentitySetup::default()).await?;
+
+  for (fragment, scope) in [
+    ("assertion-key", MethodScope::assertion_method()),
+    ("authentication-key", MethodScope::authentication()),
+  ] {
+    account
+      .update_identity()
+      .create_method()
+      .content(MethodContent::GenerateEd25519)
+      .fragment(fragment)
+      .scope(scope)
+      .apply()
+      .await?;
+  }
+
+  let credential: Credential = account
+    .issue_credential(
+      "assertion-key",
+      CredentialBuilder::default()
+        .issuer(Url::parse(account.did().as_str()).unwrap())
+        .subject(Subject::from_json_value(json!({ "id": account.did().as_str() })).unwrap()),
+      ProofOptions::default(),
+    )
+    .await?;
+  let builder = || PresentationBuilder::default().credential(credential.clone());
+  let options: ProofOptions = ProofOptions::new()
+    .challenge("475a7984-1bb5-4c4c-a56f-822bccd46440".to_owned())
+    .domain("https://example.com/".to_owned());
+
+  // Presentations cannot be signed with an assertion method.
+  assert!(matches!(
+    account
+      .create_presentation("assertion-key", builder(), options.clone())
+      .await
+      .unwrap_err(),
+    Error::InvalidMethodScope(_)
+  ));
+  // The proof purpose must match the scope of the signing method.
+  assert!(matches!(
+    account
+      .create_presentation(
+        "authentication-key",
+        builder(),
+        options.clone().purpose(ProofPurpose::AssertionMethod)
+      )
+      .await
+      .unwrap_err(),
+    Error::InvalidProofPurpose(ProofPurpose::AssertionMethod)
+  ));
+
+  let presentation: Presentation = account
+    .create_presentation("authentication-key", builder(), options)
+    .await?;
+
+  assert_eq!(presentation.holder.as_ref().unwrap().as_str(), account.did().as_str());
+  let proof: &Proof = presentation.proof.as_ref().unwrap();
+  assert_eq!(proof.purpose, Some(ProofPurpose::Authentication));
+  assert!(account
+    .document()
+    .verify_data(
+      &presentation,
+      &VerifierOptions::new()
+        .method_scope(MethodScope::authentication())
+        .purpose(ProofPurpose::Authentication)
+        .challenge("475a7984-1bb5-4c4c-a56f-822bccd46440".to_owned())
+        .domain("https://example.com/".to_owned())
+    )
+    .is_ok());
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn test_account_publish_preview() -> Result<()> {
+  let config = AccountConfig::default().autopublish(false).testmode(true);
+  let client = ClientBuilder::new()
+    .node_sync_disabled()
+    .encoding(DIDMessageEncoding::Json)
+    .build()
+    .await?;
+  let account_setup = AccountSetup::new(Arc::new(MemStore::new()), Arc::new(client), config);
+
+  let mut account = Account::create_identity(account_setup, IdentitySetup::new()).await?;
+
+  // A new identity is published as an integration message.
+  let preview: PublishPreview = account.publish_preview(PublishOptions::default()).await?.unwrap();
+  assert!(matches!(preview.publish_type(), PublishType::Integration));
+  assert!(matches!(preview.message(), PublishMessage::Integration(document) if document == account.document()));
+  assert_eq!(preview.changes(), [format!("create identity {}", account.did())]);
+  assert!(preview.size() > 0);
+
+  // Nothing was published.
+  assert!(account.chain_state().last_integration_message_id().is_null());
+
+  account.publish().await?;
+  assert!(account.publish_preview(PublishOptions::default()).await?.is_none());
+
+  account
+    .update_identity()
+    .create_service()
+    .fragment("my-service")
+    .type_("LinkedDomains")
+    .endpoint(Url::parse("https://example.org").unwrap())
+    .apply()
+    .await?;
+
+  let service_id: String = account
+    .document()
+    .service()
+    .query("my-service")
+    .unwrap()
+    .id()
+    .to_string();
+
+  let preview: PublishPreview = account.publish_preview(PublishOptions::default()).await?.unwrap();
+  assert!(matches!(preview.publish_type(), PublishType::Diff));
+  assert!(matches!(preview.message(), PublishMessage::Diff(diff) if diff.id() == account.did()));
+  assert_eq!(preview.changes(), [format!("add service {}", service_id)]);
+
+  // The size is computed with the encoding of the client and includes the signature, whose
+  // base58 encoding may be a few characters shorter than the placeholder.
+  let client = ClientBuilder::new()
+    .node_sync_disabled()
+    .encoding(DIDMessageEncoding::Json)
+    .build()
+    .await?;
+  match preview.message() {
+    PublishMessage::Diff(diff) => {
+      let mut signed: DiffMessage = diff.clone();
+      account
+        .sign(
+          IotaDocument::DEFAULT_METHOD_FRAGMENT,
+          &mut signed,
+          ProofOptions::default(),
+        )
+        .await?;
+      assert!(preview.size() > client.message_size(diff)?);
+      assert!(preview.size() >= client.message_size(&signed)?);
+      assert!(preview.size() <= client.message_size(&signed)? + 4);
+    }
+    PublishMessage::Integration(_) => unreachable!(),
+  }
+
+  let preview: PublishPreview = account
+    .publish_preview(PublishOptions::new().force_integration_update(true))
+    .await?
+    .unwrap();
+  assert!(matches!(preview.publish_type(), PublishType::Integration));
+
+  // Changing the signing methods requires an integration message.
+  account
+    .update_identity()
+    .create_method()
+    .scope(MethodScope::capability_invocation())
+    .fragment("signing-key")
+    .content(MethodContent::GenerateEd25519)
+    .apply()
+    .await?;
+
+  let method_id: String = account
+    .document()
+    .resolve_method("signing-key", None)
+    .unwrap()
+    .id()
+    .to_string();
+
+  let preview: PublishPreview = account.publish_preview(PublishOptions::default()).await?.unwrap();
+  assert!(matches!(preview.publish_type(), PublishType::Integration));
+  assert!(preview
+    .changes()
+    .contains(&format!("add verification method {}", method_id)));
+  assert!(preview
+    .changes()
+    .contains(&format!("attach CapabilityInvocation relationship to {}", method_id)));
+  assert!(preview.changes().contains(&format!("add service {}", service_id)));
+
+  // Nothing was published.
+  assert!(account.chain_state().last_diff_message_id().is_null());
+
+  Ok(())
+}
+
 // Ensure that a future that contains an account is `Send` at compile-time.
 #[tokio::test]
 async fn test_assert_account_futures_are_send() -> Result<()> {
@@ -0,0 +1,203 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use identity_core::common::Url;
+use identity_core::crypto::KeyPair;
+use identity_core::crypto::KeyType;
+use identity_did::utils::Queryable;
+use identity_iota_core::did::IotaDID;
+use identity_iota_core::tangle::Network;
+
+use crate::account::Account;
+use crate::account::AccountBuilder;
+use crate::account::AccountManager;
+use crate::types::IdentitySetup;
+use crate::types::MethodContent;
+use crate::Error;
+use crate::Result;
+
+use super::util::*;
+
+async fn manager() -> Result<AccountManager> {
+  AccountBuilder::default()
+    .testmode(true)
+    .autopublish(false)
+    .build_manager()
+    .await
+}
+
+#[tokio::test]
+async fn test_account_manager_load() -> Result<()> {
+  let mut manager: AccountManager = manager().await?;
+
+  let did1: IotaDID = manager
+    .create_identity(IdentitySetup::default())
+    .await?
+    .did()
+    .to_owned();
+  let did2: IotaDID = manager
+    .create_identity(IdentitySetup::default())
+    .await?
+    .did()
+    .to_owned();
+
+  let mut dids: Vec<IotaDID> = manager.dids().await?;
+  dids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+  let mut expected: Vec<IotaDID> = vec![did1.clone(), did2.clone()];
+  expected.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+  assert_eq!(dids, expected);
+
+  // Accounts are loaded from storage when accessed.
+  assert!(manager.is_loaded(&did1));
+  assert!(manager.unload(&did1));
+  assert!(!manager.is_loaded(&did1));
+
+  let account: &mut Account = manager.account(&did1).await?;
+  assert_eq!(account.did(), &did1);
+  assert!(manager.is_loaded(&did1));
+
+  // Loading an unknown identity fails.
+  let keypair: KeyPair = KeyPair::new(KeyType::Ed25519)?;
+  let unknown: IotaDID = IotaDID::new(keypair.public().as_ref())?;
+
+  assert!(matches!(
+    manager.account(&unknown).await.unwrap_err(),
+    Error::IdentityNotFound
+  ));
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn test_account_manager_single_writer() -> Result<()> {
+  let mut manager: AccountManager = manager().await?;
+
+  let did: IotaDID = manager
+    .create_identity(IdentitySetup::default())
+    .await?
+    .did()
+    .to_owned();
+
+  let mut account: Account = manager.take_account(&did).await?;
+
+  // The identity cannot be accessed while its account is taken out.
+  assert!(matches!(
+    manager.account(&did).await.unwrap_err(),
+    Error::IdentityInUse(in_use) if in_use == did
+  ));
+  assert!(matches!(
+    manager.take_account(&did).await.unwrap_err(),
+    Error::IdentityInUse(_)
+  ));
+
+  let task = tokio::spawn(async move {
+    let result: Result<()> = account
+      .update_identity()
+      .create_method()
+      .content(MethodContent::GenerateEd25519)
+      .fragment("my-key")
+      .apply()
+      .await;
+    result.map(|_| account)
+  });
+
+  let account: Account = task.await.expect("task failed to execute to completion")?;
+  manager.return_account(account)?;
+
+  let account: &mut Account = manager.account(&did).await?;
+  assert!(account.document().resolve_method("my-key", None).is_some());
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn test_account_manager_return_and_release() -> Result<()> {
+  let mut manager: AccountManager = manager().await?;
+
+  let did: IotaDID = manager
+    .create_identity(IdentitySetup::default())
+    .await?
+    .did()
+    .to_owned();
+
+  // Only accounts taken out of the manager can be returned, and only once.
+  let account: Account = manager.take_account(&did).await?;
+  let duplicate: Account = Account::load_identity(
+    account_setup_storage(Arc::clone(manager.storage()), Network::Mainnet).await,
+    did.clone(),
+  )
+  .await?;
+  manager.return_account(account)?;
+  assert!(matches!(
+    manager.return_account(duplicate).unwrap_err(),
+    Error::IdentityNotTaken(not_taken) if not_taken == did
+  ));
+
+  // A lost account can be released so the identity is loaded from storage again.
+  let account: Account = manager.take_account(&did).await?;
+  let result = tokio::spawn(async move {
+    let _account: Account = account;
+    panic!("task holding the account panicked");
+  })
+  .await;
+  assert!(result.is_err());
+
+  assert!(matches!(
+    manager.account(&did).await.unwrap_err(),
+    Error::IdentityInUse(_)
+  ));
+  assert!(manager.release_account(&did));
+  assert!(!manager.release_account(&did));
+  assert_eq!(manager.account(&did).await?.did(), &did);
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn test_account_manager_publish_all() -> Result<()> {
+  let mut manager: AccountManager = manager().await?;
+
+  let did1: IotaDID = manager
+    .create_identity(IdentitySetup::default())
+    .await?
+    .did()
+    .to_owned();
+  let did2: IotaDID = manager
+    .create_identity(IdentitySetup::default())
+    .await?
+    .did()
+    .to_owned();
+
+  // New identities have not been published yet.
+  let results: HashMap<IotaDID, Result<()>> = manager.publish_all().await;
+  assert_eq!(results.len(), 2);
+  assert!(results[&did1].is_ok());
+  assert!(results[&did2].is_ok());
+
+  assert!(manager.publish_all().await.is_empty());
+
+  manager
+    .account(&did2)
+    .await?
+    .update_identity()
+    .create_service()
+    .fragment("my-service")
+    .type_("LinkedDomains")
+    .endpoint(Url::parse("https://example.org").unwrap())
+    .apply()
+    .await?;
+
+  // Only the account with unpublished changes is published.
+  let results: HashMap<IotaDID, Result<()>> = manager.publish_all().await;
+  assert_eq!(results.len(), 1);
+  assert!(results[&did2].is_ok());
+
+  let account: &mut Account = manager.account(&did2).await?;
+  assert!(!account.has_unpublished_changes().await?);
+  assert!(account.document().service().query("my-service").is_some());
+
+  Ok(())
+}
@@ -2,5 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod account;
+mod manager;
+mod transaction;
 mod updates;
 mod util;
@@ -0,0 +1,249 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use identity_account_storage::identity::ChainState;
+use identity_account_storage::storage::Storage;
+use identity_account_storage::types::KeyLocation;
+use identity_core::common::Url;
+use identity_did::utils::Queryable;
+use identity_iota_core::did::IotaDID;
+use identity_iota_core::document::IotaDocument;
+use identity_iota_core::tangle::Network;
+
+use crate::account::Account;
+use crate::account::AccountSetup;
+use crate::account::PublishOptions;
+use crate::account::Transaction;
+use crate::types::IdentitySetup;
+use crate::types::MethodContent;
+use crate::Error;
+use crate::Result;
+
+use super::util::*;
+
+fn key_location(document: &IotaDocument, fragment: &str) -> KeyLocation {
+  KeyLocation::from_verification_method(document.resolve_method(fragment, None).unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn test_transaction_commit() -> Result<()> {
+  let mut account: Account =
+    Account::create_identity(account_setup(Network::Mainnet).await, IdentitySetup::default()).await?;
+
+  let did: IotaDID = account.did().to_owned();
+  let storage: Arc<dyn Storage> = Arc::clone(account.storage());
+  let initial_document: IotaDocument = account.document().to_owned();
+  let initial_chain_state: ChainState = account.chain_state().to_owned();
+  let actions: usize = account.actions();
+
+  let mut transaction: Transaction<'_, _> = account.transaction();
+
+  transaction
+    .update_identity()
+    .create_method()
+    .fragment("key-1")
+    .content(MethodContent::GenerateEd25519)
+    .apply()
+    .await?;
+
+  transaction
+    .update_identity()
+    .create_service()
+    .fragment("linked-domain")
+    .type_("LinkedDomains")
+    .endpoint(Url::parse("https://example.org").unwrap())
+    .apply()
+    .await?;
+
+  // A failing update leaves the draft unchanged.
+  let output = transaction
+    .update_identity()
+    .create_method()
+    .fragment("key-1")
+    .content(MethodContent::GenerateEd25519)
+    .apply()
+    .await;
+
+  assert!(matches!(
+    output.unwrap_err(),
+    Error::DIDError(identity_did::Error::MethodAlreadyExists)
+  ));
+
+  assert!(transaction.document().resolve_method("key-1", None).is_some());
+  assert_eq!(transaction.document().service().len(), 1);
+
+  // Nothing is published or stored before the transaction is committed.
+  assert_eq!(storage.document_get(&did).await?.unwrap(), initial_document);
+  assert_eq!(storage.chain_state_get(&did).await?.unwrap(), initial_chain_state);
+
+  transaction.commit().await?;
+
+  // All updates were published at once.
+  assert_eq!(account.actions(), actions + 1);
+  assert_eq!(
+    account.chain_state().last_integration_message_id(),
+    initial_chain_state.last_integration_message_id()
+  );
+  assert_ne!(
+    account.chain_state().last_diff_message_id(),
+    initial_chain_state.last_diff_message_id()
+  );
+
+  assert!(account.document().resolve_method("key-1", None).is_some());
+  assert_eq!(account.document().service().len(), 1);
+  assert_eq!(&storage.document_get(&did).await?.unwrap(), account.document());
+  assert_eq!(&storage.chain_state_get(&did).await?.unwrap(), account.chain_state());
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_commit_without_autopublish() -> Result<()> {
+  let mut setup: AccountSetup = account_setup(Network::Mainnet).await;
+  setup.config = setup.config.autopublish(false);
+  let mut account: Account = Account::create_identity(setup, IdentitySetup::default()).await?;
+
+  let did: IotaDID = account.did().to_owned();
+  let storage: Arc<dyn Storage> = Arc::clone(account.storage());
+  assert!(account.chain_state().is_new_identity());
+
+  let mut transaction: Transaction<'_, _> = account.transaction();
+
+  transaction
+    .update_identity()
+    .create_method()
+    .fragment("key-1")
+    .content(MethodContent::GenerateEd25519)
+    .apply()
+    .await?;
+
+  transaction.commit().await?;
+
+  // Committing a transaction publishes and stores the draft regardless of autopublish.
+  assert!(!account.chain_state().is_new_identity());
+  assert!(account.document().resolve_method("key-1", None).is_some());
+  assert_eq!(&storage.document_get(&did).await?.unwrap(), account.document());
+  assert_eq!(&storage.chain_state_get(&did).await?.unwrap(), account.chain_state());
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_commit_with_options() -> Result<()> {
+  let mut account: Account =
+    Account::create_identity(account_setup(Network::Mainnet).await, IdentitySetup::default()).await?;
+
+  let initial_chain_state: ChainState = account.chain_state().to_owned();
+
+  let mut transaction: Transaction<'_, _> = account.transaction();
+
+  transaction
+    .update_identity()
+    .create_method()
+    .fragment("key-1")
+    .content(MethodContent::GenerateEd25519)
+    .apply()
+    .await?;
+
+  transaction
+    .commit_with_options(PublishOptions::new().force_integration_update(true))
+    .await?;
+
+  // The options are used to publish the draft, forcing an integration update instead of a diff.
+  assert!(account.document().resolve_method("key-1", None).is_some());
+  assert_ne!(
+    account.chain_state().last_integration_message_id(),
+    initial_chain_state.last_integration_message_id()
+  );
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_rollback_on_failure() -> Result<()> {
+  let mut account: Account =
+    Account::create_identity(account_setup(Network::Mainnet).await, IdentitySetup::default()).await?;
+
+  let did: IotaDID = account.did().to_owned();
+  let storage: Arc<dyn Storage> = Arc::clone(account.storage());
+  let initial_document: IotaDocument = account.document().to_owned();
+  let initial_chain_state: ChainState = account.chain_state().to_owned();
+  let signing_location: KeyLocation = key_location(account.document(), IotaDocument::DEFAULT_METHOD_FRAGMENT);
+
+  let mut transaction: Transaction<'_, _> = account.transaction();
+
+  transaction
+    .update_identity()
+    .create_method()
+    .fragment("key-1")
+    .content(MethodContent::GenerateEd25519)
+    .apply()
+    .await?;
+
+  let location: KeyLocation = key_location(transaction.document(), "key-1");
+  assert!(storage.key_exists(&did, &location).await?);
+
+  // Publishing fails without the signing key.
+  assert!(storage.key_delete(&did, &signing_location).await?);
+
+  assert!(transaction.commit().await.is_err());
+
+  // The generated key was removed and the state restored.
+  assert!(!storage.key_exists(&did, &location).await?);
+  assert_eq!(account.document(), &initial_document);
+  assert_eq!(account.chain_state(), &initial_chain_state);
+  assert_eq!(storage.document_get(&did).await?.unwrap(), initial_document);
+  assert_eq!(storage.chain_state_get(&did).await?.unwrap(), initial_chain_state);
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_rollback() -> Result<()> {
+  let mut account: Account =
+    Account::create_identity(account_setup(Network::Mainnet).await, IdentitySetup::default()).await?;
+
+  let did: IotaDID = account.did().to_owned();
+  let storage: Arc<dyn Storage> = Arc::clone(account.storage());
+  let initial_document: IotaDocument = account.document().to_owned();
+
+  let mut transaction: Transaction<'_, _> = account.transaction();
+
+  transaction
+    .update_identity()
+    .create_method()
+    .fragment("key-1")
+    .content(MethodContent::GenerateEd25519)
+    .apply()
+    .await?;
+
+  let location: KeyLocation = key_location(transaction.document(), "key-1");
+
+  transaction.rollback().await?;
+
+  // The generated key was removed and the draft discarded.
+  assert!(!storage.key_exists(&did, &location).await?);
+  assert_eq!(account.document(), &initial_document);
+
+  // Dropping a transaction discards the draft, but keeps the generated keys.
+  let mut transaction: Transaction<'_, _> = account.transaction();
+
+  transaction
+    .update_identity()
+    .create_method()
+    .fragment("key-1")
+    .content(MethodContent::GenerateEd25519)
+    .apply()
+    .await?;
+
+  let location: KeyLocation = key_location(transaction.document(), "key-1");
+
+  std::mem::drop(transaction);
+
+  assert_eq!(account.document(), &initial_document);
+  assert!(storage.key_exists(&did, &location).await?);
+
+  Ok(())
+}
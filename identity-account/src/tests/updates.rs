@@ -5,6 +5,7 @@ use std::sync::Arc;
 
 use identity_account_storage::storage::MemStore;
 use identity_account_storage::types::KeyLocation;
+use identity_core::common::Duration;
 use identity_core::common::OneOrSet;
 use identity_core::common::OrderedSet;
 use identity_core::common::Timestamp;
@@ -14,6 +15,7 @@ use identity_core::crypto::KeyType;
 use identity_core::crypto::PrivateKey;
 use identity_core::crypto::PublicKey;
 use identity_did::did::DID;
+use identity_did::revocation::RevocationBitmap;
 use identity_did::service::ServiceEndpoint;
 use identity_did::utils::Queryable;
 use identity_did::verification::MethodRelationship;
@@ -23,6 +25,7 @@ use identity_iota::tangle::ClientBuilder;
 use identity_iota_core::did::IotaDID;
 use identity_iota_core::document::IotaDocument;
 use identity_iota_core::document::IotaVerificationMethod;
+use identity_iota_core::tangle::MessageId;
 use identity_iota_core::tangle::Network;
 
 use crate::account::Account;
@@ -173,7 +176,11 @@ async fn test_create_identity_from_invalid_private_key() -> Result<()> {
 #[tokio::test]
 async fn test_create_method_content_generate() -> Result<()> {
   for storage in storages().await {
-    for method_content in [MethodContent::GenerateEd25519, MethodContent::GenerateX25519] {
+    for method_content in [
+      MethodContent::GenerateEd25519,
+      MethodContent::GenerateX25519,
+      MethodContent::GenerateSecp256k1,
+    ] {
       let mut account: Account = Account::create_identity(
         account_setup_storage(Arc::clone(&storage), Network::Mainnet).await,
         IdentitySetup::default(),
@@ -611,6 +618,189 @@ async fn test_delete_method() -> Result<()> {
   Ok(())
 }
 
+#[tokio::test]
+async fn test_rotate_method() -> Result<()> {
+  let mut account = Account::create_identity(account_setup(Network::Mainnet).await, IdentitySetup::default()).await?;
+
+  let fragment: String = IotaDocument::DEFAULT_METHOD_FRAGMENT.to_owned();
+  let new_fragment = "sign-1".to_owned();
+  account
+    .process_update(Update::AttachMethodRelationship {
+      fragment: fragment.clone(),
+      relationships: vec![MethodRelationship::Authentication],
+    })
+    .await?;
+
+  let initial_document = account.document().to_owned();
+  let initial_location: KeyLocation =
+    KeyLocation::from_verification_method(initial_document.default_signing_method().unwrap()).unwrap();
+  let integration_message_id: MessageId = *account.chain_state().last_integration_message_id();
+
+  let update: Update = Update::RotateMethod {
+    fragment: fragment.clone(),
+    new_fragment: new_fragment.clone(),
+    grace_period: Some(Duration::days(1)),
+  };
+
+  account.process_update(update.clone()).await?;
+
+  let document: &IotaDocument = account.document();
+
+  // The new method took over all relationships.
+  let new_method: &IotaVerificationMethod = document.default_signing_method().unwrap();
+  assert_eq!(new_method.id().fragment().unwrap(), new_fragment);
+  assert_eq!(document.core_document().capability_invocation().len(), 1);
+  assert!(document
+    .resolve_method(&new_fragment, Some(MethodScope::authentication()))
+    .is_some());
+
+  let new_location: KeyLocation = KeyLocation::from_verification_method(new_method).unwrap();
+  assert_eq!(new_location.key_type, initial_location.key_type);
+  assert!(account
+    .storage()
+    .key_exists(account.did(), &new_location)
+    .await
+    .unwrap());
+
+  // The old method keeps all relationships but capability invocation until it expires.
+  let old_method: &IotaVerificationMethod = document
+    .resolve_method(&fragment, Some(MethodScope::VerificationMethod))
+    .unwrap();
+  assert_eq!(
+    old_method.data(),
+    initial_document.default_signing_method().unwrap().data()
+  );
+  assert!(old_method.properties().contains_key("expires"));
+  assert!(document
+    .resolve_method(&fragment, Some(MethodScope::authentication()))
+    .is_some());
+  assert!(document
+    .resolve_method(&fragment, Some(MethodScope::capability_invocation()))
+    .is_none());
+  assert_eq!(document.core_document().verification_relationships().count(), 3);
+  assert!(account
+    .storage()
+    .key_exists(account.did(), &initial_location)
+    .await
+    .unwrap());
+
+  // Rotating the signing method results in an integration update.
+  assert_ne!(
+    account.chain_state().last_integration_message_id(),
+    &integration_message_id
+  );
+
+  // The new method can sign subsequent updates.
+  account
+    .process_update(Update::CreateService {
+      fragment: "linked-domain".to_owned(),
+      type_: "LinkedDomains".to_owned(),
+      endpoint: ServiceEndpoint::One(Url::parse("https://iota.org").unwrap()),
+      properties: None,
+    })
+    .await?;
+
+  // Rotating a non-existing method fails.
+  let output = account
+    .process_update(Update::RotateMethod {
+      fragment: "key-1".to_owned(),
+      new_fragment: "key-2".to_owned(),
+      grace_period: None,
+    })
+    .await;
+
+  assert!(matches!(
+    output.unwrap_err(),
+    Error::DIDError(identity_did::Error::MethodNotFound)
+  ));
+
+  // Rotating onto an existing fragment fails.
+  let output = account
+    .process_update(Update::RotateMethod {
+      fragment: new_fragment.clone(),
+      new_fragment: fragment,
+      grace_period: None,
+    })
+    .await;
+
+  assert!(matches!(
+    output.unwrap_err(),
+    Error::DIDError(identity_did::Error::MethodAlreadyExists)
+  ));
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn test_delete_expired_methods() -> Result<()> {
+  let mut account = Account::create_identity(account_setup(Network::Mainnet).await, IdentitySetup::default()).await?;
+
+  let fragment: String = IotaDocument::DEFAULT_METHOD_FRAGMENT.to_owned();
+
+  account
+    .update_identity()
+    .create_method()
+    .fragment("key-1")
+    .content(MethodContent::GenerateEd25519)
+    .apply()
+    .await?;
+
+  account
+    .update_identity()
+    .rotate_method()
+    .fragment("key-1")
+    .new_fragment("key-2")
+    .grace_period(Duration::days(1))
+    .apply()
+    .await?;
+
+  let expired_location: KeyLocation =
+    KeyLocation::from_verification_method(account.document().resolve_method(&fragment, None).unwrap())?;
+  let kept_location: KeyLocation =
+    KeyLocation::from_verification_method(account.document().resolve_method("key-1", None).unwrap())?;
+
+  account
+    .update_identity()
+    .rotate_method()
+    .fragment(fragment.clone())
+    .new_fragment("sign-1")
+    .grace_period(Duration::seconds(0))
+    .apply()
+    .await?;
+
+  // Methods rotated without a grace period are never expired.
+  account
+    .update_identity()
+    .rotate_method()
+    .fragment("key-2")
+    .new_fragment("key-3")
+    .apply()
+    .await?;
+
+  assert_eq!(account.document().methods().count(), 5);
+
+  account.update_identity().delete_expired_methods().apply().await?;
+
+  // Only the method whose grace period ended was removed.
+  let document: &IotaDocument = account.document();
+  assert!(document.resolve_method(&fragment, None).is_none());
+  assert!(document.resolve_method("key-1", None).is_some());
+  assert!(document.resolve_method("key-2", None).is_some());
+  assert!(document.resolve_method("key-3", None).is_some());
+  assert!(document.resolve_method("sign-1", None).is_some());
+
+  // Only the key of the removed method is deleted from the storage.
+  assert!(!account.storage().key_exists(account.did(), &expired_location).await?);
+  assert!(account.storage().key_exists(account.did(), &kept_location).await?);
+
+  // Nothing is updated if no method is expired.
+  let updated: Option<Timestamp> = document.metadata.updated;
+  account.update_identity().delete_expired_methods().apply().await?;
+  assert_eq!(account.document().metadata.updated, updated);
+
+  Ok(())
+}
+
 #[tokio::test]
 async fn test_insert_service() -> Result<()> {
   let mut account = Account::create_identity(account_setup(Network::Mainnet).await, IdentitySetup::default()).await?;
@@ -677,6 +867,62 @@ async fn test_remove_service() -> Result<()> {
   Ok(())
 }
 
+#[tokio::test]
+async fn test_revoke_credentials() -> Result<()> {
+  let mut account = Account::create_identity(account_setup(Network::Mainnet).await, IdentitySetup::default()).await?;
+
+  let fragment = "#revocation".to_owned();
+
+  // Revoking requires an existing service.
+  let update: Update = Update::RevokeCredentials {
+    fragment: fragment.clone(),
+    indices: vec![0, 5],
+  };
+  let err = account.process_update(update.clone()).await.unwrap_err();
+  assert!(matches!(err, Error::UpdateError(UpdateError::ServiceNotFound)));
+
+  let create: Update = Update::CreateService {
+    fragment: fragment.clone(),
+    type_: RevocationBitmap::TYPE.to_owned(),
+    endpoint: RevocationBitmap::new().to_endpoint().unwrap(),
+    properties: None,
+  };
+  account.process_update(create).await.unwrap();
+
+  account.process_update(update).await.unwrap();
+
+  let bitmap: RevocationBitmap = account.document().resolve_revocation_bitmap(fragment.as_str()).unwrap();
+  assert!(bitmap.is_revoked(0));
+  assert!(bitmap.is_revoked(5));
+
+  let update: Update = Update::UnrevokeCredentials {
+    fragment: fragment.clone(),
+    indices: vec![5],
+  };
+  account.process_update(update).await.unwrap();
+
+  let bitmap: RevocationBitmap = account.document().resolve_revocation_bitmap(fragment.as_str()).unwrap();
+  assert!(bitmap.is_revoked(0));
+  assert!(!bitmap.is_revoked(5));
+
+  // Services of other types cannot be used for revocation.
+  let create: Update = Update::CreateService {
+    fragment: "#service-42".to_owned(),
+    type_: "LinkedDomains".to_owned(),
+    endpoint: ServiceEndpoint::One(Url::parse("https://iota.org").unwrap()),
+    properties: None,
+  };
+  account.process_update(create).await.unwrap();
+
+  let update: Update = Update::RevokeCredentials {
+    fragment: "#service-42".to_owned(),
+    indices: vec![0],
+  };
+  assert!(account.process_update(update).await.is_err());
+
+  Ok(())
+}
+
 #[tokio::test]
 async fn test_set_controller() -> Result<()> {
   let mut account = Account::create_identity(account_setup(Network::Mainnet).await, IdentitySetup::default()).await?;
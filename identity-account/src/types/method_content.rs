@@ -37,6 +37,20 @@ pub enum MethodContent {
   ///
   /// NOTE: the method will be unable to be used for key exchange without a private key.
   PublicX25519(PublicKey),
+  /// Generate and store a new secp256k1 keypair for a new
+  /// [`EcdsaSecp256k1VerificationKey2019`](identity_did::verification::MethodType::EcdsaSecp256k1VerificationKey2019)
+  /// method.
+  GenerateSecp256k1,
+  /// Store an existing secp256k1 private key and derive a public key from it for a new
+  /// [`EcdsaSecp256k1VerificationKey2019`](identity_did::verification::MethodType::EcdsaSecp256k1VerificationKey2019)
+  /// method.
+  PrivateSecp256k1(PrivateKey),
+  /// Insert an existing secp256k1 public key into a new
+  /// [`EcdsaSecp256k1VerificationKey2019`](identity_did::verification::MethodType::EcdsaSecp256k1VerificationKey2019)
+  /// method, without generating or storing a private key.
+  ///
+  /// NOTE: the method will be unable to be used to sign anything without a private key.
+  PublicSecp256k1(PublicKey),
 }
 
 impl MethodContent {
@@ -50,6 +64,9 @@ impl MethodContent {
       MethodContent::GenerateX25519 => identity_did::verification::MethodType::X25519KeyAgreementKey2019,
       MethodContent::PrivateX25519(_) => identity_did::verification::MethodType::X25519KeyAgreementKey2019,
       MethodContent::PublicX25519(_) => identity_did::verification::MethodType::X25519KeyAgreementKey2019,
+      MethodContent::GenerateSecp256k1 => identity_did::verification::MethodType::EcdsaSecp256k1VerificationKey2019,
+      MethodContent::PrivateSecp256k1(_) => identity_did::verification::MethodType::EcdsaSecp256k1VerificationKey2019,
+      MethodContent::PublicSecp256k1(_) => identity_did::verification::MethodType::EcdsaSecp256k1VerificationKey2019,
     }
   }
 
@@ -62,6 +79,9 @@ impl MethodContent {
       MethodContent::GenerateX25519 => KeyType::X25519,
       MethodContent::PrivateX25519(_) => KeyType::X25519,
       MethodContent::PublicX25519(_) => KeyType::X25519,
+      MethodContent::GenerateSecp256k1 => KeyType::Secp256k1,
+      MethodContent::PrivateSecp256k1(_) => KeyType::Secp256k1,
+      MethodContent::PublicSecp256k1(_) => KeyType::Secp256k1,
     }
   }
 }
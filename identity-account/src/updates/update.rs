@@ -6,12 +6,14 @@ use log::trace;
 
 use identity_account_storage::storage::Storage;
 use identity_account_storage::types::KeyLocation;
+use identity_core::common::Duration;
 use identity_core::common::Fragment;
 use identity_core::common::Object;
 use identity_core::common::OneOrSet;
 use identity_core::common::OrderedSet;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
+use identity_core::common::Value;
 use identity_core::crypto::KeyPair;
 use identity_core::crypto::KeyType;
 use identity_core::crypto::PrivateKey;
@@ -38,6 +40,9 @@ use crate::types::IdentitySetup;
 use crate::types::MethodContent;
 use crate::updates::UpdateError;
 
+/// The method property holding the time after which a rotated method expires.
+const METHOD_EXPIRES_PROPERTY: &str = "expires";
+
 pub(crate) async fn create_identity(
   setup: IdentitySetup,
   network: NetworkName,
@@ -72,6 +77,12 @@ pub(crate) enum Update {
   DeleteMethod {
     fragment: String,
   },
+  RotateMethod {
+    fragment: String,
+    new_fragment: String,
+    grace_period: Option<Duration>,
+  },
+  DeleteExpiredMethods {},
   AttachMethodRelationship {
     fragment: String,
     relationships: Vec<MethodRelationship>,
@@ -89,6 +100,14 @@ pub(crate) enum Update {
   DeleteService {
     fragment: String,
   },
+  RevokeCredentials {
+    fragment: String,
+    indices: Vec<u32>,
+  },
+  UnrevokeCredentials {
+    fragment: String,
+    indices: Vec<u32>,
+  },
   SetController {
     controllers: Option<OneOrSet<IotaDID>>,
   },
@@ -97,12 +116,32 @@ pub(crate) enum Update {
   },
 }
 
+/// The keys in the `storage` affected by an [`Update`].
+#[derive(Debug, Default)]
+pub(crate) struct UpdateKeys {
+  /// The location of the key added to the `storage` by the update, if any.
+  pub(crate) added: Option<KeyLocation>,
+  /// The locations of the keys of the methods removed by the update, which must only be deleted
+  /// from the `storage` once the update is published.
+  pub(crate) removed: Vec<KeyLocation>,
+}
+
 impl Update {
-  pub(crate) async fn process(self, did: &IotaDID, document: &mut IotaDocument, storage: &dyn Storage) -> Result<()> {
+  /// Applies the update to the `document`.
+  ///
+  /// Returns the keys in the `storage` added or made obsolete by the update.
+  pub(crate) async fn process(
+    self,
+    did: &IotaDID,
+    document: &mut IotaDocument,
+    storage: &dyn Storage,
+  ) -> Result<UpdateKeys> {
     debug!("[Update::process] Update = {:?}", self);
     trace!("[Update::process] Document = {:?}", document);
     trace!("[Update::process] Store = {:?}", storage);
 
+    let mut keys: UpdateKeys = UpdateKeys::default();
+
     match self {
       Self::CreateMethod {
         scope,
@@ -121,17 +160,24 @@ impl Update {
         let key_type: KeyType = content.key_type();
 
         let public: PublicKey = match content {
-          MethodContent::GenerateEd25519 | MethodContent::GenerateX25519 => {
+          MethodContent::GenerateEd25519 | MethodContent::GenerateX25519 | MethodContent::GenerateSecp256k1 => {
             let location: KeyLocation = storage.key_generate(did, key_type, fragment.name()).await?;
-            storage.key_public(did, &location).await?
+            let public: PublicKey = storage.key_public(did, &location).await?;
+            keys.added = Some(location);
+            public
           }
-          MethodContent::PrivateEd25519(private_key) | MethodContent::PrivateX25519(private_key) => {
+          MethodContent::PrivateEd25519(private_key)
+          | MethodContent::PrivateX25519(private_key)
+          | MethodContent::PrivateSecp256k1(private_key) => {
             let location: KeyLocation =
               insert_method_secret(storage, did, key_type, fragment.name(), private_key).await?;
-            storage.key_public(did, &location).await?
+            let public: PublicKey = storage.key_public(did, &location).await?;
+            keys.added = Some(location);
+            public
           }
           MethodContent::PublicEd25519(public_key) => public_key,
           MethodContent::PublicX25519(public_key) => public_key,
+          MethodContent::PublicSecp256k1(public_key) => public_key,
         };
 
         // Insert a new method.
@@ -145,18 +191,67 @@ impl Update {
 
         let method_url: IotaDIDUrl = did.to_url().join(fragment.identifier())?;
 
-        // Prevent deleting the last method capable of signing the DID document.
-        let capability_invocation_set = document.core_document().capability_invocation();
-        let is_capability_invocation = capability_invocation_set
-          .iter()
-          .any(|method_ref| method_ref.id() == &method_url);
+        delete_method(document, &method_url)?;
+      }
+      Self::RotateMethod {
+        fragment,
+        new_fragment,
+        grace_period,
+      } => {
+        let fragment: Fragment = Fragment::new(fragment);
+        let new_fragment: Fragment = Fragment::new(new_fragment);
 
-        ensure!(
-          !(is_capability_invocation && capability_invocation_set.len() == 1),
-          UpdateError::InvalidMethodFragment("cannot remove last signing method")
-        );
+        let method_url: IotaDIDUrl = did.to_url().join(fragment.identifier())?;
+        let new_method_url: IotaDIDUrl = did.to_url().join(new_fragment.identifier())?;
+
+        let mut method: IotaVerificationMethod = document
+          .resolve_method(&method_url, None)
+          .cloned()
+          .ok_or(crate::Error::DIDError(identity_did::Error::MethodNotFound))?;
+
+        // Check method identifier is not duplicated.
+        if document.resolve_method(&new_method_url, None).is_some() {
+          return Err(crate::Error::DIDError(identity_did::Error::MethodAlreadyExists));
+        }
+
+        // The old method is removed by `DeleteExpiredMethods` once the grace period ended.
+        if let Some(grace_period) = grace_period {
+          let expires: Timestamp = Timestamp::now_utc()
+            .checked_add(grace_period)
+            .ok_or(UpdateError::InvalidGracePeriod)?;
+          method
+            .properties_mut()
+            .insert(METHOD_EXPIRES_PROPERTY.to_owned(), Value::String(expires.to_rfc3339()));
+        }
+
+        // Generate a new key of the same type, which is deleted again if the rotation fails.
+        let key_type: KeyType = KeyLocation::from_verification_method(&method)?.key_type;
+        let location: KeyLocation = storage.key_generate(did, key_type, new_fragment.name()).await?;
 
-        document.remove_method(&method_url)?;
+        if let Err(error) = rotate_method(did, document, storage, &location, method, new_fragment.name()).await {
+          // Ignore result: the rotation failed regardless.
+          let _ = storage.key_delete(did, &location).await;
+          return Err(error);
+        }
+
+        keys.added = Some(location);
+      }
+      Self::DeleteExpiredMethods {} => {
+        let now: Timestamp = Timestamp::now_utc();
+        let expired: Vec<IotaVerificationMethod> = document
+          .methods()
+          .filter(|method| method_expires(method).map_or(false, |expires| expires <= now))
+          .cloned()
+          .collect();
+
+        if expired.is_empty() {
+          return Ok(keys);
+        }
+
+        for method in expired {
+          delete_method(document, method.id())?;
+          keys.removed.push(KeyLocation::from_verification_method(&method)?);
+        }
       }
       Self::AttachMethodRelationship {
         fragment,
@@ -231,6 +326,30 @@ impl Update {
 
         document.remove_service(&service_url)?;
       }
+      Self::RevokeCredentials { fragment, indices } => {
+        let fragment: Fragment = Fragment::new(fragment);
+        let service_url: IotaDIDUrl = did.to_url().join(fragment.identifier())?;
+
+        // The service must exist
+        ensure!(
+          document.service().query(&service_url).is_some(),
+          UpdateError::ServiceNotFound
+        );
+
+        document.revoke_credentials(&service_url, &indices)?;
+      }
+      Self::UnrevokeCredentials { fragment, indices } => {
+        let fragment: Fragment = Fragment::new(fragment);
+        let service_url: IotaDIDUrl = did.to_url().join(fragment.identifier())?;
+
+        // The service must exist
+        ensure!(
+          document.service().query(&service_url).is_some(),
+          UpdateError::ServiceNotFound
+        );
+
+        document.unrevoke_credentials(&service_url, &indices)?;
+      }
       Self::SetController { controllers } => {
         *document.controller_mut() = controllers;
       }
@@ -242,8 +361,86 @@ impl Update {
 
     document.metadata.updated = Some(Timestamp::now_utc());
 
-    Ok(())
+    Ok(keys)
+  }
+}
+
+/// Removes the method identified by `method_url`, unless it is the last method capable of signing
+/// the DID document.
+fn delete_method(document: &mut IotaDocument, method_url: &IotaDIDUrl) -> Result<()> {
+  // Prevent deleting the last method capable of signing the DID document.
+  let capability_invocation_set = document.core_document().capability_invocation();
+  let is_capability_invocation = capability_invocation_set
+    .iter()
+    .any(|method_ref| method_ref.id() == method_url);
+
+  ensure!(
+    !(is_capability_invocation && capability_invocation_set.len() == 1),
+    UpdateError::InvalidMethodFragment("cannot remove last signing method")
+  );
+
+  document.remove_method(method_url)?;
+
+  Ok(())
+}
+
+/// Replaces the `method` of the `document` with a new method named `new_fragment` for the key at
+/// `location`, which takes over all verification relationships of the old method.
+///
+/// The old method keeps all relationships but `capabilityInvocation`. The `document` is left
+/// unchanged on failure.
+async fn rotate_method(
+  did: &IotaDID,
+  document: &mut IotaDocument,
+  storage: &dyn Storage,
+  location: &KeyLocation,
+  method: IotaVerificationMethod,
+  new_fragment: &str,
+) -> Result<()> {
+  let public: PublicKey = storage.key_public(did, location).await?;
+  let new_method: IotaVerificationMethod =
+    IotaVerificationMethod::new(did.clone(), location.key_type, &public, new_fragment)?;
+  let method_url: IotaDIDUrl = method.id().clone();
+  let new_method_url: IotaDIDUrl = new_method.id().clone();
+
+  let relationships: Vec<MethodRelationship> = MethodRelationship::ALL
+    .into_iter()
+    .filter(|relationship| {
+      document
+        .resolve_method(&method_url, Some(MethodScope::VerificationRelationship(*relationship)))
+        .is_some()
+    })
+    .collect();
+
+  let mut rotated: IotaDocument = document.clone();
+
+  rotated.insert_method(new_method, MethodScope::VerificationMethod)?;
+  for relationship in relationships.iter().copied() {
+    rotated.attach_method_relationship(&new_method_url, relationship)?;
+  }
+
+  rotated.remove_method(&method_url)?;
+  rotated.insert_method(method, MethodScope::VerificationMethod)?;
+  for relationship in relationships
+    .into_iter()
+    .filter(|relationship| *relationship != MethodRelationship::CapabilityInvocation)
+  {
+    rotated.attach_method_relationship(&method_url, relationship)?;
   }
+
+  *document = rotated;
+
+  Ok(())
+}
+
+/// Returns the time after which a rotated method is removed by
+/// [`delete_expired_methods`](crate::types::IdentityUpdater::delete_expired_methods), if any.
+fn method_expires(method: &IotaVerificationMethod) -> Option<Timestamp> {
+  method
+    .properties()
+    .get(METHOD_EXPIRES_PROPERTY)
+    .and_then(Value::as_str)
+    .and_then(|expires| Timestamp::parse(expires).ok())
 }
 
 async fn insert_method_secret(
@@ -295,6 +492,34 @@ DeleteMethod {
   @required fragment String,
 });
 
+impl_update_builder!(
+/// Rotate a method on an identity, replacing it with a new method with a newly generated key of the
+/// same type.
+///
+/// The new method takes over all verification relationships of the old method. The old method
+/// keeps its relationships except for `capabilityInvocation`, so existing signatures remain
+/// verifiable during the grace period while it can no longer update the DID document. Rotating the
+/// signing method of the DID document always results in an integration update signed with the old
+/// method.
+///
+/// # Parameters
+/// - `fragment`: the identifier of the method to rotate, required.
+/// - `new_fragment`: the identifier of the new method in the document, required.
+/// - `grace_period`: the time after which the old method expires and is removed by
+///   [`delete_expired_methods`](crate::types::IdentityUpdater::delete_expired_methods), optional. If unset, the
+///   old method is kept until it is deleted explicitly.
+RotateMethod {
+  @required fragment String,
+  @required new_fragment String,
+  @optional grace_period Duration,
+});
+
+impl_update_builder!(
+/// Delete all methods on an identity whose grace period after a rotation has ended.
+///
+/// The keys of the deleted methods are removed from the storage once the update is published.
+DeleteExpiredMethods {});
+
 impl_update_builder!(
 /// Attach one or more verification relationships to a method on an identity.
 ///
@@ -363,6 +588,28 @@ DeleteService {
   @required fragment String,
 });
 
+impl_update_builder!(
+/// Revoke one or more credentials in the revocation bitmap of a service on an identity.
+///
+/// # Parameters
+/// - `fragment`: the identifier of the `RevocationBitmap2022` service in the document, required.
+/// - `indices`: the revocation indices of the credentials, required.
+RevokeCredentials {
+  @required fragment String,
+  @required indices Vec<u32>,
+});
+
+impl_update_builder!(
+/// Lift the revocation of one or more credentials in the revocation bitmap of a service on an identity.
+///
+/// # Parameters
+/// - `fragment`: the identifier of the `RevocationBitmap2022` service in the document, required.
+/// - `indices`: the revocation indices of the credentials, required.
+UnrevokeCredentials {
+  @required fragment String,
+  @required indices Vec<u32>,
+});
+
 impl_update_builder!(
 SetController {
     @required controllers Option<OneOrSet<IotaDID>>,
@@ -95,6 +95,14 @@ impl<T> OneOrMany<T> {
     &*self
   }
 
+  /// Returns a mutable reference to the contents as a slice.
+  pub fn as_mut_slice(&mut self) -> &mut [T] {
+    match self {
+      Self::One(inner) => core::slice::from_mut(inner),
+      Self::Many(inner) => inner.as_mut_slice(),
+    }
+  }
+
   /// Consumes the [`OneOrMany`] and returns the contents as a [`Vec`].
   pub fn into_vec(self) -> Vec<T> {
     match self {
@@ -0,0 +1,608 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::marker::PhantomData;
+use std::convert::TryInto;
+
+use bls12_381::hash_to_curve::ExpandMsgXmd;
+use bls12_381::hash_to_curve::HashToCurve;
+use bls12_381::pairing;
+use bls12_381::G1Affine;
+use bls12_381::G1Projective;
+use bls12_381::G2Affine;
+use bls12_381::G2Projective;
+use bls12_381::Scalar;
+use sha2::Digest;
+use sha2::Sha256;
+use sha2::Sha512;
+use zeroize::Zeroize;
+
+use crate::error::Error;
+use crate::error::Result;
+
+// Domain separation tag for the message generators.
+const DST_GENERATOR: &[u8] = b"IOTA_IDENTITY_BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_";
+// Domain separation tag for hashing messages to scalars.
+const DST_MESSAGE: &[u8] = b"IOTA_IDENTITY_BBS_BLS12381_MESSAGE";
+// Domain separation tag for the Fiat-Shamir challenge of a proof of knowledge.
+const DST_CHALLENGE: &[u8] = b"IOTA_IDENTITY_BBS_BLS12381_CHALLENGE";
+
+const G1_LENGTH: usize = 48;
+const SCALAR_LENGTH: usize = 32;
+
+/// An implementation of `BBS+` signatures over the `BLS12-381` curve, with public keys in `G2`.
+///
+/// A `BBS+` signature covers an ordered list of messages; a holder can derive a zero-knowledge
+/// proof of knowledge of the signature which reveals only a subset of those messages.
+///
+/// Private keys are 32-byte little-endian scalars, public keys are compressed `G2` points and
+/// signatures are the 112-byte concatenation of a compressed `G1` point and two scalars.
+///
+/// NOTE: this implementation is experimental and unaudited. It does not follow the IETF BBS draft,
+/// uses its own domain separation tags and proof encoding, and is only available with the
+/// `bbs-experimental` feature.
+#[derive(Clone, Copy, Debug)]
+pub struct Bls12381G2<T: ?Sized = [u8]>(PhantomData<T>);
+
+impl Bls12381G2 {
+  /// Length in bytes of a BLS12-381 private key.
+  pub const PRIVATE_KEY_LENGTH: usize = 32;
+  /// Length in bytes of a compressed BLS12-381 `G2` public key.
+  pub const PUBLIC_KEY_LENGTH: usize = 96;
+  /// Length in bytes of a BBS+ signature.
+  pub const SIGNATURE_LENGTH: usize = G1_LENGTH + 2 * SCALAR_LENGTH;
+
+  /// Derives a zero-knowledge proof of knowledge of a BBS+ `signature` over `messages` which
+  /// discloses only the messages at the `revealed` indices.
+  ///
+  /// The `nonce` is bound to the proof and should be supplied by the verifier to prevent replays.
+  /// Deriving a proof does not require any key material.
+  pub fn derive_proof(messages: &[Vec<u8>], revealed: &[usize], signature: &[u8], nonce: &[u8]) -> Result<Vec<u8>> {
+    let signature: Signature = Signature::from_bytes(signature)?;
+    let messages: Vec<Scalar> = messages
+      .iter()
+      .map(|message| hash_to_scalar(DST_MESSAGE, message))
+      .collect();
+
+    if !revealed.windows(2).all(|pair| pair[0] < pair[1]) || revealed.iter().any(|index| *index >= messages.len()) {
+      return Err(Error::InvalidProofValue("bbs+ revealed indices"));
+    }
+
+    let hidden: Vec<usize> = (0..messages.len()).filter(|index| !revealed.contains(index)).collect();
+    let generators: Vec<G1Projective> = generators(messages.len());
+
+    // Randomize the signature.
+    let r1: Scalar = random_scalar()?;
+    let r2: Scalar = random_scalar()?;
+    let r3: Scalar = Option::from(r1.invert()).ok_or(Error::InvalidProofValue("bbs+"))?;
+
+    let b: G1Projective = commitment(&messages, &signature.s);
+    let a_prime: G1Projective = signature.a * r1;
+    let a_bar: G1Projective = a_prime * -signature.e + b * r1;
+    let d: G1Projective = b * r1 - generators[0] * r2;
+    let s_prime: Scalar = signature.s - r2 * r3;
+
+    // Commit to the blinding factors of both proofs of knowledge:
+    //   A_bar - d = A' * -e + h0 * r2
+    //   g1 + sum(h_i * m_i) = d * r3 - h0 * s' - sum(h_j * m_j), for revealed i and hidden j
+    let blind_e: Scalar = random_scalar()?;
+    let blind_r2: Scalar = random_scalar()?;
+    let blind_r3: Scalar = random_scalar()?;
+    let blind_s: Scalar = random_scalar()?;
+    let blind_m: Vec<Scalar> = hidden.iter().map(|_| random_scalar()).collect::<Result<_>>()?;
+
+    let t1: G1Projective = a_prime * -blind_e + generators[0] * blind_r2;
+    let t2: G1Projective = hidden
+      .iter()
+      .zip(blind_m.iter())
+      .fold(d * blind_r3 - generators[0] * blind_s, |acc, (index, blind)| {
+        acc - generators[index + 1] * blind
+      });
+
+    let mut proof: SignatureProof = SignatureProof {
+      total: messages.len(),
+      revealed: revealed.to_vec(),
+      nonce: nonce.to_vec(),
+      a_prime: a_prime.into(),
+      a_bar: a_bar.into(),
+      d: d.into(),
+      challenge: Scalar::zero(),
+      response_e: Scalar::zero(),
+      response_r2: Scalar::zero(),
+      response_r3: Scalar::zero(),
+      response_s: Scalar::zero(),
+      response_m: Vec::with_capacity(hidden.len()),
+    };
+
+    let challenge: Scalar = proof.challenge(&t1, &t2, &messages)?;
+
+    proof.challenge = challenge;
+    proof.response_e = blind_e + challenge * signature.e;
+    proof.response_r2 = blind_r2 + challenge * r2;
+    proof.response_r3 = blind_r3 + challenge * r3;
+    proof.response_s = blind_s + challenge * s_prime;
+    proof.response_m = hidden
+      .iter()
+      .zip(blind_m.iter())
+      .map(|(index, blind)| blind + challenge * messages[*index])
+      .collect();
+
+    Ok(proof.to_bytes())
+  }
+}
+
+impl<T> Bls12381G2<T>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  /// Computes a BBS+ signature over all `messages` using a BLS12-381 private key.
+  pub fn sign_messages(messages: &[Vec<u8>], key: &T) -> Result<[u8; Bls12381G2::SIGNATURE_LENGTH]> {
+    let key: Scalar = bls12381_private_try_from_bytes(key.as_ref())?;
+    let messages: Vec<Scalar> = messages
+      .iter()
+      .map(|message| hash_to_scalar(DST_MESSAGE, message))
+      .collect();
+
+    let e: Scalar = random_scalar()?;
+    let s: Scalar = random_scalar()?;
+
+    let exponent: Scalar = Option::from((key + e).invert()).ok_or(Error::InvalidProofValue("bbs+"))?;
+    let a: G1Projective = commitment(&messages, &s) * exponent;
+
+    Ok(Signature { a: a.into(), e, s }.to_bytes())
+  }
+
+  /// Verifies a BBS+ signature over all `messages` against a BLS12-381 public key.
+  pub fn verify_messages(messages: &[Vec<u8>], signature: &[u8], key: &T) -> Result<()> {
+    let key: G2Affine = bls12381_public_try_from_bytes(key.as_ref())?;
+    let signature: Signature = Signature::from_bytes(signature)?;
+    let messages: Vec<Scalar> = messages
+      .iter()
+      .map(|message| hash_to_scalar(DST_MESSAGE, message))
+      .collect();
+
+    if bool::from(signature.a.is_identity()) {
+      return Err(Error::InvalidProofValue("bbs+"));
+    }
+
+    let lhs: G2Affine = (G2Projective::generator() * signature.e + key).into();
+    let rhs: G1Affine = commitment(&messages, &signature.s).into();
+
+    if pairing(&signature.a, &lhs) != pairing(&rhs, &G2Affine::generator()) {
+      return Err(Error::InvalidProofValue("bbs+"));
+    }
+
+    Ok(())
+  }
+
+  /// Verifies a proof created with [`Bls12381G2::derive_proof`] against the `revealed` messages,
+  /// given in their original order, the `nonce` expected by the verifier and a BLS12-381 public key.
+  pub fn verify_proof(revealed: &[Vec<u8>], proof: &[u8], nonce: &[u8], key: &T) -> Result<()> {
+    let key: G2Affine = bls12381_public_try_from_bytes(key.as_ref())?;
+    let proof: SignatureProof = SignatureProof::from_bytes(proof)?;
+
+    if proof.nonce != nonce {
+      return Err(Error::InvalidProofValue("bbs+ nonce"));
+    }
+
+    if revealed.len() != proof.revealed.len() {
+      return Err(Error::InvalidProofValue("bbs+ revealed messages"));
+    }
+
+    if bool::from(proof.a_prime.is_identity()) {
+      return Err(Error::InvalidProofValue("bbs+"));
+    }
+
+    if pairing(&proof.a_prime, &key) != pairing(&proof.a_bar, &G2Affine::generator()) {
+      return Err(Error::InvalidProofValue("bbs+"));
+    }
+
+    let generators: Vec<G1Projective> = generators(proof.total);
+    let hidden: Vec<usize> = proof.hidden();
+
+    // The messages are only needed at the revealed positions to recompute the challenge.
+    let mut messages: Vec<Scalar> = vec![Scalar::zero(); proof.total];
+    for (index, message) in proof.revealed.iter().zip(revealed.iter()) {
+      messages[*index] = hash_to_scalar(DST_MESSAGE, message);
+    }
+
+    let public: G1Projective = proof.revealed.iter().fold(G1Projective::generator(), |acc, index| {
+      acc + generators[index + 1] * messages[*index]
+    });
+
+    let a_prime: G1Projective = proof.a_prime.into();
+    let a_bar: G1Projective = proof.a_bar.into();
+    let d: G1Projective = proof.d.into();
+
+    let t1: G1Projective =
+      a_prime * -proof.response_e + generators[0] * proof.response_r2 - (a_bar - d) * proof.challenge;
+    let t2: G1Projective = hidden.iter().zip(proof.response_m.iter()).fold(
+      d * proof.response_r3 - generators[0] * proof.response_s - public * proof.challenge,
+      |acc, (index, response)| acc - generators[index + 1] * response,
+    );
+
+    if proof.challenge(&t1, &t2, &messages)? != proof.challenge {
+      return Err(Error::InvalidProofValue("bbs+"));
+    }
+
+    Ok(())
+  }
+}
+
+/// A BBS+ signature `(A, e, s)`.
+struct Signature {
+  a: G1Affine,
+  e: Scalar,
+  s: Scalar,
+}
+
+impl Signature {
+  fn to_bytes(&self) -> [u8; Bls12381G2::SIGNATURE_LENGTH] {
+    let mut output: [u8; Bls12381G2::SIGNATURE_LENGTH] = [0; Bls12381G2::SIGNATURE_LENGTH];
+    output[..G1_LENGTH].copy_from_slice(&self.a.to_compressed());
+    output[G1_LENGTH..G1_LENGTH + SCALAR_LENGTH].copy_from_slice(&self.e.to_bytes());
+    output[G1_LENGTH + SCALAR_LENGTH..].copy_from_slice(&self.s.to_bytes());
+    output
+  }
+
+  fn from_bytes(slice: &[u8]) -> Result<Self> {
+    if slice.len() != Bls12381G2::SIGNATURE_LENGTH {
+      return Err(Error::InvalidSigLength(slice.len(), Bls12381G2::SIGNATURE_LENGTH));
+    }
+
+    let mut reader: Reader<'_> = Reader(slice);
+
+    Ok(Self {
+      a: reader.g1()?,
+      e: reader.scalar()?,
+      s: reader.scalar()?,
+    })
+  }
+}
+
+/// A proof of knowledge of a BBS+ signature, together with the information needed to verify it.
+struct SignatureProof {
+  total: usize,
+  revealed: Vec<usize>,
+  nonce: Vec<u8>,
+  a_prime: G1Affine,
+  a_bar: G1Affine,
+  d: G1Affine,
+  challenge: Scalar,
+  response_e: Scalar,
+  response_r2: Scalar,
+  response_r3: Scalar,
+  response_s: Scalar,
+  response_m: Vec<Scalar>,
+}
+
+impl SignatureProof {
+  fn hidden(&self) -> Vec<usize> {
+    (0..self.total).filter(|index| !self.revealed.contains(index)).collect()
+  }
+
+  fn challenge(&self, t1: &G1Projective, t2: &G1Projective, messages: &[Scalar]) -> Result<Scalar> {
+    let mut input: Vec<u8> = Vec::new();
+
+    for point in [self.a_prime, self.a_bar, self.d, t1.into(), t2.into()] {
+      input.extend_from_slice(&point.to_compressed());
+    }
+
+    input.extend_from_slice(&encode_length(self.total)?);
+    input.extend_from_slice(&encode_length(self.revealed.len())?);
+
+    for index in self.revealed.iter() {
+      input.extend_from_slice(&encode_length(*index)?);
+      input.extend_from_slice(&messages[*index].to_bytes());
+    }
+
+    input.extend_from_slice(&encode_length(self.nonce.len())?);
+    input.extend_from_slice(&self.nonce);
+
+    Ok(hash_to_scalar(DST_CHALLENGE, &input))
+  }
+
+  fn to_bytes(&self) -> Vec<u8> {
+    let mut output: Vec<u8> = Vec::new();
+
+    // Lengths were validated when the proof was created.
+    output.extend_from_slice(&(self.total as u32).to_be_bytes());
+    output.extend_from_slice(&(self.revealed.len() as u32).to_be_bytes());
+
+    for index in self.revealed.iter() {
+      output.extend_from_slice(&(*index as u32).to_be_bytes());
+    }
+
+    output.extend_from_slice(&(self.nonce.len() as u32).to_be_bytes());
+    output.extend_from_slice(&self.nonce);
+
+    for point in [self.a_prime, self.a_bar, self.d] {
+      output.extend_from_slice(&point.to_compressed());
+    }
+
+    for scalar in [
+      self.challenge,
+      self.response_e,
+      self.response_r2,
+      self.response_r3,
+      self.response_s,
+    ]
+    .iter()
+    .chain(self.response_m.iter())
+    {
+      output.extend_from_slice(&scalar.to_bytes());
+    }
+
+    output
+  }
+
+  fn from_bytes(slice: &[u8]) -> Result<Self> {
+    let mut reader: Reader<'_> = Reader(slice);
+
+    let total: usize = reader.length()?;
+    let revealed: Vec<usize> = (0..reader.length()?).map(|_| reader.length()).collect::<Result<_>>()?;
+
+    if !revealed.windows(2).all(|pair| pair[0] < pair[1]) || revealed.iter().any(|index| *index >= total) {
+      return Err(Error::InvalidProofValue("bbs+ revealed indices"));
+    }
+
+    let nonce_length: usize = reader.length()?;
+    let nonce: Vec<u8> = reader.take(nonce_length)?.to_vec();
+
+    let a_prime: G1Affine = reader.g1()?;
+    let a_bar: G1Affine = reader.g1()?;
+    let d: G1Affine = reader.g1()?;
+    let challenge: Scalar = reader.scalar()?;
+    let response_e: Scalar = reader.scalar()?;
+    let response_r2: Scalar = reader.scalar()?;
+    let response_r3: Scalar = reader.scalar()?;
+    let response_s: Scalar = reader.scalar()?;
+    let response_m: Vec<Scalar> = (0..total - revealed.len())
+      .map(|_| reader.scalar())
+      .collect::<Result<_>>()?;
+
+    if !reader.0.is_empty() {
+      return Err(Error::InvalidProofValue("bbs+"));
+    }
+
+    Ok(Self {
+      total,
+      revealed,
+      nonce,
+      a_prime,
+      a_bar,
+      d,
+      challenge,
+      response_e,
+      response_r2,
+      response_r3,
+      response_s,
+      response_m,
+    })
+  }
+}
+
+/// A cursor over an encoded signature or proof.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+  fn take(&mut self, length: usize) -> Result<&'a [u8]> {
+    if self.0.len() < length {
+      return Err(Error::InvalidProofValue("bbs+"));
+    }
+
+    let (head, tail): (&[u8], &[u8]) = self.0.split_at(length);
+    self.0 = tail;
+
+    Ok(head)
+  }
+
+  fn length(&mut self) -> Result<usize> {
+    let bytes: [u8; 4] = self.take(4)?.try_into().map_err(|_| Error::InvalidProofValue("bbs+"))?;
+
+    Ok(u32::from_be_bytes(bytes) as usize)
+  }
+
+  fn g1(&mut self) -> Result<G1Affine> {
+    let bytes: &[u8; G1_LENGTH] = self
+      .take(G1_LENGTH)?
+      .try_into()
+      .map_err(|_| Error::InvalidProofValue("bbs+"))?;
+
+    Option::from(G1Affine::from_compressed(bytes)).ok_or(Error::InvalidProofValue("bbs+"))
+  }
+
+  fn scalar(&mut self) -> Result<Scalar> {
+    let bytes: &[u8; SCALAR_LENGTH] = self
+      .take(SCALAR_LENGTH)?
+      .try_into()
+      .map_err(|_| Error::InvalidProofValue("bbs+"))?;
+
+    Option::from(Scalar::from_bytes(bytes)).ok_or(Error::InvalidProofValue("bbs+"))
+  }
+}
+
+fn encode_length(length: usize) -> Result<[u8; 4]> {
+  u32::try_from(length)
+    .map(u32::to_be_bytes)
+    .map_err(|_| Error::InvalidProofValue("bbs+ message count"))
+}
+
+/// Returns the commitment `B = g1 + h0 * s + sum(h_i * m_i)` to the given messages.
+fn commitment(messages: &[Scalar], s: &Scalar) -> G1Projective {
+  let generators: Vec<G1Projective> = generators(messages.len());
+
+  messages.iter().zip(generators[1..].iter()).fold(
+    G1Projective::generator() + generators[0] * s,
+    |acc, (message, generator)| acc + generator * message,
+  )
+}
+
+/// Returns the blinding generator `h0` followed by one generator for each of `count` messages.
+///
+/// The generators are hashed to the curve, so nobody knows their discrete logarithms.
+fn generators(count: usize) -> Vec<G1Projective> {
+  (0..=count as u64)
+    .map(|index| <G1Projective as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(index.to_be_bytes(), DST_GENERATOR))
+    .collect()
+}
+
+fn hash_to_scalar(dst: &[u8], message: &[u8]) -> Scalar {
+  let mut digest: [u8; 64] = [0; 64];
+  digest.copy_from_slice(&Sha512::new().chain(dst).chain(message).finalize());
+
+  Scalar::from_bytes_wide(&digest)
+}
+
+fn random_scalar() -> Result<Scalar> {
+  let mut bytes: [u8; 64] = [0; 64];
+  crypto::utils::rand::fill(&mut bytes)?;
+
+  let scalar: Scalar = Scalar::from_bytes_wide(&bytes);
+  bytes.zeroize();
+
+  Ok(scalar)
+}
+
+/// Generates a new random BLS12-381 private key, returning its bytes and the matching public key.
+pub(crate) fn bls12381_generate() -> Result<(
+  [u8; Bls12381G2::PRIVATE_KEY_LENGTH],
+  [u8; Bls12381G2::PUBLIC_KEY_LENGTH],
+)> {
+  // Rejection sampling: the chance of sampling zero is negligible.
+  let key: Scalar = loop {
+    let key: Scalar = random_scalar()?;
+
+    if key != Scalar::zero() {
+      break key;
+    }
+  };
+
+  Ok((key.to_bytes(), bls12381_public_key(&key)))
+}
+
+/// Reconstructs a BLS12-381 private key from a byte array, returning the matching public key.
+pub(crate) fn bls12381_public_from_private(bytes: &[u8]) -> Result<[u8; Bls12381G2::PUBLIC_KEY_LENGTH]> {
+  bls12381_private_try_from_bytes(bytes).map(|key| bls12381_public_key(&key))
+}
+
+fn bls12381_public_key(key: &Scalar) -> [u8; Bls12381G2::PUBLIC_KEY_LENGTH] {
+  G2Affine::from(G2Projective::generator() * key).to_compressed()
+}
+
+fn bls12381_private_try_from_bytes(bytes: &[u8]) -> Result<Scalar> {
+  let bytes: &[u8; Bls12381G2::PRIVATE_KEY_LENGTH] = bytes
+    .try_into()
+    .map_err(|_| Error::InvalidKeyLength(bytes.len(), Bls12381G2::PRIVATE_KEY_LENGTH))?;
+
+  Option::from(Scalar::from_bytes(bytes))
+    .filter(|key| *key != Scalar::zero())
+    .ok_or(Error::InvalidKeyFormat)
+}
+
+fn bls12381_public_try_from_bytes(bytes: &[u8]) -> Result<G2Affine> {
+  let bytes: &[u8; Bls12381G2::PUBLIC_KEY_LENGTH] = bytes
+    .try_into()
+    .map_err(|_| Error::InvalidKeyLength(bytes.len(), Bls12381G2::PUBLIC_KEY_LENGTH))?;
+
+  Option::from(G2Affine::from_compressed(bytes))
+    .filter(|key: &G2Affine| !bool::from(key.is_identity()))
+    .ok_or(Error::InvalidKeyFormat)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn messages() -> Vec<Vec<u8>> {
+    vec![
+      b"name".to_vec(),
+      b"degree".to_vec(),
+      b"GPA".to_vec(),
+      b"birthdate".to_vec(),
+    ]
+  }
+
+  #[test]
+  fn test_bls12381_can_sign_and_verify() {
+    let (private_key, public_key) = bls12381_generate().unwrap();
+    let messages: Vec<Vec<u8>> = messages();
+
+    let signature: [u8; 112] = Bls12381G2::sign_messages(&messages, &private_key).unwrap();
+    assert!(Bls12381G2::verify_messages(&messages, &signature, &public_key).is_ok());
+
+    // Fails when a message is mutated
+    let mut mutated: Vec<Vec<u8>> = messages.clone();
+    mutated[1] = b"degrees".to_vec();
+    assert!(Bls12381G2::verify_messages(&mutated, &signature, &public_key).is_err());
+
+    // Fails when the messages are reordered
+    let mut reordered: Vec<Vec<u8>> = messages.clone();
+    reordered.swap(0, 1);
+    assert!(Bls12381G2::verify_messages(&reordered, &signature, &public_key).is_err());
+
+    // Fails with another key
+    let (_, other) = bls12381_generate().unwrap();
+    assert!(Bls12381G2::verify_messages(&messages, &signature, &other).is_err());
+  }
+
+  #[test]
+  fn test_bls12381_derive_and_verify_proof() {
+    let (private_key, public_key) = bls12381_generate().unwrap();
+    let messages: Vec<Vec<u8>> = messages();
+
+    let signature: [u8; 112] = Bls12381G2::sign_messages(&messages, &private_key).unwrap();
+    let proof: Vec<u8> = Bls12381G2::derive_proof(&messages, &[0, 2], &signature, b"nonce").unwrap();
+
+    let revealed: Vec<Vec<u8>> = vec![messages[0].clone(), messages[2].clone()];
+    assert!(Bls12381G2::verify_proof(&revealed, &proof, b"nonce", &public_key).is_ok());
+
+    // Fails when the verifier expects another nonce
+    assert!(Bls12381G2::verify_proof(&revealed, &proof, b"other", &public_key).is_err());
+
+    // Fails when a revealed message is mutated
+    let mutated: Vec<Vec<u8>> = vec![messages[0].clone(), messages[1].clone()];
+    assert!(Bls12381G2::verify_proof(&mutated, &proof, b"nonce", &public_key).is_err());
+
+    // Fails when the number of revealed messages does not match
+    assert!(Bls12381G2::verify_proof(&revealed[..1], &proof, b"nonce", &public_key).is_err());
+
+    // Fails with another key
+    let (_, other) = bls12381_generate().unwrap();
+    assert!(Bls12381G2::verify_proof(&revealed, &proof, b"nonce", &other).is_err());
+
+    // Fails when the proof is mutated
+    let mut mutated: Vec<u8> = proof.clone();
+    let last: usize = mutated.len() - 1;
+    mutated[last] ^= 0x01;
+    assert!(Bls12381G2::verify_proof(&revealed, &mutated, b"nonce", &public_key).is_err());
+
+    // Proofs are unlinkable
+    let other: Vec<u8> = Bls12381G2::derive_proof(&messages, &[0, 2], &signature, b"nonce").unwrap();
+    assert_ne!(proof, other);
+    assert!(Bls12381G2::verify_proof(&revealed, &other, b"nonce", &public_key).is_ok());
+  }
+
+  #[test]
+  fn test_bls12381_derive_proof_invalid_indices() {
+    let (private_key, _) = bls12381_generate().unwrap();
+    let messages: Vec<Vec<u8>> = messages();
+    let signature: [u8; 112] = Bls12381G2::sign_messages(&messages, &private_key).unwrap();
+
+    assert!(Bls12381G2::derive_proof(&messages, &[2, 0], &signature, &[]).is_err());
+    assert!(Bls12381G2::derive_proof(&messages, &[1, 1], &signature, &[]).is_err());
+    assert!(Bls12381G2::derive_proof(&messages, &[4], &signature, &[]).is_err());
+  }
+
+  #[test]
+  fn test_bls12381_invalid_lengths() {
+    assert!(matches!(
+      bls12381_public_from_private(&[1; 31]),
+      Err(Error::InvalidKeyLength(31, 32))
+    ));
+    assert!(matches!(
+      Signature::from_bytes(&[1; 111]),
+      Err(Error::InvalidSigLength(111, 112))
+    ));
+  }
+}
@@ -3,15 +3,23 @@
 
 #![allow(clippy::module_inception)]
 
+#[cfg(feature = "bbs-experimental")]
+pub use self::bls12381::Bls12381G2;
 pub use self::ed25519::Ed25519;
 pub use self::key::PrivateKey;
 pub use self::key::PublicKey;
+pub use self::p256::P256;
 pub use self::pair::KeyPair;
+pub use self::secp256k1::Secp256k1;
 pub use self::type_::KeyType;
 pub use self::x25519::X25519;
 
+#[cfg(feature = "bbs-experimental")]
+mod bls12381;
 mod ed25519;
 mod key;
+mod p256;
 mod pair;
+mod secp256k1;
 mod type_;
 mod x25519;
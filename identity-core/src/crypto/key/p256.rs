@@ -0,0 +1,169 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::marker::PhantomData;
+
+use p256::ecdsa::signature::Signature as _;
+use p256::ecdsa::signature::Signer as _;
+use p256::ecdsa::signature::Verifier as _;
+use p256::ecdsa::Signature;
+use p256::ecdsa::SigningKey;
+use p256::ecdsa::VerifyingKey;
+use zeroize::Zeroize;
+
+use crate::crypto::Sign;
+use crate::crypto::Verify;
+use crate::error::Error;
+use crate::error::Result;
+
+/// An implementation of `ECDSA` signatures over the NIST `P-256` curve (`ES256`).
+///
+/// Private keys are 32-byte big-endian scalars, public keys are SEC1-encoded
+/// curve points and signatures are the 64-byte concatenation of `r` and `s`.
+#[derive(Clone, Copy, Debug)]
+pub struct P256<T: ?Sized = [u8]>(PhantomData<T>);
+
+impl P256 {
+  /// Length in bytes of a P-256 private key.
+  pub const PRIVATE_KEY_LENGTH: usize = 32;
+  /// Length in bytes of an uncompressed SEC1-encoded P-256 public key.
+  pub const PUBLIC_KEY_LENGTH: usize = 65;
+  /// Length in bytes of a P-256 signature.
+  pub const SIGNATURE_LENGTH: usize = 64;
+  /// Length in bytes of a compressed SEC1-encoded P-256 public key.
+  pub const COMPRESSED_PUBLIC_KEY_LENGTH: usize = 33;
+
+  /// Converts a SEC1-encoded P-256 public key into its compressed form.
+  pub fn compress_public_key(public_key: &[u8]) -> Result<Vec<u8>> {
+    let key: VerifyingKey = p256_public_try_from_bytes(public_key)?;
+    Ok(key.to_encoded_point(true).as_bytes().to_vec())
+  }
+
+  /// Converts a SEC1-encoded P-256 public key into its uncompressed form.
+  pub fn decompress_public_key(public_key: &[u8]) -> Result<Vec<u8>> {
+    let key: VerifyingKey = p256_public_try_from_bytes(public_key)?;
+    Ok(key.to_encoded_point(false).as_bytes().to_vec())
+  }
+}
+
+impl<T> Sign for P256<T>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  type Private = T;
+  type Output = [u8; P256::SIGNATURE_LENGTH];
+
+  /// Computes an ECDSA signature over the SHA-256 digest of `message` using a P-256 private key.
+  fn sign(message: &[u8], key: &Self::Private) -> Result<Self::Output> {
+    let key: SigningKey = p256_private_try_from_bytes(key.as_ref())?;
+    let signature: Signature = key.sign(message);
+
+    let mut output: Self::Output = [0; P256::SIGNATURE_LENGTH];
+    output.copy_from_slice(signature.as_bytes());
+
+    Ok(output)
+  }
+}
+
+impl<T> Verify for P256<T>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  type Public = T;
+
+  /// Verifies an ECDSA signature against a P-256 public key.
+  fn verify(message: &[u8], signature: &[u8], key: &Self::Public) -> Result<()> {
+    let key: VerifyingKey = p256_public_try_from_bytes(key.as_ref())?;
+    let sig: Signature = parse_signature(signature)?;
+
+    key.verify(message, &sig).map_err(|_| Error::InvalidProofValue("p-256"))
+  }
+}
+
+fn parse_signature(slice: &[u8]) -> Result<Signature> {
+  if slice.len() != P256::SIGNATURE_LENGTH {
+    return Err(Error::InvalidSigLength(slice.len(), P256::SIGNATURE_LENGTH));
+  }
+
+  Signature::from_bytes(slice).map_err(|_| Error::InvalidProofValue("p-256"))
+}
+
+/// Generates a new random P-256 private key.
+pub(crate) fn p256_private_generate() -> Result<SigningKey> {
+  let mut bytes: [u8; P256::PRIVATE_KEY_LENGTH] = [0; P256::PRIVATE_KEY_LENGTH];
+
+  // Rejection sampling: the chance of hitting an invalid scalar is negligible.
+  let key: SigningKey = loop {
+    crypto::utils::rand::fill(&mut bytes)?;
+
+    if let Ok(key) = SigningKey::from_bytes(&bytes) {
+      break key;
+    }
+  };
+
+  bytes.zeroize();
+
+  Ok(key)
+}
+
+/// Reconstructs a P-256 private key from a byte array.
+pub(crate) fn p256_private_try_from_bytes(bytes: &[u8]) -> Result<SigningKey> {
+  if bytes.len() != P256::PRIVATE_KEY_LENGTH {
+    return Err(Error::InvalidKeyLength(bytes.len(), P256::PRIVATE_KEY_LENGTH));
+  }
+
+  SigningKey::from_bytes(bytes).map_err(|_| Error::InvalidKeyFormat)
+}
+
+/// Reconstructs a P-256 public key from a compressed or uncompressed SEC1-encoded byte array.
+pub(crate) fn p256_public_try_from_bytes(bytes: &[u8]) -> Result<VerifyingKey> {
+  VerifyingKey::from_sec1_bytes(bytes).map_err(|_| Error::InvalidKeyFormat)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const SECRET_KEY_HEX: &str = "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+  const MESSAGE: &[u8] = b"IOTA Identity";
+
+  #[test]
+  fn test_p256_can_sign_and_verify() {
+    let private_key: Vec<u8> = hex::decode(SECRET_KEY_HEX).unwrap();
+    let public_key: Vec<u8> = p256_private_try_from_bytes(&private_key)
+      .unwrap()
+      .verifying_key()
+      .to_encoded_point(false)
+      .as_bytes()
+      .to_vec();
+    assert_eq!(public_key.len(), P256::PUBLIC_KEY_LENGTH);
+
+    let signature: [u8; 64] = P256::sign(MESSAGE, &private_key).unwrap();
+    assert!(P256::verify(MESSAGE, &signature, &public_key).is_ok());
+
+    // Fails when the message is mutated
+    assert!(P256::verify(b"IOTA", &signature, &public_key).is_err());
+
+    // The public key survives a compression round trip
+    let compressed: Vec<u8> = P256::compress_public_key(&public_key).unwrap();
+    assert_eq!(compressed.len(), P256::COMPRESSED_PUBLIC_KEY_LENGTH);
+    assert_eq!(P256::decompress_public_key(&compressed).unwrap(), public_key);
+
+    // Fails when the signature is mutated
+    let mut mutated: [u8; 64] = signature;
+    mutated[0] ^= 0xff;
+    assert!(P256::verify(MESSAGE, &mutated, &public_key).is_err());
+  }
+
+  #[test]
+  fn test_p256_invalid_lengths() {
+    assert!(matches!(
+      p256_private_try_from_bytes(&[1; 31]),
+      Err(Error::InvalidKeyLength(31, 32))
+    ));
+    assert!(matches!(
+      parse_signature(&[1; 63]),
+      Err(Error::InvalidSigLength(63, 64))
+    ));
+  }
+}
@@ -7,7 +7,17 @@ use crypto::keys::x25519;
 use crypto::signatures::ed25519;
 use zeroize::Zeroize;
 
+#[cfg(feature = "bbs-experimental")]
+use crate::crypto::key::bls12381::bls12381_generate;
+#[cfg(feature = "bbs-experimental")]
+use crate::crypto::key::bls12381::bls12381_public_from_private;
 use crate::crypto::key::ed25519::ed25519_private_try_from_bytes;
+use crate::crypto::key::p256::p256_private_generate;
+use crate::crypto::key::p256::p256_private_try_from_bytes;
+use crate::crypto::key::secp256k1::secp256k1_private_generate;
+use crate::crypto::key::secp256k1::secp256k1_private_try_from_bytes;
+#[cfg(feature = "bbs-experimental")]
+use crate::crypto::Bls12381G2;
 use crate::crypto::KeyType;
 use crate::crypto::PrivateKey;
 use crate::crypto::PublicKey;
@@ -46,6 +56,35 @@ impl KeyPair {
         let public: PublicKey = public.to_bytes().to_vec().into();
         (public, private)
       }
+      KeyType::Secp256k1 => {
+        let secret: k256::ecdsa::SigningKey = secp256k1_private_generate()?;
+
+        let private: PrivateKey = secret.to_bytes().to_vec().into();
+        let public: PublicKey = secret.verifying_key().to_bytes().to_vec().into();
+        (public, private)
+      }
+      KeyType::P256 => {
+        let secret: p256::ecdsa::SigningKey = p256_private_generate()?;
+
+        let private: PrivateKey = secret.to_bytes().to_vec().into();
+        let public: PublicKey = secret
+          .verifying_key()
+          .to_encoded_point(false)
+          .as_bytes()
+          .to_vec()
+          .into();
+        (public, private)
+      }
+      #[cfg(feature = "bbs-experimental")]
+      KeyType::Bls12381G2 => {
+        let (secret, public) = bls12381_generate()?;
+
+        let private: PrivateKey = secret.to_vec().into();
+        let public: PublicKey = public.to_vec().into();
+        (public, private)
+      }
+      #[cfg(not(feature = "bbs-experimental"))]
+      KeyType::Bls12381G2 => return Err(crate::Error::UnsupportedKeyType(type_)),
     };
 
     Ok(Self { type_, public, private })
@@ -56,6 +95,16 @@ impl KeyPair {
   /// The private key for [`Ed25519`][`KeyType::Ed25519`] must be a 32-byte seed in compliance
   /// with [RFC 8032](https://datatracker.ietf.org/doc/html/rfc8032#section-3.2).
   /// Other implementations often use another format. See [this blog post](https://blog.mozilla.org/warner/2011/11/29/ed25519-keys/) for further explanation.
+  ///
+  /// The private key for [`Secp256k1`][`KeyType::Secp256k1`] must be a 32-byte big-endian scalar.
+  /// The derived public key is encoded as a 33-byte compressed SEC1 point.
+  ///
+  /// The private key for [`P256`][`KeyType::P256`] must be a 32-byte big-endian scalar.
+  /// The derived public key is encoded as a 65-byte uncompressed SEC1 point.
+  ///
+  /// The private key for [`Bls12381G2`][`KeyType::Bls12381G2`] must be a 32-byte little-endian scalar.
+  /// The derived public key is encoded as a 96-byte compressed `G2` point. These keys require the
+  /// `bbs-experimental` feature.
   pub fn try_from_private_key_bytes(key_type: KeyType, private_key_bytes: &[u8]) -> Result<Self> {
     let (public, private) = match key_type {
       KeyType::Ed25519 => {
@@ -77,6 +126,35 @@ impl KeyPair {
         let public: PublicKey = public_key.to_bytes().to_vec().into();
         (public, private)
       }
+      KeyType::Secp256k1 => {
+        let private_key: k256::ecdsa::SigningKey = secp256k1_private_try_from_bytes(private_key_bytes)?;
+
+        let private: PrivateKey = private_key.to_bytes().to_vec().into();
+        let public: PublicKey = private_key.verifying_key().to_bytes().to_vec().into();
+        (public, private)
+      }
+      KeyType::P256 => {
+        let private_key: p256::ecdsa::SigningKey = p256_private_try_from_bytes(private_key_bytes)?;
+
+        let private: PrivateKey = private_key.to_bytes().to_vec().into();
+        let public: PublicKey = private_key
+          .verifying_key()
+          .to_encoded_point(false)
+          .as_bytes()
+          .to_vec()
+          .into();
+        (public, private)
+      }
+      #[cfg(feature = "bbs-experimental")]
+      KeyType::Bls12381G2 => {
+        let public_key: [u8; Bls12381G2::PUBLIC_KEY_LENGTH] = bls12381_public_from_private(private_key_bytes)?;
+
+        let private: PrivateKey = private_key_bytes.to_vec().into();
+        let public: PublicKey = public_key.to_vec().into();
+        (public, private)
+      }
+      #[cfg(not(feature = "bbs-experimental"))]
+      KeyType::Bls12381G2 => return Err(crate::Error::UnsupportedKeyType(key_type)),
     };
 
     Ok(Self {
@@ -146,9 +224,41 @@ mod tests {
     assert_eq!(keypair.private().as_ref().len(), 32);
   }
 
+  #[test]
+  fn test_new_secp256k1() {
+    let keypair: KeyPair = KeyPair::new(KeyType::Secp256k1).unwrap();
+    assert_eq!(keypair.type_(), KeyType::Secp256k1);
+    assert_eq!(keypair.public().as_ref().len(), 33);
+    assert_eq!(keypair.private().as_ref().len(), 32);
+  }
+
+  #[test]
+  fn test_new_p256() {
+    let keypair: KeyPair = KeyPair::new(KeyType::P256).unwrap();
+    assert_eq!(keypair.type_(), KeyType::P256);
+    assert_eq!(keypair.public().as_ref().len(), 65);
+    assert_eq!(keypair.private().as_ref().len(), 32);
+  }
+
+  #[cfg(feature = "bbs-experimental")]
+  #[test]
+  fn test_new_bls12381g2() {
+    let keypair: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+    assert_eq!(keypair.type_(), KeyType::Bls12381G2);
+    assert_eq!(keypair.public().as_ref().len(), 96);
+    assert_eq!(keypair.private().as_ref().len(), 32);
+  }
+
   #[test]
   fn test_try_from_private_key_bytes() {
-    for key_type in [KeyType::Ed25519, KeyType::X25519] {
+    for key_type in [
+      KeyType::Ed25519,
+      KeyType::X25519,
+      KeyType::Secp256k1,
+      KeyType::P256,
+      #[cfg(feature = "bbs-experimental")]
+      KeyType::Bls12381G2,
+    ] {
       let keypair: KeyPair = KeyPair::new(key_type).unwrap();
       let reconstructed: KeyPair = KeyPair::try_from_private_key_bytes(key_type, keypair.private.as_ref()).unwrap();
       assert_eq!(keypair.private.as_ref(), reconstructed.private.as_ref());
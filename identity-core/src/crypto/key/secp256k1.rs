@@ -0,0 +1,151 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::marker::PhantomData;
+
+use k256::ecdsa::signature::Signature as _;
+use k256::ecdsa::signature::Signer as _;
+use k256::ecdsa::signature::Verifier as _;
+use k256::ecdsa::Signature;
+use k256::ecdsa::SigningKey;
+use k256::ecdsa::VerifyingKey;
+use zeroize::Zeroize;
+
+use crate::crypto::Sign;
+use crate::crypto::Verify;
+use crate::error::Error;
+use crate::error::Result;
+
+/// An implementation of `ECDSA` signatures over the `secp256k1` curve (`ES256K`).
+///
+/// Private keys are 32-byte big-endian scalars, public keys are SEC1-encoded
+/// curve points and signatures are the 64-byte concatenation of `r` and `s`.
+#[derive(Clone, Copy, Debug)]
+pub struct Secp256k1<T: ?Sized = [u8]>(PhantomData<T>);
+
+impl Secp256k1 {
+  /// Length in bytes of a secp256k1 private key.
+  pub const PRIVATE_KEY_LENGTH: usize = 32;
+  /// Length in bytes of a compressed SEC1-encoded secp256k1 public key.
+  pub const PUBLIC_KEY_LENGTH: usize = 33;
+  /// Length in bytes of a secp256k1 signature.
+  pub const SIGNATURE_LENGTH: usize = 64;
+}
+
+impl<T> Sign for Secp256k1<T>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  type Private = T;
+  type Output = [u8; Secp256k1::SIGNATURE_LENGTH];
+
+  /// Computes an ECDSA signature over the SHA-256 digest of `message` using a secp256k1 private key.
+  fn sign(message: &[u8], key: &Self::Private) -> Result<Self::Output> {
+    let key: SigningKey = secp256k1_private_try_from_bytes(key.as_ref())?;
+    let signature: Signature = key.sign(message);
+
+    let mut output: Self::Output = [0; Secp256k1::SIGNATURE_LENGTH];
+    output.copy_from_slice(signature.as_bytes());
+
+    Ok(output)
+  }
+}
+
+impl<T> Verify for Secp256k1<T>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  type Public = T;
+
+  /// Verifies an ECDSA signature against a secp256k1 public key.
+  fn verify(message: &[u8], signature: &[u8], key: &Self::Public) -> Result<()> {
+    let key: VerifyingKey = secp256k1_public_try_from_bytes(key.as_ref())?;
+    let sig: Signature = parse_signature(signature)?;
+
+    key
+      .verify(message, &sig)
+      .map_err(|_| Error::InvalidProofValue("secp256k1"))
+  }
+}
+
+fn parse_signature(slice: &[u8]) -> Result<Signature> {
+  if slice.len() != Secp256k1::SIGNATURE_LENGTH {
+    return Err(Error::InvalidSigLength(slice.len(), Secp256k1::SIGNATURE_LENGTH));
+  }
+
+  Signature::from_bytes(slice).map_err(|_| Error::InvalidProofValue("secp256k1"))
+}
+
+/// Generates a new random secp256k1 private key.
+pub(crate) fn secp256k1_private_generate() -> Result<SigningKey> {
+  let mut bytes: [u8; Secp256k1::PRIVATE_KEY_LENGTH] = [0; Secp256k1::PRIVATE_KEY_LENGTH];
+
+  // Rejection sampling: the chance of hitting an invalid scalar is negligible.
+  let key: SigningKey = loop {
+    crypto::utils::rand::fill(&mut bytes)?;
+
+    if let Ok(key) = SigningKey::from_bytes(&bytes) {
+      break key;
+    }
+  };
+
+  bytes.zeroize();
+
+  Ok(key)
+}
+
+/// Reconstructs a secp256k1 private key from a byte array.
+pub(crate) fn secp256k1_private_try_from_bytes(bytes: &[u8]) -> Result<SigningKey> {
+  if bytes.len() != Secp256k1::PRIVATE_KEY_LENGTH {
+    return Err(Error::InvalidKeyLength(bytes.len(), Secp256k1::PRIVATE_KEY_LENGTH));
+  }
+
+  SigningKey::from_bytes(bytes).map_err(|_| Error::InvalidKeyFormat)
+}
+
+/// Reconstructs a secp256k1 public key from a compressed or uncompressed SEC1-encoded byte array.
+pub(crate) fn secp256k1_public_try_from_bytes(bytes: &[u8]) -> Result<VerifyingKey> {
+  VerifyingKey::from_sec1_bytes(bytes).map_err(|_| Error::InvalidKeyFormat)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const SECRET_KEY_HEX: &str = "d1b35c9b8f1b5a7c8d3ec0fc5b7d6b4c3d34ce1d0cf2cf0b2e1ec4c26cfd3e12";
+  const MESSAGE: &[u8] = b"IOTA Identity";
+
+  #[test]
+  fn test_secp256k1_can_sign_and_verify() {
+    let private_key: Vec<u8> = hex::decode(SECRET_KEY_HEX).unwrap();
+    let public_key: Vec<u8> = secp256k1_private_try_from_bytes(&private_key)
+      .unwrap()
+      .verifying_key()
+      .to_bytes()
+      .to_vec();
+    assert_eq!(public_key.len(), Secp256k1::PUBLIC_KEY_LENGTH);
+
+    let signature: [u8; 64] = Secp256k1::sign(MESSAGE, &private_key).unwrap();
+    assert!(Secp256k1::verify(MESSAGE, &signature, &public_key).is_ok());
+
+    // Fails when the message is mutated
+    assert!(Secp256k1::verify(b"IOTA", &signature, &public_key).is_err());
+
+    // Fails when the signature is mutated
+    let mut mutated: [u8; 64] = signature;
+    mutated[0] ^= 0xff;
+    assert!(Secp256k1::verify(MESSAGE, &mutated, &public_key).is_err());
+  }
+
+  #[test]
+  fn test_secp256k1_invalid_lengths() {
+    assert!(matches!(
+      secp256k1_private_try_from_bytes(&[1; 31]),
+      Err(Error::InvalidKeyLength(31, 32))
+    ));
+    assert!(matches!(
+      parse_signature(&[1; 63]),
+      Err(Error::InvalidSigLength(63, 64))
+    ));
+  }
+}
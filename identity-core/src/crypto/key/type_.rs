@@ -18,6 +18,12 @@ pub enum KeyType {
   Ed25519,
   /// An `X25519` cryptographic key.
   X25519,
+  /// A `secp256k1` cryptographic key.
+  Secp256k1,
+  /// A NIST `P-256` cryptographic key.
+  P256,
+  /// A `BLS12-381` cryptographic key with its public key in `G2`, used for `BBS+` signatures.
+  Bls12381G2,
 }
 
 impl KeyType {
@@ -26,6 +32,9 @@ impl KeyType {
     match self {
       Self::Ed25519 => "Ed25519",
       Self::X25519 => "X25519",
+      Self::Secp256k1 => "Secp256k1",
+      Self::P256 => "P256",
+      Self::Bls12381G2 => "Bls12381G2",
     }
   }
 }
@@ -38,6 +47,12 @@ impl FromStr for KeyType {
       Ok(Self::Ed25519)
     } else if string.eq_ignore_ascii_case("X25519") {
       Ok(Self::X25519)
+    } else if string.eq_ignore_ascii_case("Secp256k1") {
+      Ok(Self::Secp256k1)
+    } else if string.eq_ignore_ascii_case("P256") {
+      Ok(Self::P256)
+    } else if string.eq_ignore_ascii_case("Bls12381G2") {
+      Ok(Self::Bls12381G2)
     } else {
       Err(Error::InvalidKeyFormat)
     }
@@ -58,7 +73,13 @@ mod tests {
 
   #[test]
   fn test_key_type_serde() {
-    for key_type in [KeyType::Ed25519, KeyType::X25519] {
+    for key_type in [
+      KeyType::Ed25519,
+      KeyType::X25519,
+      KeyType::Secp256k1,
+      KeyType::P256,
+      KeyType::Bls12381G2,
+    ] {
       let ser: Value = serde_json::to_value(&key_type).unwrap();
       assert_eq!(ser.as_str().unwrap(), key_type.as_str());
       let de: KeyType = serde_json::from_value(ser.clone()).unwrap();
@@ -3,13 +3,23 @@
 
 //! Cryptographic Utilities
 
+#[cfg(feature = "bbs-experimental")]
+pub use self::key::Bls12381G2;
 pub use self::key::Ed25519;
 pub use self::key::KeyPair;
 pub use self::key::KeyType;
 pub use self::key::PrivateKey;
 pub use self::key::PublicKey;
+pub use self::key::Secp256k1;
+pub use self::key::P256;
 pub use self::key::X25519;
+pub use self::proof::EcdsaSecp256k1;
+#[cfg(feature = "bbs-experimental")]
+pub use self::proof::JcsBbs;
+#[cfg(feature = "bbs-experimental")]
+pub use self::proof::JcsBbsProof;
 pub use self::proof::JcsEd25519;
+pub use self::proof::JwsP256;
 pub use self::proof::Proof;
 pub use self::proof::ProofOptions;
 pub use self::proof::ProofPurpose;
@@ -0,0 +1,118 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::marker::PhantomData;
+
+use serde::Serialize;
+
+use crate::convert::ToJson;
+use crate::crypto::Named;
+use crate::crypto::ProofValue;
+use crate::crypto::Secp256k1;
+use crate::crypto::Sign;
+use crate::crypto::Signer;
+use crate::crypto::Verifier;
+use crate::crypto::Verify;
+use crate::error::Error;
+use crate::error::Result;
+use crate::utils::decode_b58;
+use crate::utils::encode_b58;
+
+/// An implementation of a JCS ECDSA secp256k1 signature suite
+/// for [Linked Data Proofs][SPEC1].
+///
+/// The data is canonicalized with [JCS](https://tools.ietf.org/html/rfc8785) and the signature is
+/// encoded in base58, as with [`JcsEd25519`][crate::crypto::JcsEd25519]. This is not the
+/// [Ecdsa Secp256k1 Signature 2019][SPEC2] suite, which uses URDNA2015 and detached JWS.
+///
+/// Users should use the [`Sign`]/[`Verify`] traits to access
+/// this implementation.
+///
+/// [SPEC1]: https://w3c-ccg.github.io/ld-proofs/
+/// [SPEC2]: https://w3c-ccg.github.io/lds-ecdsa-secp256k1-2019/
+pub struct EcdsaSecp256k1<T = Secp256k1>(PhantomData<T>);
+
+impl<T> Named for EcdsaSecp256k1<T> {
+  const NAME: &'static str = "JcsEcdsaSecp256k1Signature2022";
+}
+
+impl<T> Signer<T::Private> for EcdsaSecp256k1<T>
+where
+  T: Sign,
+  T::Output: AsRef<[u8]>,
+{
+  fn sign<X>(data: &X, private: &T::Private) -> Result<ProofValue>
+  where
+    X: Serialize,
+  {
+    let message: Vec<u8> = data.to_jcs()?;
+    let signature: T::Output = T::sign(&message, private)?;
+    let signature: String = encode_b58(signature.as_ref());
+
+    Ok(ProofValue::Signature(signature))
+  }
+}
+
+impl<T> Verifier<T::Public> for EcdsaSecp256k1<T>
+where
+  T: Verify,
+{
+  fn verify<X>(data: &X, signature: &ProofValue, public: &T::Public) -> Result<()>
+  where
+    X: Serialize,
+  {
+    let signature: &str = signature
+      .as_signature()
+      .ok_or(Error::InvalidProofValue("ecdsa secp256k1"))?;
+
+    let signature: Vec<u8> = decode_b58(signature)?;
+    let message: Vec<u8> = data.to_jcs()?;
+
+    T::verify(&message, &signature, public)?;
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::common::Value;
+  use crate::crypto::EcdsaSecp256k1;
+  use crate::crypto::KeyPair;
+  use crate::crypto::KeyType;
+  use crate::crypto::PrivateKey;
+  use crate::crypto::ProofValue;
+  use crate::crypto::PublicKey;
+  use crate::crypto::Secp256k1;
+  use crate::crypto::Signer as _;
+  use crate::crypto::Verifier as _;
+  use crate::json;
+
+  type Signer = EcdsaSecp256k1<Secp256k1<PrivateKey>>;
+
+  type Verifier = EcdsaSecp256k1<Secp256k1<PublicKey>>;
+
+  #[test]
+  fn test_sign_verify() {
+    let key1: KeyPair = KeyPair::new(KeyType::Secp256k1).unwrap();
+    let key2: KeyPair = KeyPair::new(KeyType::Secp256k1).unwrap();
+
+    let data1: Value = json!({ "msg": "IOTA Identity" });
+    let data2: Value = json!({ "msg": "IOTA Identity 2" });
+
+    let signature: ProofValue = Signer::sign(&data1, key1.private()).unwrap();
+
+    // The signature should be valid
+    assert!(Verifier::verify(&data1, &signature, key1.public()).is_ok());
+
+    // Modified data should be invalid
+    assert!(Verifier::verify(&data2, &signature, key1.public()).is_err());
+
+    // A modified key should be invalid
+    assert!(Verifier::verify(&data1, &signature, key2.public()).is_err());
+
+    // A modified signature should be invalid
+    let signature: ProofValue = ProofValue::Signature("IOTA".into());
+    assert!(Verifier::verify(&data1, &signature, key1.public()).is_err());
+  }
+}
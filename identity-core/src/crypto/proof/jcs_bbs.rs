@@ -0,0 +1,392 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::marker::PhantomData;
+
+use serde::Serialize;
+
+use crate::common::Value;
+use crate::convert::ToJson;
+use crate::crypto::Bls12381G2;
+use crate::crypto::GetSignature;
+use crate::crypto::Named;
+use crate::crypto::Proof;
+use crate::crypto::ProofOptions;
+use crate::crypto::ProofValue;
+use crate::crypto::SetSignature;
+use crate::crypto::Signer;
+use crate::crypto::Verifier;
+use crate::error::Error;
+use crate::error::Result;
+use crate::utils::decode_b58;
+use crate::utils::encode_b58;
+
+/// A BBS+ signature suite over [JCS](https://tools.ietf.org/html/rfc8785)-canonicalized JSON
+/// for [Linked Data Proofs][SPEC1].
+///
+/// The data is split into one message per JSON leaf value, identified by its
+/// [JSON Pointer](https://tools.ietf.org/html/rfc6901), so that a holder can later disclose a
+/// subset of the leaves with a [`JcsBbsProof`].
+///
+/// NOTE: this suite is experimental, unaudited and specific to this library. It is not interoperable
+/// with the [BBS+ Signatures 2020](https://w3c-ccg.github.io/ldp-bbs2020/) suite, which signs
+/// RDF-canonicalized data, and is only available with the `bbs-experimental` feature.
+///
+/// Users should use the [`Signer`]/[`Verifier`] traits to access
+/// this implementation.
+///
+/// [SPEC1]: https://w3c-ccg.github.io/ld-proofs/
+pub struct JcsBbs<T = Bls12381G2>(PhantomData<T>);
+
+impl<T> Named for JcsBbs<T> {
+  const NAME: &'static str = "JcsBbsSignature";
+}
+
+impl<T> Signer<T> for JcsBbs<Bls12381G2<T>>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  fn sign<X>(data: &X, private: &T) -> Result<ProofValue>
+  where
+    X: Serialize,
+  {
+    let messages: Vec<Vec<u8>> = messages(data, false)?;
+    let signature: [u8; Bls12381G2::SIGNATURE_LENGTH] = Bls12381G2::sign_messages(&messages, private)?;
+
+    Ok(ProofValue::Proof(encode_b58(&signature)))
+  }
+}
+
+impl<T> Verifier<T> for JcsBbs<Bls12381G2<T>>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  fn verify<X>(data: &X, signature: &ProofValue, public: &T) -> Result<()>
+  where
+    X: Serialize,
+  {
+    let signature: &str = signature.as_proof().ok_or(Error::InvalidProofValue("bbs+"))?;
+    let signature: Vec<u8> = decode_b58(signature)?;
+    let messages: Vec<Vec<u8>> = messages(data, false)?;
+
+    Bls12381G2::verify_messages(&messages, &signature, public)
+  }
+}
+
+// =============================================================================
+// =============================================================================
+
+/// The proof suite derived from a [`JcsBbs`] signature.
+///
+/// A proof of this type is derived by the holder of data signed with a [`JcsBbs`] signature
+/// and proves knowledge of the issuer's signature while disclosing only the JSON leaf values
+/// which are still present in the derived data.
+///
+/// Each proof is bound to a challenge chosen by the verifier, which is set as the `challenge` of the
+/// derived proof so that proofs cannot be replayed to other verifiers.
+pub struct JcsBbsProof<T = Bls12381G2>(PhantomData<T>);
+
+impl<T> Named for JcsBbsProof<T> {
+  const NAME: &'static str = "JcsBbsSignatureProof";
+}
+
+impl JcsBbsProof {
+  /// Derives a [`JcsBbsProof`] from the [`JcsBbs`] signature of `signed` and applies it to
+  /// `disclosed`.
+  ///
+  /// `disclosed` must be a copy of `signed` with some of its JSON properties removed; every
+  /// remaining leaf value must be unchanged. The `challenge` is bound to the derived proof.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `signed` is not signed with a [`JcsBbs`] signature or if `disclosed` contains
+  /// a value which is not covered by the signature.
+  pub fn derive<X, Y>(signed: &X, disclosed: &mut Y, challenge: &str) -> Result<()>
+  where
+    X: Serialize + GetSignature,
+    Y: Serialize + SetSignature,
+  {
+    let signature: &Proof = signed.signature().ok_or(Error::MissingSignature)?;
+
+    if signature.type_() != JcsBbs::<Bls12381G2>::NAME {
+      return Err(Error::InvalidProofValue("signature name"));
+    }
+
+    let value: &str = signature.value().as_proof().ok_or(Error::InvalidProofValue("bbs+"))?;
+    let value: Vec<u8> = decode_b58(value)?;
+
+    signature.hide_value();
+    let messages: Result<Vec<Vec<u8>>> = messages(signed, false);
+    signature.show_value();
+    let messages: Vec<Vec<u8>> = messages?;
+
+    // The derived proof keeps the other properties of the original signature, which are signed as well.
+    let options: ProofOptions = ProofOptions {
+      created: signature.created,
+      expires: signature.expires,
+      challenge: Some(challenge.to_owned()),
+      domain: signature.domain.clone(),
+      purpose: signature.purpose,
+    };
+    let proof: Proof = Proof::new_with_options(Self::NAME, signature.verification_method(), options);
+    disclosed.set_signature(proof);
+
+    // Messages are sorted, so the indices of the disclosed messages are sorted as well.
+    let revealed: Vec<usize> = self::messages(&disclosed, true)?
+      .iter()
+      .map(|message| messages.binary_search(message))
+      .collect::<Result<_, _>>()
+      .map_err(|_| Error::InvalidProofValue("bbs+ disclosed data"))?;
+
+    let proof: Vec<u8> = Bls12381G2::derive_proof(&messages, &revealed, &value, challenge.as_bytes())?;
+    let write: &mut Proof = disclosed.signature_mut().ok_or(Error::MissingSignature)?;
+    write.set_value(ProofValue::Proof(encode_b58(&proof)));
+
+    Ok(())
+  }
+}
+
+impl<T> JcsBbsProof<Bls12381G2<T>>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  /// Extracts and verifies a derived proof from the given `data`.
+  ///
+  /// The proof must have been derived with the `challenge` of the proof. Verifiers must check that
+  /// it is the challenge they supplied to the holder.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the proof is invalid or has no challenge.
+  pub fn verify_signature<X>(data: &X, public: &T) -> Result<()>
+  where
+    X: Serialize + GetSignature,
+  {
+    let signature: &Proof = data.signature().ok_or(Error::MissingSignature)?;
+
+    if signature.type_() != Self::NAME {
+      return Err(Error::InvalidProofValue("signature name"));
+    }
+
+    let proof: &str = signature.value().as_proof().ok_or(Error::InvalidProofValue("bbs+"))?;
+    let proof: Vec<u8> = decode_b58(proof)?;
+    let challenge: &str = signature
+      .challenge
+      .as_deref()
+      .ok_or(Error::InvalidProofValue("bbs+ challenge"))?;
+
+    signature.hide_value();
+    let messages: Result<Vec<Vec<u8>>> = messages(data, true);
+    signature.show_value();
+
+    Bls12381G2::verify_proof(&messages?, &proof, challenge.as_bytes(), public)
+  }
+}
+
+/// Splits `data` into the sorted list of messages covered by the `BBS+` suites.
+///
+/// Each message is the JCS-canonicalized pair of a JSON Pointer and the leaf value it points to.
+/// The proof `type` is not covered since it differs between a signature and a derived proof, nor
+/// is the `challenge` of a `derived` proof, which is bound to the proof instead.
+fn messages<X>(data: &X, derived: bool) -> Result<Vec<Vec<u8>>>
+where
+  X: Serialize,
+{
+  let mut data: Value = data.to_json_value()?;
+
+  if let Some(Value::Object(proof)) = data.get_mut("proof") {
+    proof.remove("type");
+
+    if derived {
+      proof.remove("challenge");
+    }
+  }
+
+  let mut leaves: Vec<(String, &Value)> = Vec::new();
+  flatten(String::new(), &data, &mut leaves);
+
+  let mut messages: Vec<Vec<u8>> = leaves.into_iter().map(|leaf| leaf.to_jcs()).collect::<Result<_>>()?;
+  messages.sort_unstable();
+
+  Ok(messages)
+}
+
+fn flatten<'a>(pointer: String, value: &'a Value, leaves: &mut Vec<(String, &'a Value)>) {
+  match value {
+    Value::Object(object) if !object.is_empty() => {
+      for (key, value) in object {
+        flatten(
+          format!("{}/{}", pointer, key.replace('~', "~0").replace('/', "~1")),
+          value,
+          leaves,
+        );
+      }
+    }
+    Value::Array(array) if !array.is_empty() => {
+      for (index, value) in array.iter().enumerate() {
+        flatten(format!("{}/{}", pointer, index), value, leaves);
+      }
+    }
+    _ => leaves.push((pointer, value)),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde::Serialize;
+
+  use crate::common::Object;
+  use crate::common::Value;
+  use crate::convert::FromJson;
+  use crate::crypto::Bls12381G2;
+  use crate::crypto::GetSignature;
+  use crate::crypto::GetSignatureMut;
+  use crate::crypto::JcsBbs;
+  use crate::crypto::JcsBbsProof;
+  use crate::crypto::KeyPair;
+  use crate::crypto::KeyType;
+  use crate::crypto::PrivateKey;
+  use crate::crypto::Proof;
+  use crate::crypto::ProofOptions;
+  use crate::crypto::ProofValue;
+  use crate::crypto::PublicKey;
+  use crate::crypto::SetSignature;
+  use crate::crypto::Signer as _;
+  use crate::crypto::Verifier as _;
+  use crate::json;
+
+  type Signer = JcsBbs<Bls12381G2<PrivateKey>>;
+
+  type Verifier = JcsBbs<Bls12381G2<PublicKey>>;
+
+  type ProofVerifier = JcsBbsProof<Bls12381G2<PublicKey>>;
+
+  #[derive(Clone, Serialize)]
+  struct Data {
+    #[serde(flatten)]
+    properties: Object,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proof: Option<Proof>,
+  }
+
+  impl GetSignature for Data {
+    fn signature(&self) -> Option<&Proof> {
+      self.proof.as_ref()
+    }
+  }
+
+  impl GetSignatureMut for Data {
+    fn signature_mut(&mut self) -> Option<&mut Proof> {
+      self.proof.as_mut()
+    }
+  }
+
+  impl SetSignature for Data {
+    fn set_signature(&mut self, signature: Proof) {
+      self.proof = Some(signature);
+    }
+  }
+
+  fn data() -> Data {
+    let properties: Object = Object::from_json_value(json!({
+      "id": "did:example:123",
+      "name": "Alice",
+      "degree": { "type": "BachelorDegree", "name": "Bachelor of Science and Arts" },
+      "GPA": "4.0",
+      "courses": ["Cryptography", "Distributed Systems"],
+    }))
+    .unwrap();
+
+    Data {
+      properties,
+      proof: None,
+    }
+  }
+
+  #[test]
+  fn test_sign_verify() {
+    let key1: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+    let key2: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+
+    let data1: Value = json!({ "msg": "IOTA Identity" });
+    let data2: Value = json!({ "msg": "IOTA Identity 2" });
+
+    let signature: ProofValue = Signer::sign(&data1, key1.private()).unwrap();
+
+    // The signature should be valid
+    assert!(Verifier::verify(&data1, &signature, key1.public()).is_ok());
+
+    // Modified data should be invalid
+    assert!(Verifier::verify(&data2, &signature, key1.public()).is_err());
+
+    // A modified key should be invalid
+    assert!(Verifier::verify(&data1, &signature, key2.public()).is_err());
+
+    // A modified signature should be invalid
+    let signature: ProofValue = ProofValue::Proof("IOTA".into());
+    assert!(Verifier::verify(&data1, &signature, key1.public()).is_err());
+  }
+
+  #[test]
+  fn test_derive_verify() {
+    let key: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+
+    let mut signed: Data = data();
+    Signer::create_signature(&mut signed, "#bbs", key.private(), ProofOptions::default()).unwrap();
+    assert!(Verifier::verify_signature(&signed, key.public()).is_ok());
+
+    // Disclose everything but the degree and the GPA
+    let mut disclosed: Data = signed.clone();
+    disclosed.properties.remove("degree");
+    disclosed.properties.remove("GPA");
+    JcsBbsProof::derive(&signed, &mut disclosed, "challenge").unwrap();
+
+    assert_eq!(disclosed.signature().unwrap().type_(), "JcsBbsSignatureProof");
+    assert_eq!(disclosed.signature().unwrap().verification_method(), "#bbs");
+    assert_eq!(disclosed.signature().unwrap().challenge.as_deref(), Some("challenge"));
+    assert!(ProofVerifier::verify_signature(&disclosed, key.public()).is_ok());
+
+    // A proof with another challenge should be invalid
+    let mut modified: Data = disclosed.clone();
+    modified.signature_mut().unwrap().challenge = Some("other".to_owned());
+    assert!(ProofVerifier::verify_signature(&modified, key.public()).is_err());
+    modified.signature_mut().unwrap().challenge = None;
+    assert!(ProofVerifier::verify_signature(&modified, key.public()).is_err());
+
+    // The derived proof is not a signature
+    assert!(Verifier::verify_signature(&disclosed, key.public()).is_err());
+
+    // Adding back an undisclosed value should be invalid
+    let mut modified: Data = disclosed.clone();
+    modified.properties.insert("GPA".into(), "4.0".into());
+    assert!(ProofVerifier::verify_signature(&modified, key.public()).is_err());
+
+    // Modifying a disclosed value should be invalid
+    let mut modified: Data = disclosed.clone();
+    modified.properties.insert("name".into(), "Bob".into());
+    assert!(ProofVerifier::verify_signature(&modified, key.public()).is_err());
+
+    // A modified key should be invalid
+    let other: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+    assert!(ProofVerifier::verify_signature(&disclosed, other.public()).is_err());
+  }
+
+  #[test]
+  fn test_derive_invalid_disclosure() {
+    let key: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+
+    let mut signed: Data = data();
+    Signer::create_signature(&mut signed, "#bbs", key.private(), ProofOptions::default()).unwrap();
+
+    // Values which are not covered by the signature cannot be disclosed
+    let mut disclosed: Data = signed.clone();
+    disclosed.properties.insert("name".into(), "Bob".into());
+    assert!(JcsBbsProof::derive(&signed, &mut disclosed, "challenge").is_err());
+
+    // Only JcsBbs signatures can be used to derive proofs
+    let mut signed: Data = data();
+    signed.set_signature(Proof::new("JcsEd25519Signature2020", "#bbs"));
+    let mut disclosed: Data = signed.clone();
+    assert!(JcsBbsProof::derive(&signed, &mut disclosed, "challenge").is_err());
+  }
+}
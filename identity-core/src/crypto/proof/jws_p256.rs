@@ -0,0 +1,149 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::marker::PhantomData;
+
+use serde::Serialize;
+
+use crate::convert::ToJson;
+use crate::crypto::Named;
+use crate::crypto::ProofValue;
+use crate::crypto::Sign;
+use crate::crypto::Signer;
+use crate::crypto::Verifier;
+use crate::crypto::Verify;
+use crate::crypto::P256;
+use crate::error::Error;
+use crate::error::Result;
+use crate::utils::decode_b64;
+use crate::utils::encode_b64;
+
+// The protected header of every detached `ES256` JWS created by this suite.
+//
+// The payload is not base64url-encoded (see RFC 7797), so the signing input is the encoded header,
+// a period and the raw JCS-canonicalized data.
+const JWS_HEADER: &str = r#"{"alg":"ES256","b64":false,"crit":["b64"]}"#;
+
+/// An implementation of a JCS JSON Web Signature suite for [Linked Data Proofs][SPEC1],
+/// using `ES256` (ECDSA over NIST `P-256`).
+///
+/// The data is canonicalized with [JCS](https://tools.ietf.org/html/rfc8785) and signed as the
+/// unencoded payload of a detached JWS, which is stored as a [`ProofValue::Jws`]. This is not the
+/// [JSON Web Signature 2020][SPEC2] suite, which canonicalizes the data with URDNA2015.
+///
+/// Users should use the [`Sign`]/[`Verify`] traits to access
+/// this implementation.
+///
+/// [SPEC1]: https://w3c-ccg.github.io/ld-proofs/
+/// [SPEC2]: https://w3c-ccg.github.io/lds-jws2020/
+pub struct JwsP256<T = P256>(PhantomData<T>);
+
+impl<T> Named for JwsP256<T> {
+  const NAME: &'static str = "JcsJwsP256Signature2022";
+}
+
+impl<T> Signer<T::Private> for JwsP256<T>
+where
+  T: Sign,
+  T::Output: AsRef<[u8]>,
+{
+  fn sign<X>(data: &X, private: &T::Private) -> Result<ProofValue>
+  where
+    X: Serialize,
+  {
+    let header: String = encode_b64(JWS_HEADER);
+    let message: Vec<u8> = signing_input(&header, data)?;
+    let signature: T::Output = T::sign(&message, private)?;
+
+    Ok(ProofValue::Jws(format!(
+      "{}..{}",
+      header,
+      encode_b64(signature.as_ref())
+    )))
+  }
+}
+
+impl<T> Verifier<T::Public> for JwsP256<T>
+where
+  T: Verify,
+{
+  fn verify<X>(data: &X, signature: &ProofValue, public: &T::Public) -> Result<()>
+  where
+    X: Serialize,
+  {
+    let jws: &str = signature.as_jws().ok_or(Error::InvalidProofValue("jws p-256"))?;
+
+    let (header, signature): (&str, &str) = match jws.split('.').collect::<Vec<_>>().as_slice() {
+      [header, "", signature] => (*header, *signature),
+      _ => return Err(Error::InvalidProofValue("jws p-256")),
+    };
+
+    if decode_b64(header)? != JWS_HEADER.as_bytes() {
+      return Err(Error::InvalidProofValue("jws p-256"));
+    }
+
+    let signature: Vec<u8> = decode_b64(signature)?;
+    let message: Vec<u8> = signing_input(header, data)?;
+
+    T::verify(&message, &signature, public)?;
+
+    Ok(())
+  }
+}
+
+fn signing_input<X>(header: &str, data: &X) -> Result<Vec<u8>>
+where
+  X: Serialize,
+{
+  let mut message: Vec<u8> = Vec::from(header.as_bytes());
+  message.push(b'.');
+  message.extend(data.to_jcs()?);
+
+  Ok(message)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::common::Value;
+  use crate::crypto::JwsP256;
+  use crate::crypto::KeyPair;
+  use crate::crypto::KeyType;
+  use crate::crypto::PrivateKey;
+  use crate::crypto::ProofValue;
+  use crate::crypto::PublicKey;
+  use crate::crypto::Signer as _;
+  use crate::crypto::Verifier as _;
+  use crate::crypto::P256;
+  use crate::json;
+
+  type Signer = JwsP256<P256<PrivateKey>>;
+
+  type Verifier = JwsP256<P256<PublicKey>>;
+
+  #[test]
+  fn test_sign_verify() {
+    let key1: KeyPair = KeyPair::new(KeyType::P256).unwrap();
+    let key2: KeyPair = KeyPair::new(KeyType::P256).unwrap();
+
+    let data1: Value = json!({ "msg": "IOTA Identity" });
+    let data2: Value = json!({ "msg": "IOTA Identity 2" });
+
+    let signature: ProofValue = Signer::sign(&data1, key1.private()).unwrap();
+
+    // The signature is a detached JWS
+    assert!(signature.as_jws().unwrap().contains(".."));
+
+    // The signature should be valid
+    assert!(Verifier::verify(&data1, &signature, key1.public()).is_ok());
+
+    // Modified data should be invalid
+    assert!(Verifier::verify(&data2, &signature, key1.public()).is_err());
+
+    // A modified key should be invalid
+    assert!(Verifier::verify(&data1, &signature, key2.public()).is_err());
+
+    // A non-JWS proof value should be invalid
+    let signature: ProofValue = ProofValue::Signature("IOTA".into());
+    assert!(Verifier::verify(&data1, &signature, key1.public()).is_err());
+  }
+}
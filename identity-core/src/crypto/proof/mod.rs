@@ -6,13 +6,23 @@
 //! Types and traits for helping ensure the authenticity and integrity of
 //! DID Documents and Verifiable Credentials.
 
+pub use self::ecdsa_secp256k1::EcdsaSecp256k1;
+#[cfg(feature = "bbs-experimental")]
+pub use self::jcs_bbs::JcsBbs;
+#[cfg(feature = "bbs-experimental")]
+pub use self::jcs_bbs::JcsBbsProof;
 pub use self::jcs_ed25519::JcsEd25519;
+pub use self::jws_p256::JwsP256;
 pub use self::proof::Proof;
 pub use self::proof_options::ProofOptions;
 pub use self::proof_options::ProofPurpose;
 pub use self::proof_value::ProofValue;
 
+mod ecdsa_secp256k1;
+#[cfg(feature = "bbs-experimental")]
+mod jcs_bbs;
 mod jcs_ed25519;
+mod jws_p256;
 mod proof;
 mod proof_options;
 mod proof_value;
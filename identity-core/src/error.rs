@@ -24,6 +24,9 @@ pub enum Error {
   /// Caused by a failure to decode multibase-encoded data.
   #[error("Failed to decode multibase data: {0}")]
   DecodeMultibase(#[from] multibase::Error),
+  /// Caused by a failure to decode base64url-encoded data.
+  #[error("Failed to decode base64 data: {0}")]
+  DecodeBase64(multibase::Error),
   /// Caused by attempting to perform an invalid `Diff` operation.
   #[deprecated(since = "0.5.0", note = "diff chain features are slated for removal")]
   #[error("Invalid Document Diff: {0}")]
@@ -47,6 +50,9 @@ pub enum Error {
   /// Raised by a validation attempt against an invalid DID proof.
   #[error("Invalid Proof Value: {0}")]
   InvalidProofValue(&'static str),
+  /// Caused by a key type which is not supported, e.g. because a feature is disabled.
+  #[error("Unsupported Key Type: {0}")]
+  UnsupportedKeyType(crate::crypto::KeyType),
   /// Caused by attempting to parse an invalid cryptographic key.
   #[error("Invalid Key Format")]
   InvalidKeyFormat,
@@ -115,6 +115,26 @@ where
   bs58::encode(data).with_alphabet(bs58::Alphabet::BITCOIN).into_string()
 }
 
+/// Decodes the given `data` as unpadded base64url.
+///
+/// See [RFC 4648 Section 5](https://datatracker.ietf.org/doc/html/rfc4648#section-5).
+pub fn decode_b64<T>(data: &T) -> Result<Vec<u8>>
+where
+  T: AsRef<str> + ?Sized,
+{
+  multibase::Base::Base64Url.decode(data).map_err(Error::DecodeBase64)
+}
+
+/// Encodes the given `data` as unpadded base64url.
+///
+/// See [RFC 4648 Section 5](https://datatracker.ietf.org/doc/html/rfc4648#section-5).
+pub fn encode_b64<T>(data: &T) -> String
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  multibase::Base::Base64Url.encode(data)
+}
+
 #[cfg(test)]
 mod tests {
   use quickcheck_macros::quickcheck;
@@ -136,6 +156,17 @@ mod tests {
     assert_eq!(decode_b58(&encode_b58(&data)).unwrap(), data);
   }
 
+  #[quickcheck]
+  fn test_b64_random(data: Vec<u8>) {
+    assert_eq!(decode_b64(&encode_b64(&data)).unwrap(), data);
+  }
+
+  #[test]
+  fn test_b64_url_alphabet() {
+    assert_eq!(encode_b64(&[0xfb, 0xff]), "-_8");
+    assert_eq!(decode_b64("-_8").unwrap(), vec![0xfb, 0xff]);
+  }
+
   #[quickcheck]
   fn test_multibase_random(data: Vec<u8>) {
     assert_eq!(decode_multibase(&encode_multibase(&data, None)).unwrap(), data);
@@ -14,6 +14,8 @@ use identity_core::common::Url;
 use identity_core::convert::FmtJson;
 use identity_core::crypto::GetSignature;
 use identity_core::crypto::GetSignatureMut;
+#[cfg(feature = "bbs-experimental")]
+use identity_core::crypto::JcsBbsProof;
 use identity_core::crypto::Proof;
 use identity_core::crypto::SetSignature;
 use identity_did::verification::MethodUriType;
@@ -166,6 +168,35 @@ impl<T> Credential<T> {
   }
 }
 
+#[cfg(feature = "bbs-experimental")]
+impl<T> Credential<T>
+where
+  T: Clone + Serialize,
+{
+  /// Derives a copy of the `Credential` which discloses only the `reveal`ed properties of each
+  /// credential subject, proven with a [`JcsBbsProof`].
+  ///
+  /// The `Credential` must be signed with a `JcsBbs` signature. The `id` of each subject and
+  /// all properties outside of the credential subjects are always disclosed. The `challenge` of
+  /// the verifier is bound to the derived proof and must match the challenge in its
+  /// [`VerifierOptions`].
+  ///
+  /// Requires the `bbs-experimental` feature.
+  ///
+  /// [`VerifierOptions`]: identity_did::verifiable::VerifierOptions
+  pub fn derive_disclosure(&self, reveal: &[&str], challenge: &str) -> Result<Self> {
+    let mut disclosed: Self = self.clone();
+
+    for subject in disclosed.credential_subject.as_mut_slice() {
+      subject.properties.retain(|key, _| reveal.contains(&key.as_str()));
+    }
+
+    JcsBbsProof::derive(self, &mut disclosed, challenge).map_err(Error::InvalidDisclosure)?;
+
+    Ok(disclosed)
+  }
+}
+
 impl<T> Display for Credential<T>
 where
   T: Serialize,
@@ -200,8 +231,24 @@ impl<T> TryMethod for Credential<T> {
 #[cfg(test)]
 mod tests {
   use identity_core::convert::FromJson;
+  #[cfg(feature = "bbs-experimental")]
+  use identity_core::crypto::Bls12381G2;
+  #[cfg(feature = "bbs-experimental")]
+  use identity_core::crypto::JcsBbs;
+  #[cfg(feature = "bbs-experimental")]
+  use identity_core::crypto::JcsBbsProof;
+  #[cfg(feature = "bbs-experimental")]
+  use identity_core::crypto::KeyPair;
+  #[cfg(feature = "bbs-experimental")]
+  use identity_core::crypto::KeyType;
+  #[cfg(feature = "bbs-experimental")]
+  use identity_core::crypto::ProofOptions;
+  #[cfg(feature = "bbs-experimental")]
+  use identity_core::crypto::Signer;
 
   use crate::credential::Credential;
+  #[cfg(feature = "bbs-experimental")]
+  use crate::credential::Subject;
 
   const JSON1: &str = include_str!("../../tests/fixtures/credential-1.json");
   const JSON2: &str = include_str!("../../tests/fixtures/credential-2.json");
@@ -231,4 +278,37 @@ mod tests {
     let _credential: Credential = Credential::from_json(JSON11).unwrap();
     let _credential: Credential = Credential::from_json(JSON12).unwrap();
   }
+
+  #[cfg(feature = "bbs-experimental")]
+  #[test]
+  fn test_derive_disclosure() {
+    let key: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+
+    let mut credential: Credential = Credential::from_json(JSON1).unwrap();
+    credential.credential_subject.as_mut_slice()[0]
+      .properties
+      .insert("name".into(), "Alice".into());
+    credential.proof = None;
+    JcsBbs::<Bls12381G2>::create_signature(
+      &mut credential,
+      "did:example:issuer#bbs-0",
+      key.private().as_ref(),
+      ProofOptions::default(),
+    )
+    .unwrap();
+
+    let disclosed: Credential = credential.derive_disclosure(&["alumniOf"], "challenge").unwrap();
+
+    let subject: &Subject = disclosed.credential_subject.get(0).unwrap();
+    assert_eq!(subject.id, credential.credential_subject.get(0).unwrap().id);
+    assert!(subject.properties.contains_key("alumniOf"));
+    assert!(!subject.properties.contains_key("name"));
+
+    assert_eq!(disclosed.proof().unwrap().type_(), "JcsBbsSignatureProof");
+    assert_eq!(disclosed.proof().unwrap().challenge.as_deref(), Some("challenge"));
+    assert!(JcsBbsProof::<Bls12381G2>::verify_signature(&disclosed, key.public().as_ref()).is_ok());
+
+    // Only credentials signed with BBS+ can be selectively disclosed
+    assert!(disclosed.derive_disclosure(&[], "challenge").is_err());
+  }
 }
@@ -0,0 +1,198 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+
+use crate::credential::Credential;
+use crate::credential::Issuer;
+use crate::error::Error;
+use crate::error::Result;
+
+/// The claims of a [`Credential`] encoded as a JSON Web Token (VC-JWT).
+///
+/// Properties with a registered JWT claim name are moved out of the `vc` claim, as described in the
+/// [JWT encoding](https://www.w3.org/TR/vc-data-model/#jwt-encoding) of the Verifiable Credentials Data Model.
+///
+/// A JWT is secured by its own signature, so any embedded [`Proof`][identity_core::crypto::Proof] is not retained.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CredentialJwtClaims {
+  /// The issuer of the `Credential`.
+  pub iss: Url,
+  /// The id of the `Credential` subject, if the `Credential` has exactly one subject.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sub: Option<Url>,
+  /// The issuance date of the `Credential` as a Unix timestamp.
+  pub nbf: i64,
+  /// The expiration date of the `Credential` as a Unix timestamp.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub exp: Option<i64>,
+  /// The id of the `Credential`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub jti: Option<Url>,
+  /// The remaining properties of the `Credential`.
+  pub vc: Object,
+}
+
+impl CredentialJwtClaims {
+  /// Creates the JWT claims representing the given `Credential`.
+  pub fn new<T>(credential: &Credential<T>) -> Result<Self>
+  where
+    T: Serialize,
+  {
+    let mut vc: Object = credential
+      .to_json_value()
+      .and_then(Object::from_json_value)
+      .map_err(|error| Error::InvalidJwtClaims("vc", Some(error)))?;
+
+    for key in ["id", "issuanceDate", "expirationDate", "proof"] {
+      vc.remove(key);
+    }
+
+    // An issuer in object form carries additional properties, so only its id is replaced by `iss`.
+    if let Issuer::Url(_) = credential.issuer {
+      vc.remove("issuer");
+    }
+
+    let sub: Option<Url> = match credential.credential_subject.as_slice() {
+      [subject] => subject.id.clone(),
+      _ => None,
+    };
+
+    if sub.is_some() {
+      if let Some(subject) = single_subject(&mut vc).and_then(Value::as_object_mut) {
+        subject.remove("id");
+      }
+    }
+
+    Ok(Self {
+      iss: credential.issuer.url().clone(),
+      sub,
+      nbf: credential.issuance_date.to_unix(),
+      exp: credential.expiration_date.map(|timestamp| timestamp.to_unix()),
+      jti: credential.id.clone(),
+      vc,
+    })
+  }
+
+  /// Reconstructs the [`Credential`] represented by these claims.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the claims contradict the `vc` claim or do not describe a valid `Credential`.
+  pub fn try_into_credential<T>(self) -> Result<Credential<T>>
+  where
+    T: DeserializeOwned,
+  {
+    let mut vc: Object = self.vc;
+
+    match vc.get("issuer") {
+      Some(issuer) => {
+        let issuer: Issuer =
+          Issuer::from_json_value(issuer.clone()).map_err(|error| Error::InvalidJwtClaims("vc", Some(error)))?;
+
+        if issuer.url() != &self.iss {
+          return Err(Error::InvalidJwtClaims("iss", None));
+        }
+      }
+      None => {
+        vc.insert("issuer".into(), Value::String(self.iss.into_string()));
+      }
+    }
+
+    vc.insert("issuanceDate".into(), timestamp_value(self.nbf, "nbf")?);
+
+    if let Some(exp) = self.exp {
+      vc.insert("expirationDate".into(), timestamp_value(exp, "exp")?);
+    }
+
+    if let Some(jti) = self.jti {
+      vc.insert("id".into(), Value::String(jti.into_string()));
+    }
+
+    if let Some(sub) = self.sub {
+      single_subject(&mut vc)
+        .and_then(Value::as_object_mut)
+        .ok_or(Error::InvalidJwtClaims("sub", None))?
+        .insert("id".into(), Value::String(sub.into_string()));
+    }
+
+    Credential::from_json_value(Value::Object(vc.into_iter().collect()))
+      .map_err(|error| Error::InvalidJwtClaims("vc", Some(error)))
+  }
+}
+
+// Returns the only subject of the `vc` claim, which may be serialized on its own or in an array.
+fn single_subject(vc: &mut Object) -> Option<&mut Value> {
+  match vc.get_mut("credentialSubject")? {
+    Value::Array(subjects) => match subjects.as_mut_slice() {
+      [subject] => Some(subject),
+      _ => None,
+    },
+    subject => Some(subject),
+  }
+}
+
+fn timestamp_value(seconds: i64, claim: &'static str) -> Result<Value> {
+  Timestamp::from_unix(seconds)
+    .map(|timestamp| Value::String(timestamp.to_rfc3339()))
+    .map_err(|error| Error::InvalidJwtClaims(claim, Some(error)))
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Object;
+  use identity_core::common::Value;
+  use identity_core::convert::FromJson;
+
+  use crate::credential::Credential;
+  use crate::credential::CredentialJwtClaims;
+  use crate::error::Error;
+
+  const JSON1: &str = include_str!("../../tests/fixtures/credential-1.json");
+
+  #[test]
+  fn test_jwt_claims_round_trip() {
+    let mut credential: Credential = Credential::from_json(JSON1).unwrap();
+    credential.proof = None;
+
+    let claims: CredentialJwtClaims = CredentialJwtClaims::new(&credential).unwrap();
+
+    assert_eq!(claims.iss, "https://example.edu/issuers/14");
+    assert_eq!(claims.sub.as_ref().unwrap(), "did:example:ebfeb1f712ebc6f1c276e12ec21");
+    assert_eq!(claims.nbf, credential.issuance_date.to_unix());
+    assert_eq!(claims.jti, credential.id);
+
+    // Properties with a registered claim are not duplicated in the `vc` claim.
+    assert!(!claims.vc.contains_key("issuer"));
+    assert!(!claims.vc.contains_key("issuanceDate"));
+    assert!(!claims.vc.contains_key("id"));
+
+    let decoded: Credential = claims.try_into_credential().unwrap();
+    assert_eq!(decoded, credential);
+  }
+
+  #[test]
+  fn test_jwt_claims_issuer_mismatch() {
+    let mut credential: Credential = Credential::from_json(JSON1).unwrap();
+    credential.proof = None;
+
+    let mut claims: CredentialJwtClaims = CredentialJwtClaims::new(&credential).unwrap();
+    claims.vc.insert(
+      "issuer".into(),
+      Value::String("did:example:76e12ec712ebc6f1c221ebfeb1f".into()),
+    );
+
+    assert!(matches!(
+      claims.try_into_credential::<Object>(),
+      Err(Error::InvalidJwtClaims("iss", None))
+    ));
+  }
+}
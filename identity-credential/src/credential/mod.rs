@@ -9,18 +9,27 @@ mod builder;
 mod credential;
 mod evidence;
 mod issuer;
+mod jwt;
 mod policy;
 mod refresh;
+mod revocation_bitmap_status;
 mod schema;
 mod status;
+mod status_list;
 mod subject;
 
 pub use self::builder::CredentialBuilder;
 pub use self::credential::Credential;
 pub use self::evidence::Evidence;
 pub use self::issuer::Issuer;
+pub use self::jwt::CredentialJwtClaims;
 pub use self::policy::Policy;
 pub use self::refresh::Refresh;
+pub use self::revocation_bitmap_status::RevocationBitmapStatus;
 pub use self::schema::Schema;
 pub use self::status::Status;
+pub use self::status_list::StatusList2021;
+pub use self::status_list::StatusList2021CredentialBuilder;
+pub use self::status_list::StatusList2021Entry;
+pub use self::status_list::StatusPurpose;
 pub use self::subject::Subject;
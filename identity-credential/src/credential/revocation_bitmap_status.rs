@@ -0,0 +1,135 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_did::did::CoreDIDUrl;
+use identity_did::did::DIDUrl;
+use identity_did::did::DID;
+use identity_did::revocation::RevocationBitmap;
+
+use crate::credential::Status;
+use crate::error::Error;
+use crate::error::Result;
+
+/// A [`Status`] referencing an index of a [`RevocationBitmap`] published in the issuer's DID Document.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RevocationBitmapStatus {
+  id: CoreDIDUrl,
+  index: u32,
+}
+
+impl RevocationBitmapStatus {
+  /// The type name of the `Status`.
+  pub const TYPE: &'static str = RevocationBitmap::TYPE;
+
+  const INDEX_PROPERTY: &'static str = "revocationBitmapIndex";
+
+  /// Creates a new `RevocationBitmapStatus` referencing the credential at `index` in the [`RevocationBitmap`]
+  /// published by the issuer's service with the id `service`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `service` has no fragment.
+  pub fn new<D: DID>(service: DIDUrl<D>, index: u32) -> Result<Self> {
+    let mut id: CoreDIDUrl = CoreDIDUrl::parse(service.to_string()).map_err(|_| Error::InvalidStatus("id"))?;
+
+    if id.fragment().unwrap_or_default().is_empty() {
+      return Err(Error::InvalidStatus("id"));
+    }
+
+    id.set_query(Some(&format!("index={}", index)))
+      .map_err(|_| Error::InvalidStatus("id"))?;
+
+    Ok(Self { id, index })
+  }
+
+  /// Returns the id of the `Status`, which identifies the service of the issuer's DID Document.
+  pub fn id(&self) -> &CoreDIDUrl {
+    &self.id
+  }
+
+  /// Returns the index of the credential in the referenced [`RevocationBitmap`].
+  pub fn index(&self) -> u32 {
+    self.index
+  }
+}
+
+impl From<RevocationBitmapStatus> for Status {
+  fn from(other: RevocationBitmapStatus) -> Self {
+    let mut properties: Object = Object::new();
+    // The index is represented as a string of digits.
+    properties.insert(
+      RevocationBitmapStatus::INDEX_PROPERTY.into(),
+      other.index.to_string().into(),
+    );
+
+    // A DID Url is always a valid Url.
+    let id: Url = Url::parse(other.id.to_string()).expect("DID Url");
+
+    Status::with_properties(id, RevocationBitmapStatus::TYPE.to_owned(), properties)
+  }
+}
+
+impl TryFrom<&Status> for RevocationBitmapStatus {
+  type Error = Error;
+
+  fn try_from(status: &Status) -> Result<Self> {
+    if !status.types.iter().any(|type_| type_ == Self::TYPE) {
+      return Err(Error::InvalidStatus("type"));
+    }
+
+    let id: CoreDIDUrl = CoreDIDUrl::parse(status.id.as_str()).map_err(|_| Error::InvalidStatus("id"))?;
+
+    if id.fragment().unwrap_or_default().is_empty() {
+      return Err(Error::InvalidStatus("id"));
+    }
+
+    let index: u32 = match status.properties.get(Self::INDEX_PROPERTY) {
+      Some(Value::String(index)) => index.parse().ok(),
+      Some(Value::Number(index)) => index.as_u64().and_then(|index| u32::try_from(index).ok()),
+      _ => None,
+    }
+    .ok_or(Error::InvalidStatus(Self::INDEX_PROPERTY))?;
+
+    Ok(Self { id, index })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+  use identity_did::did::CoreDIDUrl;
+
+  use crate::credential::RevocationBitmapStatus;
+  use crate::credential::Status;
+  use crate::error::Error;
+
+  #[test]
+  fn test_revocation_bitmap_status() {
+    let service: CoreDIDUrl = CoreDIDUrl::parse("did:example:1234#revocation").unwrap();
+    let revocation_status: RevocationBitmapStatus = RevocationBitmapStatus::new(service, 5).unwrap();
+
+    assert_eq!(revocation_status.id().fragment(), Some("revocation"));
+    assert_eq!(revocation_status.id().query(), Some("index=5"));
+
+    let status: Status = revocation_status.clone().into();
+    assert_eq!(status.types.as_slice(), [RevocationBitmapStatus::TYPE]);
+    assert_eq!(status.properties["revocationBitmapIndex"], "5");
+    assert_eq!(RevocationBitmapStatus::try_from(&status).unwrap(), revocation_status);
+
+    // The status must identify the service with a fragment.
+    let service: CoreDIDUrl = CoreDIDUrl::parse("did:example:1234").unwrap();
+    assert!(RevocationBitmapStatus::new(service, 5).is_err());
+
+    let status: Status = Status::new(
+      Url::parse("did:example:1234#revocation").unwrap(),
+      RevocationBitmapStatus::TYPE.to_owned(),
+    );
+    assert!(matches!(
+      RevocationBitmapStatus::try_from(&status),
+      Err(Error::InvalidStatus("revocationBitmapIndex"))
+    ));
+  }
+}
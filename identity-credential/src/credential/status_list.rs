@@ -0,0 +1,522 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::TryFrom;
+use std::io::Read;
+use std::io::Write;
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use identity_core::common::Context;
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_core::utils::decode_b64;
+use identity_core::utils::encode_b64;
+
+use crate::credential::Credential;
+use crate::credential::CredentialBuilder;
+use crate::credential::Issuer;
+use crate::credential::Status;
+use crate::credential::Subject;
+use crate::error::Error;
+use crate::error::Result;
+
+lazy_static! {
+  static ref STATUS_LIST_2021_CONTEXT: Context =
+    Context::Url(Url::parse("https://w3id.org/vc/status-list/2021/v1").unwrap());
+}
+
+/// The purpose of a [`StatusList2021`], i.e. what a set bit means for the referencing credential.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusPurpose {
+  /// A set bit permanently revokes the credential.
+  Revocation,
+  /// A set bit temporarily suspends the credential.
+  Suspension,
+}
+
+impl StatusPurpose {
+  /// Returns the `statusPurpose` value of `self`.
+  pub const fn as_str(self) -> &'static str {
+    match self {
+      Self::Revocation => "revocation",
+      Self::Suspension => "suspension",
+    }
+  }
+
+  fn parse(value: Option<&Value>) -> Option<Self> {
+    match value?.as_str()? {
+      "revocation" => Some(Self::Revocation),
+      "suspension" => Some(Self::Suspension),
+      _ => None,
+    }
+  }
+}
+
+/// A [`Status`] referencing an entry of a [`StatusList2021`] credential.
+///
+/// [More Info](https://w3c-ccg.github.io/vc-status-list-2021/#statuslist2021entry)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatusList2021Entry {
+  id: Url,
+  purpose: StatusPurpose,
+  index: usize,
+  credential: Url,
+}
+
+impl StatusList2021Entry {
+  /// The type name of the `Status`.
+  pub const TYPE: &'static str = "StatusList2021Entry";
+
+  /// Creates a new `StatusList2021Entry` referencing the bit at `index` of the status list published in the
+  /// credential with the id `credential`.
+  pub fn new(id: Url, purpose: StatusPurpose, index: usize, credential: Url) -> Self {
+    Self {
+      id,
+      purpose,
+      index,
+      credential,
+    }
+  }
+
+  /// Returns the id of the `Status`.
+  pub fn id(&self) -> &Url {
+    &self.id
+  }
+
+  /// Returns the purpose of the referenced status list.
+  pub fn purpose(&self) -> StatusPurpose {
+    self.purpose
+  }
+
+  /// Returns the index of the credential in the referenced status list.
+  pub fn index(&self) -> usize {
+    self.index
+  }
+
+  /// Returns the id of the credential publishing the status list.
+  pub fn status_list_credential(&self) -> &Url {
+    &self.credential
+  }
+}
+
+impl From<StatusList2021Entry> for Status {
+  fn from(other: StatusList2021Entry) -> Self {
+    let mut properties: Object = Object::new();
+    properties.insert("statusPurpose".into(), other.purpose.as_str().into());
+    // The index is represented as a string of digits.
+    properties.insert("statusListIndex".into(), other.index.to_string().into());
+    properties.insert("statusListCredential".into(), other.credential.into_string().into());
+
+    Status::with_properties(other.id, StatusList2021Entry::TYPE.to_owned(), properties)
+  }
+}
+
+impl TryFrom<&Status> for StatusList2021Entry {
+  type Error = Error;
+
+  fn try_from(status: &Status) -> Result<Self> {
+    if !status.types.iter().any(|type_| type_ == Self::TYPE) {
+      return Err(Error::InvalidStatus("type"));
+    }
+
+    let purpose: StatusPurpose =
+      StatusPurpose::parse(status.properties.get("statusPurpose")).ok_or(Error::InvalidStatus("statusPurpose"))?;
+
+    let index: usize = match status.properties.get("statusListIndex") {
+      Some(Value::String(index)) => index.parse().ok(),
+      Some(Value::Number(index)) => index.as_u64().and_then(|index| usize::try_from(index).ok()),
+      _ => None,
+    }
+    .ok_or(Error::InvalidStatus("statusListIndex"))?;
+
+    let credential: Url = status
+      .properties
+      .get("statusListCredential")
+      .and_then(Value::as_str)
+      .and_then(|credential| Url::parse(credential).ok())
+      .ok_or(Error::InvalidStatus("statusListCredential"))?;
+
+    Ok(Self::new(status.id.clone(), purpose, index, credential))
+  }
+}
+
+/// A compressed bitstring published in a credential, where each bit represents the status of a credential
+/// referencing it by index.
+///
+/// [More Info](https://w3c-ccg.github.io/vc-status-list-2021/)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatusList2021 {
+  bits: Vec<u8>,
+}
+
+impl StatusList2021 {
+  /// The default number of entries of a status list, which provides herd privacy for the referencing credentials.
+  pub const DEFAULT_LEN: usize = 131_072;
+
+  /// The maximum number of entries of a status list, which bounds the memory needed to decode an untrusted list.
+  pub const MAX_LEN: usize = 1 << 26;
+
+  /// The type name of the status list credential.
+  pub const CREDENTIAL_TYPE: &'static str = "StatusList2021Credential";
+
+  /// The type name of the status list credential subject.
+  pub const SUBJECT_TYPE: &'static str = "StatusList2021";
+
+  /// Returns the JSON-LD context of status list credentials.
+  pub fn context() -> &'static Context {
+    &*STATUS_LIST_2021_CONTEXT
+  }
+
+  /// Creates a new `StatusList2021` with `len` entries, which are all unset.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `len` is zero, not a multiple of 8 or exceeds [`StatusList2021::MAX_LEN`].
+  pub fn new(len: usize) -> Result<Self> {
+    if len == 0 || len % 8 != 0 || len > Self::MAX_LEN {
+      return Err(Error::InvalidStatusList("length"));
+    }
+
+    Ok(Self { bits: vec![0; len / 8] })
+  }
+
+  /// Returns the number of entries of the status list.
+  pub fn len(&self) -> usize {
+    self.bits.len() * 8
+  }
+
+  /// Returns `true` if the status list has no entries.
+  pub fn is_empty(&self) -> bool {
+    self.bits.is_empty()
+  }
+
+  /// Returns `true` if the entry at `index` is set.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `index` is out of bounds.
+  pub fn get(&self, index: usize) -> Result<bool> {
+    let byte: u8 = *self.bits.get(index / 8).ok_or(Error::InvalidStatusList("index"))?;
+
+    Ok(byte & Self::mask(index) != 0)
+  }
+
+  /// Sets the entry at `index` to `value`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `index` is out of bounds.
+  pub fn set(&mut self, index: usize, value: bool) -> Result<()> {
+    let byte: &mut u8 = self.bits.get_mut(index / 8).ok_or(Error::InvalidStatusList("index"))?;
+
+    if value {
+      *byte |= Self::mask(index);
+    } else {
+      *byte &= !Self::mask(index);
+    }
+
+    Ok(())
+  }
+
+  /// Returns the GZIP-compressed, base64url-encoded representation of the status list.
+  pub fn to_encoded_str(&self) -> Result<String> {
+    let mut encoder: GzEncoder<Vec<u8>> = GzEncoder::new(Vec::new(), Compression::default());
+
+    encoder
+      .write_all(&self.bits)
+      .and_then(|_| encoder.finish())
+      .map(|compressed| encode_b64(&compressed))
+      .map_err(|_| Error::InvalidStatusList("encodedList"))
+  }
+
+  /// Decodes a status list from its GZIP-compressed, base64url-encoded representation.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the list is malformed, empty or has more than [`StatusList2021::MAX_LEN`] entries.
+  pub fn from_encoded_str(encoded: &str) -> Result<Self> {
+    const MAX_BYTES: usize = StatusList2021::MAX_LEN / 8;
+
+    let compressed: Vec<u8> = decode_b64(encoded).map_err(|_| Error::InvalidStatusList("encodedList"))?;
+    let mut bits: Vec<u8> = Vec::new();
+
+    // Stop decompressing after the maximum size to guard against compression bombs.
+    GzDecoder::new(compressed.as_slice())
+      .take(MAX_BYTES as u64 + 1)
+      .read_to_end(&mut bits)
+      .map_err(|_| Error::InvalidStatusList("encodedList"))?;
+
+    if bits.is_empty() || bits.len() > MAX_BYTES {
+      return Err(Error::InvalidStatusList("encodedList"));
+    }
+
+    Ok(Self { bits })
+  }
+
+  /// Extracts the status list published in the given status list `credential`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `credential` is not a status list credential with the given `purpose`.
+  pub fn from_credential<T>(credential: &Credential<T>, purpose: StatusPurpose) -> Result<Self> {
+    if !credential.types.iter().any(|type_| type_ == Self::CREDENTIAL_TYPE) {
+      return Err(Error::InvalidStatusList("type"));
+    }
+
+    let subject: &Subject = match credential.credential_subject.as_slice() {
+      [subject] => subject,
+      _ => return Err(Error::InvalidStatusList("credentialSubject")),
+    };
+
+    if subject.properties.get("type").and_then(Value::as_str) != Some(Self::SUBJECT_TYPE) {
+      return Err(Error::InvalidStatusList("credentialSubject"));
+    }
+
+    if StatusPurpose::parse(subject.properties.get("statusPurpose")) != Some(purpose) {
+      return Err(Error::InvalidStatusList("statusPurpose"));
+    }
+
+    subject
+      .properties
+      .get("encodedList")
+      .and_then(Value::as_str)
+      .ok_or(Error::InvalidStatusList("encodedList"))
+      .and_then(Self::from_encoded_str)
+  }
+
+  // The first entry of the status list is the most significant bit of the first byte.
+  const fn mask(index: usize) -> u8 {
+    0b1000_0000 >> (index % 8)
+  }
+}
+
+impl Default for StatusList2021 {
+  fn default() -> Self {
+    Self {
+      bits: vec![0; Self::DEFAULT_LEN / 8],
+    }
+  }
+}
+
+/// A `StatusList2021CredentialBuilder` is used to create a credential publishing a [`StatusList2021`].
+#[derive(Clone, Debug)]
+pub struct StatusList2021CredentialBuilder {
+  status_list: StatusList2021,
+  purpose: StatusPurpose,
+  id: Option<Url>,
+  issuer: Option<Issuer>,
+  issuance_date: Option<Timestamp>,
+  expiration_date: Option<Timestamp>,
+}
+
+impl StatusList2021CredentialBuilder {
+  /// Creates a new `StatusList2021CredentialBuilder` publishing the given `status_list` for the purpose of
+  /// revocation.
+  pub fn new(status_list: StatusList2021) -> Self {
+    Self {
+      status_list,
+      purpose: StatusPurpose::Revocation,
+      id: None,
+      issuer: None,
+      issuance_date: None,
+      expiration_date: None,
+    }
+  }
+
+  /// Sets the purpose of the status list.
+  #[must_use]
+  pub fn purpose(mut self, value: StatusPurpose) -> Self {
+    self.purpose = value;
+    self
+  }
+
+  /// Sets the value of the `Credential` `id`, which is referenced by [`StatusList2021Entry`]s.
+  #[must_use]
+  pub fn id(mut self, value: Url) -> Self {
+    self.id = Some(value);
+    self
+  }
+
+  /// Sets the value of the `Credential` `issuer`.
+  #[must_use]
+  pub fn issuer(mut self, value: impl Into<Issuer>) -> Self {
+    self.issuer = Some(value.into());
+    self
+  }
+
+  /// Sets the value of the `Credential` `issuanceDate`.
+  #[must_use]
+  pub fn issuance_date(mut self, value: Timestamp) -> Self {
+    self.issuance_date = Some(value);
+    self
+  }
+
+  /// Sets the value of the `Credential` `expirationDate`.
+  #[must_use]
+  pub fn expiration_date(mut self, value: Timestamp) -> Self {
+    self.expiration_date = Some(value);
+    self
+  }
+
+  /// Returns a new status list `Credential` based on the builder configuration.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the `id` or `issuer` is missing, or the status list cannot be encoded.
+  pub fn build(self) -> Result<Credential> {
+    let id: Url = self.id.ok_or(Error::InvalidStatusList("id"))?;
+
+    let mut properties: Object = Object::new();
+    properties.insert("type".into(), StatusList2021::SUBJECT_TYPE.into());
+    properties.insert("statusPurpose".into(), self.purpose.as_str().into());
+    properties.insert("encodedList".into(), self.status_list.to_encoded_str()?.into());
+
+    let subject_id: Url = id.join("#list").map_err(|_| Error::InvalidStatusList("id"))?;
+    let subject: Subject = Subject::with_id_and_properties(subject_id, properties);
+
+    let mut builder: CredentialBuilder = CredentialBuilder::default()
+      .context(StatusList2021::context().clone())
+      .id(id)
+      .type_(StatusList2021::CREDENTIAL_TYPE)
+      .subject(subject);
+
+    if let Some(issuer) = self.issuer {
+      builder = builder.issuer(issuer);
+    }
+
+    if let Some(issuance_date) = self.issuance_date {
+      builder = builder.issuance_date(issuance_date);
+    }
+
+    if let Some(expiration_date) = self.expiration_date {
+      builder = builder.expiration_date(expiration_date);
+    }
+
+    builder.build()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use core::convert::TryFrom;
+
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+
+  use crate::credential::Credential;
+  use crate::credential::Status;
+  use crate::credential::StatusList2021;
+  use crate::credential::StatusList2021CredentialBuilder;
+  use crate::credential::StatusList2021Entry;
+  use crate::credential::StatusPurpose;
+  use crate::error::Error;
+
+  const JSON: &str = include_str!("../../tests/fixtures/status-2.json");
+
+  #[test]
+  fn test_status_list_entry() {
+    let status: Status = Status::from_json(JSON).unwrap();
+    let entry: StatusList2021Entry = StatusList2021Entry::try_from(&status).unwrap();
+
+    assert_eq!(entry.id(), "https://example.com/credentials/status/3#94567");
+    assert_eq!(entry.purpose(), StatusPurpose::Revocation);
+    assert_eq!(entry.index(), 94567);
+    assert_eq!(
+      entry.status_list_credential(),
+      "https://example.com/credentials/status/3"
+    );
+    assert_eq!(Status::from(entry), status);
+
+    let status: Status = Status::from_json(include_str!("../../tests/fixtures/status-1.json")).unwrap();
+    assert!(matches!(
+      StatusList2021Entry::try_from(&status),
+      Err(Error::InvalidStatus("type"))
+    ));
+  }
+
+  #[test]
+  fn test_status_list_bits() {
+    assert!(StatusList2021::new(0).is_err());
+    assert!(StatusList2021::new(12).is_err());
+
+    let mut status_list: StatusList2021 = StatusList2021::new(16).unwrap();
+    assert_eq!(status_list.len(), 16);
+
+    status_list.set(0, true).unwrap();
+    status_list.set(9, true).unwrap();
+    status_list.set(15, true).unwrap();
+    status_list.set(15, false).unwrap();
+
+    assert!(status_list.get(0).unwrap());
+    assert!(!status_list.get(1).unwrap());
+    assert!(status_list.get(9).unwrap());
+    assert!(!status_list.get(15).unwrap());
+    assert!(status_list.get(16).is_err());
+    assert!(status_list.set(16, true).is_err());
+
+    // The first entry is the most significant bit of the first byte.
+    assert_eq!(status_list.bits, [0b1000_0000, 0b0100_0000]);
+  }
+
+  #[test]
+  fn test_status_list_encoding() {
+    let mut status_list: StatusList2021 = StatusList2021::default();
+    assert_eq!(status_list.len(), StatusList2021::DEFAULT_LEN);
+
+    status_list.set(94567, true).unwrap();
+
+    let encoded: String = status_list.to_encoded_str().unwrap();
+    assert_eq!(StatusList2021::from_encoded_str(&encoded).unwrap(), status_list);
+    assert!(StatusList2021::from_encoded_str("not a status list").is_err());
+  }
+
+  #[test]
+  fn test_status_list_max_len() {
+    assert!(StatusList2021::new(StatusList2021::MAX_LEN).is_ok());
+    assert!(StatusList2021::new(StatusList2021::MAX_LEN + 8).is_err());
+
+    // Lists which decompress beyond the maximum length are rejected.
+    let oversized: StatusList2021 = StatusList2021 {
+      bits: vec![0; StatusList2021::MAX_LEN / 8 + 1],
+    };
+    let encoded: String = oversized.to_encoded_str().unwrap();
+    assert!(StatusList2021::from_encoded_str(&encoded).is_err());
+  }
+
+  #[test]
+  fn test_status_list_credential() {
+    let mut status_list: StatusList2021 = StatusList2021::default();
+    status_list.set(94567, true).unwrap();
+
+    let credential: Credential = StatusList2021CredentialBuilder::new(status_list.clone())
+      .id(Url::parse("https://example.com/credentials/status/3").unwrap())
+      .issuer(Url::parse("did:example:12345").unwrap())
+      .build()
+      .unwrap();
+
+    assert!(credential.check_structure().is_ok());
+    assert_eq!(credential.context.get(1), Some(StatusList2021::context()));
+    assert_eq!(
+      credential.credential_subject.get(0).unwrap().id.as_ref().unwrap(),
+      "https://example.com/credentials/status/3#list"
+    );
+
+    assert_eq!(
+      StatusList2021::from_credential(&credential, StatusPurpose::Revocation).unwrap(),
+      status_list
+    );
+    assert!(matches!(
+      StatusList2021::from_credential(&credential, StatusPurpose::Suspension),
+      Err(Error::InvalidStatusList("statusPurpose"))
+    ));
+
+    // A status list credential must have an id to be referenced by.
+    assert!(StatusList2021CredentialBuilder::new(status_list).build().is_err());
+  }
+}
@@ -24,4 +24,16 @@ pub enum Error {
   /// Caused when validating a Credential with a malformed subject.
   #[error("Invalid Credential Subject")]
   InvalidSubject,
+  /// Caused when deriving a selective disclosure of a Credential fails.
+  #[error("Invalid Selective Disclosure")]
+  InvalidDisclosure(#[source] identity_core::Error),
+  /// Caused when converting between a Credential or Presentation and its JWT claims fails.
+  #[error("Invalid JWT Claims: {0}")]
+  InvalidJwtClaims(&'static str, #[source] Option<identity_core::Error>),
+  /// Caused when a Credential Status does not describe a valid status list entry.
+  #[error("Invalid Credential Status: {0}")]
+  InvalidStatus(&'static str),
+  /// Caused when creating, decoding or accessing a status list fails.
+  #[error("Invalid Status List: {0}")]
+  InvalidStatusList(&'static str),
 }
@@ -0,0 +1,222 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use identity_core::common::Object;
+use identity_core::common::OneOrMany;
+use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use identity_core::crypto::ProofOptions;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::presentation::Presentation;
+
+/// The claims of a [`Presentation`] encoded as a JSON Web Token (VP-JWT).
+///
+/// The `verifiableCredential` property of the `vp` claim may hold embedded credentials as well as credentials
+/// encoded as JWTs, see [`PresentationJwtClaims::append_credential_jwt`].
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PresentationJwtClaims {
+  /// The holder of the `Presentation`.
+  pub iss: Url,
+  /// The time before which the `Presentation` must not be accepted, as a Unix timestamp.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub nbf: Option<i64>,
+  /// The time after which the `Presentation` must not be accepted, as a Unix timestamp.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub exp: Option<i64>,
+  /// The id of the `Presentation`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub jti: Option<Url>,
+  /// The challenge supplied by the verifier, which binds the token to a single verification.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub nonce: Option<String>,
+  /// The domain of the verifier the token is intended for.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub aud: Option<OneOrMany<String>>,
+  /// The remaining properties of the `Presentation`.
+  pub vp: Object,
+}
+
+impl PresentationJwtClaims {
+  /// Creates the JWT claims representing the given `Presentation`.
+  ///
+  /// The `nbf`, `exp`, `nonce` and `aud` claims are left unset; verifiers requiring a challenge or
+  /// domain reject tokens without them. See [`PresentationJwtClaims::new_with_options`].
+  ///
+  /// # Errors
+  ///
+  /// Fails if the `Presentation` has no holder.
+  pub fn new<T, U>(presentation: &Presentation<T, U>) -> Result<Self>
+  where
+    T: Serialize,
+    U: Serialize,
+  {
+    Self::new_with_options(presentation, &ProofOptions::default())
+  }
+
+  /// Creates the JWT claims representing the given `Presentation`, bounded by the given `options`.
+  ///
+  /// The `nbf` and `exp` claims are set to the `created` and `expires` options, the `nonce` claim to
+  /// the challenge and the `aud` claim to the domain of the verifier. The proof purpose does not apply
+  /// to JWTs.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the `Presentation` has no holder.
+  pub fn new_with_options<T, U>(presentation: &Presentation<T, U>, options: &ProofOptions) -> Result<Self>
+  where
+    T: Serialize,
+    U: Serialize,
+  {
+    let iss: Url = presentation
+      .holder
+      .clone()
+      .ok_or(Error::InvalidJwtClaims("iss", None))?;
+
+    let mut vp: Object = presentation
+      .to_json_value()
+      .and_then(Object::from_json_value)
+      .map_err(|error| Error::InvalidJwtClaims("vp", Some(error)))?;
+
+    for key in ["id", "holder", "proof"] {
+      vp.remove(key);
+    }
+
+    Ok(Self {
+      iss,
+      nbf: options.created.map(|timestamp| timestamp.to_unix()),
+      exp: options.expires.map(|timestamp| timestamp.to_unix()),
+      jti: presentation.id.clone(),
+      nonce: options.challenge.clone(),
+      aud: options.domain.clone().map(OneOrMany::One),
+      vp,
+    })
+  }
+
+  /// Adds a credential encoded as a JWT to the `verifiableCredential` property of the `vp` claim.
+  pub fn append_credential_jwt(&mut self, jwt: impl Into<String>) {
+    let jwt: Value = Value::String(jwt.into());
+
+    match self.vp.remove("verifiableCredential") {
+      Some(Value::Array(mut credentials)) => {
+        credentials.push(jwt);
+        self.vp.insert("verifiableCredential".into(), Value::Array(credentials));
+      }
+      Some(credential) => {
+        self
+          .vp
+          .insert("verifiableCredential".into(), Value::Array(vec![credential, jwt]));
+      }
+      None => {
+        self.vp.insert("verifiableCredential".into(), jwt);
+      }
+    }
+  }
+
+  /// Reconstructs the [`Presentation`] represented by these claims.
+  ///
+  /// Credentials encoded as JWTs must have been decoded and replaced by their embedded form beforehand.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the claims do not describe a valid `Presentation`.
+  pub fn try_into_presentation<T, U>(self) -> Result<Presentation<T, U>>
+  where
+    T: DeserializeOwned,
+    U: DeserializeOwned,
+  {
+    let mut vp: Object = self.vp;
+
+    vp.insert("holder".into(), Value::String(self.iss.into_string()));
+
+    if let Some(jti) = self.jti {
+      vp.insert("id".into(), Value::String(jti.into_string()));
+    }
+
+    Presentation::from_json_value(Value::Object(vp.into_iter().collect()))
+      .map_err(|error| Error::InvalidJwtClaims("vp", Some(error)))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::OneOrMany;
+  use identity_core::common::Timestamp;
+  use identity_core::common::Url;
+  use identity_core::common::Value;
+  use identity_core::convert::FromJson;
+  use identity_core::crypto::ProofOptions;
+
+  use crate::presentation::Presentation;
+  use crate::presentation::PresentationJwtClaims;
+
+  const JSON: &str = include_str!("../../tests/fixtures/presentation-1.json");
+
+  #[test]
+  fn test_jwt_claims_round_trip() {
+    let mut presentation: Presentation = Presentation::from_json(JSON).unwrap();
+    presentation.holder = Some(Url::parse("did:example:ebfeb1f712ebc6f1c276e12ec21").unwrap());
+    presentation.proof = None;
+
+    let claims: PresentationJwtClaims = PresentationJwtClaims::new(&presentation).unwrap();
+
+    assert_eq!(claims.iss, "did:example:ebfeb1f712ebc6f1c276e12ec21");
+    assert_eq!(claims.jti, presentation.id);
+    assert!(!claims.vp.contains_key("holder"));
+
+    let decoded: Presentation = claims.try_into_presentation().unwrap();
+    assert_eq!(decoded, presentation);
+  }
+
+  #[test]
+  fn test_jwt_claims_with_options() {
+    let mut presentation: Presentation = Presentation::from_json(JSON).unwrap();
+    presentation.holder = Some(Url::parse("did:example:ebfeb1f712ebc6f1c276e12ec21").unwrap());
+
+    let claims: PresentationJwtClaims = PresentationJwtClaims::new(&presentation).unwrap();
+    assert_eq!(claims.nbf, None);
+    assert_eq!(claims.exp, None);
+
+    let created: Timestamp = Timestamp::parse("2022-01-01T00:00:00Z").unwrap();
+    let expires: Timestamp = Timestamp::parse("2022-01-02T00:00:00Z").unwrap();
+    let options: ProofOptions = ProofOptions::new()
+      .created(created)
+      .expires(expires)
+      .challenge("some challenge".to_owned())
+      .domain("https://example.com".to_owned());
+
+    let claims: PresentationJwtClaims = PresentationJwtClaims::new_with_options(&presentation, &options).unwrap();
+    assert_eq!(claims.nbf, Some(created.to_unix()));
+    assert_eq!(claims.exp, Some(expires.to_unix()));
+    assert_eq!(claims.nonce.as_deref(), Some("some challenge"));
+    assert_eq!(claims.aud, Some(OneOrMany::One("https://example.com".to_owned())));
+  }
+
+  #[test]
+  fn test_jwt_claims_append_credential_jwt() {
+    let mut presentation: Presentation = Presentation::from_json(JSON).unwrap();
+
+    // A holder is required for the `iss` claim.
+    assert!(PresentationJwtClaims::new(&presentation).is_err());
+
+    presentation.holder = Some(Url::parse("did:example:ebfeb1f712ebc6f1c276e12ec21").unwrap());
+
+    let mut claims: PresentationJwtClaims = PresentationJwtClaims::new(&presentation).unwrap();
+    claims.append_credential_jwt("eyJ0.eyJ1.c2ln");
+
+    match claims.vp.get("verifiableCredential") {
+      Some(Value::Array(credentials)) => {
+        assert_eq!(credentials.len(), 2);
+        assert!(credentials[0].is_object());
+        assert_eq!(credentials[1], "eyJ0.eyJ1.c2ln");
+      }
+      _ => panic!("expected an array of credentials"),
+    }
+  }
+}
@@ -6,7 +6,9 @@
 #![allow(clippy::module_inception)]
 
 mod builder;
+mod jwt;
 mod presentation;
 
 pub use self::builder::PresentationBuilder;
+pub use self::jwt::PresentationJwtClaims;
 pub use self::presentation::Presentation;
@@ -14,6 +14,7 @@ use identity_core::common::KeyComparable;
 use identity_core::diff::Diff;
 use identity_core::diff::DiffString;
 
+use crate::did::did_url::is_valid_component;
 use crate::did::DIDError;
 use crate::did::DIDUrl;
 
@@ -116,8 +117,10 @@ impl CoreDID {
   }
 
   /// Validates whether a string is a valid [`DID`] method-id.
+  ///
+  /// Percent-encoded octets are permitted, e.g. to encode the port of a `did:web` DID.
   pub fn valid_method_id(value: &str) -> Result<(), DIDError> {
-    if !value.chars().all(is_char_method_id) {
+    if !is_valid_component(value, is_char_method_id) {
       return Err(DIDError::InvalidMethodId);
     }
     Ok(())
@@ -319,6 +322,10 @@ mod tests {
       CoreDID::parse("did:iota:main:123456890").unwrap(),
       "did:iota:main:123456890"
     );
+    assert_eq!(
+      CoreDID::parse("did:web:example.com%3A3000").unwrap(),
+      "did:web:example.com%3A3000"
+    );
   }
 
   #[test]
@@ -326,6 +333,9 @@ mod tests {
     assert!(CoreDID::parse("").is_err());
     assert!(CoreDID::parse("did:").is_err());
     assert!(CoreDID::parse("dad:example:123456890").is_err());
+    assert!(CoreDID::parse("did:example:1234%").is_err());
+    assert!(CoreDID::parse("did:example:1234%3").is_err());
+    assert!(CoreDID::parse("did:example:1234%3G").is_err());
   }
 
   proptest::proptest! {
@@ -104,7 +104,7 @@ impl RelativeDIDUrl {
     self.path = value
       .filter(|s| !s.is_empty())
       .map(|s| {
-        if s.starts_with('/') && s.chars().all(is_char_path) {
+        if s.starts_with('/') && is_valid_component(s, is_char_path) {
           Ok(s.to_owned())
         } else {
           Err(DIDError::InvalidPath)
@@ -146,7 +146,7 @@ impl RelativeDIDUrl {
       .map(|mut s| {
         // Ignore leading '?' during validation.
         s = s.strip_prefix('?').unwrap_or(s);
-        if s.is_empty() || !s.chars().all(is_char_query) {
+        if s.is_empty() || !is_valid_component(s, is_char_query) {
           return Err(DIDError::InvalidQuery);
         }
         Ok(format!("?{}", s))
@@ -196,7 +196,7 @@ impl RelativeDIDUrl {
       .map(|mut s| {
         // Ignore leading '#' during validation.
         s = s.strip_prefix('#').unwrap_or(s);
-        if s.is_empty() || !s.chars().all(is_char_fragment) {
+        if s.is_empty() || !is_valid_component(s, is_char_fragment) {
           return Err(DIDError::InvalidFragment);
         }
         Ok(format!("#{}", s))
@@ -612,8 +612,8 @@ where
 #[inline(always)]
 #[rustfmt::skip]
 pub(crate) const fn is_char_path(ch: char) -> bool {
-  // Allow percent encoding or not?
-  is_char_method_id(ch) || matches!(ch, '~' | '!' | '$' | '&' | '\'' | '(' | ')' | '*' | '+' | ',' | ';' | '=' | '@' | '/' /* | '%' */)
+  // Percent encoding is validated by `is_valid_component`.
+  is_char_method_id(ch) || matches!(ch, '~' | '!' | '$' | '&' | '\'' | '(' | ')' | '*' | '+' | ',' | ';' | '=' | '@' | '/')
 }
 
 /// Checks whether a character satisfies DID Url query constraints.
@@ -628,6 +628,24 @@ pub(crate) const fn is_char_fragment(ch: char) -> bool {
   is_char_path(ch) || ch == '?'
 }
 
+/// Checks whether every character of `value` satisfies `is_char` or is part of a
+/// percent-encoded octet.
+pub(crate) fn is_valid_component(value: &str, is_char: fn(char) -> bool) -> bool {
+  let mut chars = value.chars();
+  while let Some(ch) = chars.next() {
+    let valid: bool = if ch == '%' {
+      matches!(chars.next(), Some(ch) if ch.is_ascii_hexdigit())
+        && matches!(chars.next(), Some(ch) if ch.is_ascii_hexdigit())
+    } else {
+      is_char(ch)
+    };
+    if !valid {
+      return false;
+    }
+  }
+  true
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -790,6 +808,10 @@ mod tests {
     assert_eq!(relative_url.query().unwrap(), "query");
     assert!(relative_url.set_query(Some("name=value&name2=value2&3=true")).is_ok());
     assert_eq!(relative_url.query().unwrap(), "name=value&name2=value2&3=true");
+
+    // Percent-encoded.
+    assert!(relative_url.set_query(Some("?relativeRef=%2Fpath%20name")).is_ok());
+    assert_eq!(relative_url.query().unwrap(), "relativeRef=%2Fpath%20name");
   }
 
   #[rustfmt::skip]
@@ -806,6 +828,10 @@ mod tests {
     assert!(matches!(relative_url.set_query(Some("?white\nspace")), Err(DIDError::InvalidQuery)));
     assert!(matches!(relative_url.set_query(Some("?query{invalid_brackets}")), Err(DIDError::InvalidQuery)));
 
+    // Invalid percent encoding.
+    assert!(matches!(relative_url.set_query(Some("?name=%2")), Err(DIDError::InvalidQuery)));
+    assert!(matches!(relative_url.set_query(Some("?name=%zz")), Err(DIDError::InvalidQuery)));
+
     // Reject fragment delimiter '#'.
     assert!(matches!(relative_url.set_query(Some("#fragment")), Err(DIDError::InvalidQuery)));
     assert!(matches!(relative_url.set_query(Some("some#fragment")), Err(DIDError::InvalidQuery)));
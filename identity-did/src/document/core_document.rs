@@ -5,6 +5,7 @@ use core::convert::TryInto as _;
 use core::fmt::Display;
 use core::fmt::Formatter;
 
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use identity_core::common::KeyComparable;
@@ -13,14 +14,36 @@ use identity_core::common::OneOrSet;
 use identity_core::common::OrderedSet;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
+use identity_core::common::Value;
 use identity_core::convert::FmtJson;
+use identity_core::convert::FromJson;
+#[cfg(feature = "bbs-experimental")]
+use identity_core::crypto::Bls12381G2;
+use identity_core::crypto::EcdsaSecp256k1;
 use identity_core::crypto::Ed25519;
 use identity_core::crypto::GetSignature;
+#[cfg(feature = "bbs-experimental")]
+use identity_core::crypto::JcsBbs;
+#[cfg(feature = "bbs-experimental")]
+use identity_core::crypto::JcsBbsProof;
 use identity_core::crypto::JcsEd25519;
+use identity_core::crypto::JwsP256;
+use identity_core::crypto::KeyType;
+#[cfg(feature = "bbs-experimental")]
+use identity_core::crypto::Named;
 use identity_core::crypto::PrivateKey;
 use identity_core::crypto::Proof;
 use identity_core::crypto::ProofPurpose;
+use identity_core::crypto::Secp256k1;
 use identity_core::crypto::Verifier;
+use identity_core::crypto::P256;
+use identity_core::utils::decode_b64;
+use libjose::jws::Decoder;
+use libjose::jws::JwsAlgorithm;
+use libjose::jws::JwsFormat;
+use libjose::jws::JwsHeader;
+use libjose::jws::Token;
+use libjose::utils::Secret;
 
 use crate::did::CoreDID;
 use crate::did::DIDUrl;
@@ -31,8 +54,10 @@ use crate::error::Result;
 use crate::service::Service;
 use crate::utils::DIDUrlQuery;
 use crate::utils::Queryable;
+use crate::verifiable::jws_algorithm;
 use crate::verifiable::DocumentSigner;
 use crate::verifiable::VerifierOptions;
+use crate::verification::MethodData;
 use crate::verification::MethodRef;
 use crate::verification::MethodRelationship;
 use crate::verification::MethodScope;
@@ -676,36 +701,20 @@ where
   {
     let signature: &Proof = data.signature().ok_or(Error::InvalidSignature("missing signature"))?;
 
-    // Retrieve the method used to create the signature and check it has the required verification
-    // method relationship (purpose takes precedence over method_scope).
-    let purpose_scope = options.purpose.map(|purpose| match purpose {
-      ProofPurpose::AssertionMethod => MethodScope::assertion_method(),
-      ProofPurpose::Authentication => MethodScope::authentication(),
-    });
-    let method: &VerificationMethod<D, U> = match (purpose_scope, options.method_scope) {
-      (Some(purpose_scope), _) => self
-        .resolve_method(signature, Some(purpose_scope))
-        .ok_or(Error::InvalidSignature("method with purpose scope not found"))?,
-      (None, Some(scope)) => self
-        .resolve_method(signature, Some(scope))
-        .ok_or(Error::InvalidSignature("method with specified scope not found"))?,
-      (None, None) => self
-        .resolve_method(signature, None)
-        .ok_or(Error::InvalidSignature("method not found"))?,
-    };
-
-    // Check method type.
-    if let Some(ref method_types) = options.method_type {
-      if !method_types.is_empty() && !method_types.contains(&method.type_) {
-        return Err(Error::InvalidSignature("invalid method type"));
-      }
-    }
+    // Retrieve the method used to create the signature.
+    let method: &VerificationMethod<D, U> = self.resolve_verifier_method(signature, options)?;
 
     // Check challenge.
     if options.challenge.is_some() && options.challenge != signature.challenge {
       return Err(Error::InvalidSignature("invalid challenge"));
     }
 
+    // Proofs derived for selective disclosure must be bound to the challenge of the verifier to prevent replays.
+    #[cfg(feature = "bbs-experimental")]
+    if options.challenge.is_none() && signature.type_() == JcsBbsProof::<Bls12381G2>::NAME {
+      return Err(Error::InvalidSignature("missing challenge"));
+    }
+
     // Check domain.
     if options.domain.is_some() && options.domain != signature.domain {
       return Err(Error::InvalidSignature("invalid domain"));
@@ -727,6 +736,109 @@ where
     Self::do_verify(method, data)
   }
 
+  /// Decodes a compact JSON Web Signature created with [`DocumentSigner::sign_jwt`] and returns
+  /// its claims, after verifying the signature with the method referenced by the `kid` header.
+  ///
+  /// The challenge and domain of the [`VerifierOptions`] are checked against the `nonce` and `aud`
+  /// claims of the token. The `allow_expired` option does not apply to JWTs.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the token is malformed, the method is not found in this document, an unsupported
+  /// verification method is used, the signature is invalid, the `nonce` or `aud` claims do not
+  /// match the options or the claims cannot be deserialized.
+  pub fn verify_jwt<X>(&self, jwt: &str, options: &VerifierOptions) -> Result<X>
+  where
+    X: DeserializeOwned,
+  {
+    let header: JwsHeader = jwt
+      .split('.')
+      .next()
+      .and_then(|header| decode_b64(header).ok())
+      .and_then(|header| JwsHeader::from_json_slice(&header).ok())
+      .ok_or(Error::InvalidJwt("malformed header"))?;
+
+    let kid: &str = header.kid().ok_or(Error::InvalidJwt("missing kid"))?;
+    let method: &VerificationMethod<D, U> = self.resolve_verifier_method(kid, options)?;
+    let algorithm: JwsAlgorithm = jws_algorithm(method.key_type()?).ok_or(Error::InvalidMethodType)?;
+
+    // JsonWebKey2020 methods hold a JWK, which carries the curve the key belongs to.
+    let public_key: Vec<u8>;
+    let secret: Secret<'_> = match method.data() {
+      MethodData::PublicKeyJwk(jwk) => Secret::Jwk(jwk),
+      data => {
+        public_key = data.try_decode()?;
+        Secret::Arr(&public_key)
+      }
+    };
+
+    let token: Token<'_> = Decoder::new(secret)
+      .format(JwsFormat::Compact)
+      .algorithm(algorithm)
+      .key_id(kid)
+      .decode(jwt.as_bytes())?;
+
+    if options.challenge.is_some() || options.domain.is_some() {
+      let claims: Object = Object::from_json_slice(&token.claims)?;
+
+      // Check challenge.
+      if let Some(ref challenge) = options.challenge {
+        if claims.get("nonce").and_then(Value::as_str) != Some(challenge) {
+          return Err(Error::InvalidSignature("invalid challenge"));
+        }
+      }
+
+      // Check domain.
+      if let Some(ref domain) = options.domain {
+        let valid: bool = match claims.get("aud") {
+          Some(Value::String(audience)) => audience == domain,
+          Some(Value::Array(audience)) => audience.iter().any(|value| value.as_str() == Some(domain)),
+          _ => false,
+        };
+
+        if !valid {
+          return Err(Error::InvalidSignature("invalid domain"));
+        }
+      }
+    }
+
+    X::from_json_slice(&token.claims).map_err(Into::into)
+  }
+
+  /// Resolves the verification method referenced by `query` and checks it satisfies the method
+  /// scope and type required by the [`VerifierOptions`].
+  fn resolve_verifier_method<'query, Q>(&self, query: Q, options: &VerifierOptions) -> Result<&VerificationMethod<D, U>>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+  {
+    // Check the method has the required verification method relationship
+    // (purpose takes precedence over method_scope).
+    let purpose_scope = options.purpose.map(|purpose| match purpose {
+      ProofPurpose::AssertionMethod => MethodScope::assertion_method(),
+      ProofPurpose::Authentication => MethodScope::authentication(),
+    });
+    let method: &VerificationMethod<D, U> = match (purpose_scope, options.method_scope) {
+      (Some(purpose_scope), _) => self
+        .resolve_method(query, Some(purpose_scope))
+        .ok_or(Error::InvalidSignature("method with purpose scope not found"))?,
+      (None, Some(scope)) => self
+        .resolve_method(query, Some(scope))
+        .ok_or(Error::InvalidSignature("method with specified scope not found"))?,
+      (None, None) => self
+        .resolve_method(query, None)
+        .ok_or(Error::InvalidSignature("method not found"))?,
+    };
+
+    // Check method type.
+    if let Some(ref method_types) = options.method_type {
+      if !method_types.is_empty() && !method_types.contains(&method.type_) {
+        return Err(Error::InvalidSignature("invalid method type"));
+      }
+    }
+
+    Ok(method)
+  }
+
   /// Verifies the signature of the provided data matches the public key data from the given
   /// verification method.
   ///
@@ -747,6 +859,28 @@ where
       MethodType::X25519KeyAgreementKey2019 => {
         return Err(Error::InvalidMethodType);
       }
+      MethodType::EcdsaSecp256k1VerificationKey2019 => {
+        EcdsaSecp256k1::<Secp256k1>::verify_signature(data, &public_key)?;
+      }
+      MethodType::JsonWebKey2020 => match method.key_type()? {
+        KeyType::Ed25519 => JcsEd25519::<Ed25519>::verify_signature(data, &public_key)?,
+        KeyType::Secp256k1 => EcdsaSecp256k1::<Secp256k1>::verify_signature(data, &public_key)?,
+        KeyType::P256 => JwsP256::<P256>::verify_signature(data, &public_key)?,
+        KeyType::X25519 | KeyType::Bls12381G2 => return Err(Error::InvalidMethodType),
+      },
+      #[cfg(feature = "bbs-experimental")]
+      MethodType::Bls12381G2Key2020 => {
+        // Holders may present a proof derived from the signature, disclosing only part of the data.
+        let type_: Option<&str> = data.signature().map(Proof::type_);
+
+        if type_ == Some(JcsBbsProof::<Bls12381G2>::NAME) {
+          JcsBbsProof::<Bls12381G2>::verify_signature(data, &public_key)?;
+        } else {
+          JcsBbs::<Bls12381G2>::verify_signature(data, &public_key)?;
+        }
+      }
+      #[cfg(not(feature = "bbs-experimental"))]
+      MethodType::Bls12381G2Key2020 => return Err(Error::InvalidMethodType),
     }
 
     Ok(())
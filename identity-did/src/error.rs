@@ -16,9 +16,15 @@ pub enum Error {
   CoreError(#[from] ::identity_core::Error),
   #[error("{0}")]
   InvalidDID(#[from] crate::did::DIDError),
+  /// Caused by errors from the [libjose] crate when creating or decoding JSON Web Tokens.
+  #[error("{0}")]
+  JoseError(#[from] ::libjose::Error),
 
   #[error("Verification Method Not Found")]
   MethodNotFound,
+  /// Caused by a [`Service`](crate::service::Service) not being present in a [`CoreDocument`].
+  #[error("service not found")]
+  ServiceNotFound,
 
   /// Caused by invalid or missing properties when constructing a [`CoreDocument`].
   #[error("invalid document property: {0}")]
@@ -26,6 +32,9 @@ pub enum Error {
   /// Caused by invalid or missing properties when constructing a [`Service`].
   #[error("invalid service property: {0}")]
   InvalidService(&'static str),
+  /// Caused by an invalid encoding of a [`RevocationBitmap`](crate::revocation::RevocationBitmap).
+  #[error("invalid revocation bitmap: {0}")]
+  InvalidRevocationBitmap(&'static str),
   /// Caused by invalid or missing properties when constructing a [`VerificationMethod`].
   #[error("invalid verification method property: {0}")]
   InvalidMethod(&'static str),
@@ -57,9 +66,15 @@ pub enum Error {
   InvalidKeyDataBase58,
   #[error("Invalid Multibase Key Data")]
   InvalidKeyDataMultibase,
+  #[error("Invalid JWK Key Data")]
+  InvalidKeyDataJwk,
+  #[error("Unsupported JWK Curve")]
+  UnsupportedJwkCurve,
 
   #[error("signature verification failed: {0}")]
   InvalidSignature(&'static str),
+  #[error("invalid JSON Web Token: {0}")]
+  InvalidJwt(&'static str),
 
   #[error("Missing Resolution DID")]
   MissingResolutionDID,
@@ -75,4 +90,17 @@ pub enum Error {
   InvalidDIDFragment,
   #[error("Invalid DID Resolution Service")]
   InvalidResolutionService,
+  /// Caused by a failure to encode a DID Document as CBOR.
+  #[error("failed to encode DID document as CBOR")]
+  EncodeCBOR(#[source] serde_cbor::Error),
+
+  /// Caused by a DID that is not a valid `did:key` DID.
+  #[error("invalid did:key: {0}")]
+  InvalidDIDKey(&'static str),
+  /// Caused by a DID that is not a valid `did:web` DID.
+  #[error("invalid did:web: {0}")]
+  InvalidDIDWeb(&'static str),
+  /// Caused by a failure to fetch a `did:web` DID Document.
+  #[error("failed to fetch did:web document")]
+  DIDWebFetch(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
 }
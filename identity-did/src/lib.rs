@@ -28,7 +28,9 @@ pub mod diff;
 pub mod did;
 pub mod document;
 pub mod error;
+pub mod methods;
 pub mod resolution;
+pub mod revocation;
 pub mod service;
 pub mod utils;
 pub mod verifiable;
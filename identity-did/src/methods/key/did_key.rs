@@ -0,0 +1,249 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::crypto::KeyType;
+use identity_core::crypto::PublicKey;
+use identity_core::crypto::P256;
+use identity_core::crypto::X25519;
+use identity_core::utils::decode_multibase;
+use identity_core::utils::encode_multibase;
+
+use crate::did::CoreDID;
+use crate::did::CoreDIDUrl;
+use crate::did::DID;
+use crate::document::CoreDocument;
+use crate::document::DocumentBuilder;
+use crate::error::Error;
+use crate::error::Result;
+use crate::methods::key::multicodec;
+use crate::verification::VerificationMethod;
+
+/// A public key identified by a [`did:key`](https://w3c-ccg.github.io/did-method-key/) DID.
+///
+/// The DID Document of a `did:key` DID is derived deterministically from the public key
+/// and never published to a ledger.
+#[derive(Clone, Debug)]
+pub struct DIDKey {
+  key_type: KeyType,
+  public_key: PublicKey,
+}
+
+impl DIDKey {
+  /// The DID method name of `did:key` DIDs.
+  pub const METHOD: &'static str = "key";
+
+  /// Creates a new `DIDKey` from a public key of the given [`KeyType`].
+  ///
+  /// `P-256` public keys may be given in compressed or uncompressed SEC1 encoding.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `public_key` is not a valid public key of type `key_type`.
+  pub fn new(key_type: KeyType, public_key: &PublicKey) -> Result<Self> {
+    let public_key: PublicKey = match key_type {
+      KeyType::P256 => P256::decompress_public_key(public_key.as_ref())
+        .map_err(|_| Error::InvalidDIDKey("invalid key"))?
+        .into(),
+      _ if public_key.as_ref().len() == multicodec::key_length(key_type) => public_key.as_ref().to_vec().into(),
+      _ => return Err(Error::InvalidDIDKey("invalid key length")),
+    };
+
+    Ok(Self { key_type, public_key })
+  }
+
+  /// Parses a `DIDKey` from a `did:key` DID.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `did` is not a `did:key` DID or its method-specific id is not a
+  /// multicodec-prefixed, base58-btc multibase-encoded public key.
+  pub fn parse(did: &CoreDID) -> Result<Self> {
+    if did.method() != Self::METHOD {
+      return Err(Error::InvalidDIDKey("method"));
+    }
+
+    if !did.method_id().starts_with('z') {
+      return Err(Error::InvalidDIDKey("expected base58-btc multibase encoding"));
+    }
+
+    let data: Vec<u8> = decode_multibase(did.method_id()).map_err(|_| Error::InvalidDIDKey("multibase"))?;
+    let (key_type, public_key): (KeyType, &[u8]) = multicodec::decode(&data)?;
+
+    Self::new(key_type, &public_key.to_vec().into())
+  }
+
+  /// Returns the [`KeyType`] of the public key.
+  pub fn key_type(&self) -> KeyType {
+    self.key_type
+  }
+
+  /// Returns the public key.
+  ///
+  /// `P-256` public keys are returned in uncompressed SEC1 encoding.
+  pub fn public_key(&self) -> &PublicKey {
+    &self.public_key
+  }
+
+  /// Returns the method-specific id of the `did:key` DID, which is the multicodec-prefixed public key
+  /// in base58-btc multibase encoding.
+  pub fn method_id(&self) -> String {
+    let data: Vec<u8> = match self.key_type {
+      // The key was validated on construction.
+      KeyType::P256 => multicodec::encode(
+        self.key_type,
+        &P256::compress_public_key(self.public_key.as_ref()).expect("valid P-256 key"),
+      ),
+      _ => multicodec::encode(self.key_type, self.public_key.as_ref()),
+    };
+
+    encode_multibase(&data, None)
+  }
+
+  /// Returns the `did:key` DID of the public key.
+  pub fn did(&self) -> CoreDID {
+    CoreDID::parse(format!("did:{}:{}", Self::METHOD, self.method_id())).expect("valid did:key")
+  }
+
+  /// Derives the DID Document of the `did:key` DID.
+  ///
+  /// The public key is expressed as a single verification method identified by its method-specific id.
+  /// `X25519` keys are used for key agreement, all other keys for authentication, assertion and capability
+  /// invocation and delegation. `Ed25519` keys additionally derive an `X25519` key agreement method.
+  pub fn document(&self) -> Result<CoreDocument> {
+    let did: CoreDID = self.did();
+    let method: VerificationMethod =
+      VerificationMethod::new(did.clone(), self.key_type, &self.public_key, &self.method_id())?;
+    let method_id: CoreDIDUrl = method.id().clone();
+
+    let mut builder: DocumentBuilder = CoreDocument::builder(Object::new())
+      .id(did.clone())
+      .verification_method(method);
+
+    if self.key_type == KeyType::X25519 {
+      builder = builder.key_agreement(method_id);
+    } else {
+      builder = builder
+        .authentication(method_id.clone())
+        .assertion_method(method_id.clone())
+        .capability_invocation(method_id.clone())
+        .capability_delegation(method_id);
+    }
+
+    if self.key_type == KeyType::Ed25519 {
+      let agreement: Self = Self::new(KeyType::X25519, &X25519::ed25519_to_x25519_public(&self.public_key)?)?;
+      let agreement_method: VerificationMethod =
+        VerificationMethod::new(did, KeyType::X25519, &agreement.public_key, &agreement.method_id())?;
+      let agreement_method_id: CoreDIDUrl = agreement_method.id().clone();
+
+      builder = builder
+        .verification_method(agreement_method)
+        .key_agreement(agreement_method_id);
+    }
+
+    builder.build()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::crypto::KeyPair;
+
+  use super::*;
+  use crate::verification::MethodScope;
+  use crate::verification::MethodType;
+
+  #[test]
+  fn test_did_key_ed25519() {
+    let did: CoreDID = CoreDID::parse("did:key:z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp").unwrap();
+    let did_key: DIDKey = DIDKey::parse(&did).unwrap();
+
+    assert_eq!(did_key.key_type(), KeyType::Ed25519);
+    assert_eq!(did_key.did(), did);
+
+    let document: CoreDocument = did_key.document().unwrap();
+    assert_eq!(document.id(), &did);
+    assert_eq!(document.verification_method().len(), 2);
+    assert_eq!(document.authentication().len(), 1);
+    assert_eq!(document.assertion_method().len(), 1);
+    assert_eq!(document.capability_invocation().len(), 1);
+    assert_eq!(document.capability_delegation().len(), 1);
+    assert_eq!(document.key_agreement().len(), 1);
+
+    let method: &VerificationMethod = document
+      .resolve_method(
+        "#z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp",
+        Some(MethodScope::authentication()),
+      )
+      .unwrap();
+    assert_eq!(method.type_(), MethodType::Ed25519VerificationKey2018);
+    assert_eq!(method.data().try_decode().unwrap(), did_key.public_key().as_ref());
+
+    let x25519: PublicKey = X25519::ed25519_to_x25519_public(did_key.public_key()).unwrap();
+    let fragment: String = DIDKey::new(KeyType::X25519, &x25519).unwrap().method_id();
+    let agreement: &VerificationMethod = document
+      .resolve_method(&*format!("#{}", fragment), Some(MethodScope::key_agreement()))
+      .unwrap();
+    assert!(fragment.starts_with("z6LS"));
+    assert_eq!(agreement.type_(), MethodType::X25519KeyAgreementKey2019);
+    assert_eq!(agreement.data().try_decode().unwrap(), x25519.as_ref());
+
+    // The document is derived deterministically.
+    assert_eq!(did_key.document().unwrap(), document);
+  }
+
+  #[test]
+  fn test_did_key_round_trip() {
+    for did in [
+      // P-256
+      "did:key:zDnaerDaTF5BXEavCrfRZEk316dpbLsfPDZ3WJ5hRTPFU2169",
+      // secp256k1
+      "did:key:zQ3shokFTS3brHcDQrn82RUDfCZESWL1ZdCEJwekUDPQiYBme",
+      // X25519
+      "did:key:z6LSeu9HkTHSfLLeUs2nnzUSNedgDUevfNQgQjQC23ZCit6F",
+    ] {
+      let did: CoreDID = CoreDID::parse(did).unwrap();
+      let did_key: DIDKey = DIDKey::parse(&did).unwrap();
+      assert_eq!(did_key.did(), did);
+      assert_eq!(did_key.document().unwrap().verification_method().len(), 1);
+    }
+
+    for key_type in [
+      KeyType::Ed25519,
+      KeyType::X25519,
+      KeyType::Secp256k1,
+      KeyType::P256,
+      #[cfg(feature = "bbs-experimental")]
+      KeyType::Bls12381G2,
+    ] {
+      let keypair: KeyPair = KeyPair::new(key_type).unwrap();
+      let did_key: DIDKey = DIDKey::new(key_type, keypair.public()).unwrap();
+      let parsed: DIDKey = DIDKey::parse(&did_key.did()).unwrap();
+
+      assert_eq!(parsed.key_type(), key_type);
+      assert_eq!(parsed.public_key().as_ref(), keypair.public().as_ref());
+      assert!(did_key.document().is_ok());
+    }
+  }
+
+  #[test]
+  fn test_did_key_invalid() {
+    // Wrong method.
+    let did: CoreDID = CoreDID::parse("did:example:z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp").unwrap();
+    assert!(matches!(DIDKey::parse(&did), Err(Error::InvalidDIDKey("method"))));
+
+    // Not base58-btc.
+    let did: CoreDID = CoreDID::parse("did:key:mAAAA").unwrap();
+    assert!(DIDKey::parse(&did).is_err());
+
+    // Truncated key.
+    let truncated: String = encode_multibase(&multicodec::encode(KeyType::Ed25519, &[0; 31]), None);
+    let did: CoreDID = CoreDID::parse(format!("did:key:{}", truncated)).unwrap();
+    assert!(matches!(
+      DIDKey::parse(&did),
+      Err(Error::InvalidDIDKey("invalid key length"))
+    ));
+
+    assert!(DIDKey::new(KeyType::Ed25519, &vec![0; 31].into()).is_err());
+  }
+}
@@ -0,0 +1,11 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! An implementation of the [`did:key`](https://w3c-ccg.github.io/did-method-key/) method.
+
+mod did_key;
+mod multicodec;
+mod resolver;
+
+pub use self::did_key::DIDKey;
+pub use self::resolver::KeyResolver;
@@ -0,0 +1,66 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::crypto::KeyType;
+
+use crate::error::Error;
+use crate::error::Result;
+
+// Varint-encoded multicodec prefixes of the supported public key types.
+//
+// See https://github.com/multiformats/multicodec/blob/master/table.csv
+const ED25519_PUB: [u8; 2] = [0xed, 0x01];
+const X25519_PUB: [u8; 2] = [0xec, 0x01];
+const SECP256K1_PUB: [u8; 2] = [0xe7, 0x01];
+const P256_PUB: [u8; 2] = [0x80, 0x24];
+const BLS12381_G2_PUB: [u8; 2] = [0xeb, 0x01];
+
+/// Returns the multicodec prefix of public keys of the given [`KeyType`].
+pub(crate) const fn prefix(key_type: KeyType) -> [u8; 2] {
+  match key_type {
+    KeyType::Ed25519 => ED25519_PUB,
+    KeyType::X25519 => X25519_PUB,
+    KeyType::Secp256k1 => SECP256K1_PUB,
+    KeyType::P256 => P256_PUB,
+    KeyType::Bls12381G2 => BLS12381_G2_PUB,
+  }
+}
+
+/// Returns the length in bytes of a `did:key` encoded public key of the given [`KeyType`].
+///
+/// Elliptic curve keys are encoded as compressed SEC1 points.
+pub(crate) const fn key_length(key_type: KeyType) -> usize {
+  match key_type {
+    KeyType::Ed25519 | KeyType::X25519 => 32,
+    KeyType::Secp256k1 | KeyType::P256 => 33,
+    KeyType::Bls12381G2 => 96,
+  }
+}
+
+/// Prepends the multicodec prefix of the given [`KeyType`] to `public_key`.
+pub(crate) fn encode(key_type: KeyType, public_key: &[u8]) -> Vec<u8> {
+  let mut output: Vec<u8> = Vec::with_capacity(2 + public_key.len());
+  output.extend_from_slice(&prefix(key_type));
+  output.extend_from_slice(public_key);
+  output
+}
+
+/// Splits multicodec-prefixed public key `data` into its [`KeyType`] and public key bytes.
+pub(crate) fn decode(data: &[u8]) -> Result<(KeyType, &[u8])> {
+  let key_type: KeyType = match data.get(..2) {
+    Some(code) if code == ED25519_PUB => KeyType::Ed25519,
+    Some(code) if code == X25519_PUB => KeyType::X25519,
+    Some(code) if code == SECP256K1_PUB => KeyType::Secp256k1,
+    Some(code) if code == P256_PUB => KeyType::P256,
+    Some(code) if code == BLS12381_G2_PUB => KeyType::Bls12381G2,
+    _ => return Err(Error::InvalidDIDKey("unsupported multicodec")),
+  };
+
+  let public_key: &[u8] = &data[2..];
+
+  if public_key.len() != key_length(key_type) {
+    return Err(Error::InvalidDIDKey("invalid key length"));
+  }
+
+  Ok((key_type, public_key))
+}
@@ -0,0 +1,43 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+
+use crate::did::CoreDID;
+use crate::did::DID;
+use crate::error::Result;
+use crate::methods::key::DIDKey;
+use crate::resolution::DocumentMetadata;
+use crate::resolution::InputMetadata;
+use crate::resolution::MetaDocument;
+use crate::resolution::ResolverMethod;
+
+/// A [`ResolverMethod`] for `did:key` DIDs.
+///
+/// Documents are derived from the DID itself, so resolution never performs any I/O.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct KeyResolver;
+
+impl KeyResolver {
+  /// Creates a new `KeyResolver`.
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ResolverMethod for KeyResolver {
+  fn is_supported(&self, did: &CoreDID) -> bool {
+    did.method() == DIDKey::METHOD
+  }
+
+  async fn read(&self, did: &CoreDID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+    let did_key: DIDKey = DIDKey::parse(did)?;
+
+    Ok(Some(MetaDocument {
+      data: did_key.document()?,
+      meta: DocumentMetadata::new(),
+    }))
+  }
+}
@@ -0,0 +1,7 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementations of DID methods that are independent of the IOTA Tangle.
+
+pub mod key;
+pub mod web;
@@ -0,0 +1,113 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+
+use crate::did::CoreDID;
+use crate::did::DID;
+use crate::error::Error;
+use crate::error::Result;
+
+/// A [`did:web`](https://w3c-ccg.github.io/did-method-web/) DID and the HTTPS URL of its DID Document.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DIDWeb {
+  did: CoreDID,
+  url: Url,
+}
+
+impl DIDWeb {
+  /// The DID method name of `did:web` DIDs.
+  pub const METHOD: &'static str = "web";
+
+  /// Parses a `DIDWeb` from a `did:web` DID.
+  ///
+  /// The first `:`-separated segment of the method-specific id is the domain name, optionally followed by a
+  /// percent-encoded port, and any further segments form the path of the DID Document. A DID without path
+  /// segments maps to the `/.well-known/did.json` document of the domain.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `did` is not a `did:web` DID or does not map to a valid HTTPS URL.
+  pub fn parse(did: &CoreDID) -> Result<Self> {
+    if did.method() != Self::METHOD {
+      return Err(Error::InvalidDIDWeb("method"));
+    }
+
+    let mut segments = did.method_id().split(':');
+    // `split` always yields at least one segment.
+    let host: String = segments
+      .next()
+      .unwrap_or_default()
+      .replace("%3A", ":")
+      .replace("%3a", ":");
+    let path: Vec<&str> = segments.collect();
+
+    if host.is_empty() || path.iter().any(|segment| segment.is_empty()) {
+      return Err(Error::InvalidDIDWeb("empty segment"));
+    }
+
+    let url: String = if path.is_empty() {
+      format!("https://{}/.well-known/did.json", host)
+    } else {
+      format!("https://{}/{}/did.json", host, path.join("/"))
+    };
+    let url: Url = Url::parse(url).map_err(|_| Error::InvalidDIDWeb("invalid url"))?;
+
+    if url.host_str().is_none() {
+      return Err(Error::InvalidDIDWeb("invalid url"));
+    }
+
+    Ok(Self { did: did.clone(), url })
+  }
+
+  /// Returns the `did:web` DID.
+  pub fn did(&self) -> &CoreDID {
+    &self.did
+  }
+
+  /// Returns the HTTPS URL of the DID Document.
+  pub fn url(&self) -> &Url {
+    &self.url
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn url(did: &str) -> Result<String> {
+    DIDWeb::parse(&CoreDID::parse(did).unwrap()).map(|did_web| did_web.url().to_string())
+  }
+
+  #[test]
+  fn test_did_web_url() {
+    assert_eq!(
+      url("did:web:w3c-ccg.github.io").unwrap(),
+      "https://w3c-ccg.github.io/.well-known/did.json"
+    );
+    assert_eq!(
+      url("did:web:w3c-ccg.github.io:user:alice").unwrap(),
+      "https://w3c-ccg.github.io/user/alice/did.json"
+    );
+    assert_eq!(
+      url("did:web:example.com%3A3000:user:alice").unwrap(),
+      "https://example.com:3000/user/alice/did.json"
+    );
+  }
+
+  #[test]
+  fn test_did_web_invalid() {
+    assert!(matches!(
+      url("did:key:example.com"),
+      Err(Error::InvalidDIDWeb("method"))
+    ));
+    assert!(matches!(
+      url("did:web:example.com::alice"),
+      Err(Error::InvalidDIDWeb("empty segment"))
+    ));
+    assert!(matches!(
+      url("did:web:example.com%2Fpath"),
+      Err(Error::InvalidDIDWeb("invalid url"))
+    ));
+  }
+}
@@ -0,0 +1,32 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::error::Error;
+
+use async_trait::async_trait;
+use identity_core::common::Url;
+
+/// A minimal HTTP client used by the [`WebResolver`](crate::methods::web::WebResolver) to fetch `did:web`
+/// documents.
+///
+/// Implementing this trait allows the resolver to be used with any HTTP library, or with an in-process
+/// stand-in for testing.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait HttpClient: Send + Sync {
+  /// Fetches the body of the resource at `url` with an HTTP `GET` request.
+  ///
+  /// Implementations should return an error for any non-successful response status.
+  async fn get(&self, url: &Url) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<T> HttpClient for &'_ T
+where
+  T: HttpClient + ?Sized,
+{
+  async fn get(&self, url: &Url) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>> {
+    (**self).get(url).await
+  }
+}
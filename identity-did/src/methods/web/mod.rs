@@ -0,0 +1,12 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! An implementation of the [`did:web`](https://w3c-ccg.github.io/did-method-web/) method.
+
+mod did_web;
+mod http_client;
+mod resolver;
+
+pub use self::did_web::DIDWeb;
+pub use self::http_client::HttpClient;
+pub use self::resolver::WebResolver;
@@ -0,0 +1,190 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_core::convert::FromJson;
+
+use crate::did::CoreDID;
+use crate::did::DID;
+use crate::document::CoreDocument;
+use crate::error::Error;
+use crate::error::Result;
+use crate::methods::web::DIDWeb;
+use crate::methods::web::HttpClient;
+use crate::resolution::DocumentMetadata;
+use crate::resolution::InputMetadata;
+use crate::resolution::MetaDocument;
+use crate::resolution::ResolverMethod;
+use crate::verification::MethodRef;
+
+/// A [`ResolverMethod`] for `did:web` DIDs, fetching documents with the given [`HttpClient`].
+#[derive(Clone, Debug)]
+pub struct WebResolver<C> {
+  client: C,
+}
+
+impl<C> WebResolver<C>
+where
+  C: HttpClient,
+{
+  /// Creates a new `WebResolver` fetching documents with `client`.
+  pub fn new(client: C) -> Self {
+    Self { client }
+  }
+
+  /// Returns a reference to the [`HttpClient`] of the resolver.
+  pub fn client(&self) -> &C {
+    &self.client
+  }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<C> ResolverMethod for WebResolver<C>
+where
+  C: HttpClient,
+{
+  fn is_supported(&self, did: &CoreDID) -> bool {
+    did.method() == DIDWeb::METHOD
+  }
+
+  async fn read(&self, did: &CoreDID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+    let did_web: DIDWeb = DIDWeb::parse(did)?;
+    let data: Vec<u8> = self.client.get(did_web.url()).await.map_err(Error::DIDWebFetch)?;
+
+    let document: CoreDocument = CoreDocument::from_json_slice(&data)
+      .map_err(|error| Error::InvalidDocument("malformed did:web document", Some(error)))?;
+    check_structure(&document, did_web.did())?;
+
+    Ok(Some(MetaDocument {
+      data: document,
+      meta: DocumentMetadata::new(),
+    }))
+  }
+}
+
+/// Checks that a fetched `document` is the DID Document of `did` and all of its verification
+/// relationships reference methods of the document.
+fn check_structure(document: &CoreDocument, did: &CoreDID) -> Result<()> {
+  if document.id() != did {
+    return Err(Error::InvalidDocument("id does not match the did:web DID", None));
+  }
+
+  for method_ref in document.verification_relationships() {
+    if matches!(method_ref, MethodRef::Refer(_)) && document.resolve_method_ref(method_ref).is_none() {
+      return Err(Error::InvalidDocument(
+        "unresolvable verification method reference",
+        None,
+      ));
+    }
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use identity_core::common::Object;
+  use identity_core::common::Url;
+  use identity_core::convert::ToJson;
+  use identity_core::crypto::KeyPair;
+  use identity_core::crypto::KeyType;
+
+  use super::*;
+  use crate::did::CoreDIDUrl;
+  use crate::resolution::resolve;
+  use crate::resolution::Resolution;
+  use crate::verification::VerificationMethod;
+
+  /// An in-process stand-in for an HTTP server.
+  #[derive(Default)]
+  struct MockClient(HashMap<String, Vec<u8>>);
+
+  #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+  #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+  impl HttpClient for MockClient {
+    async fn get(&self, url: &Url) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync + 'static>> {
+      self.0.get(url.as_str()).cloned().ok_or_else(|| "404 Not Found".into())
+    }
+  }
+
+  fn document(did: &str) -> CoreDocument {
+    let did: CoreDID = CoreDID::parse(did).unwrap();
+    let keypair: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
+    let method: VerificationMethod =
+      VerificationMethod::new(did.clone(), KeyType::Ed25519, keypair.public(), "key-1").unwrap();
+    let method_id: CoreDIDUrl = method.id().clone();
+
+    CoreDocument::builder(Object::new())
+      .id(did)
+      .verification_method(method)
+      .authentication(method_id)
+      .build()
+      .unwrap()
+  }
+
+  #[tokio::test]
+  async fn test_web_resolver() {
+    let expected: CoreDocument = document("did:web:example.com:user:alice");
+    let mut client: MockClient = MockClient::default();
+    client.0.insert(
+      "https://example.com/user/alice/did.json".to_owned(),
+      expected.to_json_vec().unwrap(),
+    );
+    let resolver: WebResolver<MockClient> = WebResolver::new(client);
+
+    let did: CoreDID = CoreDID::parse("did:web:example.com:user:alice").unwrap();
+    assert!(resolver.is_supported(&did));
+    assert!(!resolver.is_supported(&CoreDID::parse("did:key:z6Mk").unwrap()));
+
+    let resolved: MetaDocument = resolver.read(&did, InputMetadata::new()).await.unwrap().unwrap();
+    assert_eq!(resolved.data, expected);
+
+    let resolution: Resolution = resolve(did.as_str(), InputMetadata::new(), &resolver).await.unwrap();
+    assert_eq!(resolution.document, Some(expected));
+
+    // Fetch errors are forwarded.
+    let did: CoreDID = CoreDID::parse("did:web:example.com:user:bob").unwrap();
+    assert!(matches!(
+      resolver.read(&did, InputMetadata::new()).await,
+      Err(Error::DIDWebFetch(_))
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_web_resolver_invalid_document() {
+    let mut client: MockClient = MockClient::default();
+    // A document published for another DID.
+    client.0.insert(
+      "https://example.com/.well-known/did.json".to_owned(),
+      document("did:web:example.org").to_json_vec().unwrap(),
+    );
+    // A document referencing a method it does not contain.
+    let mut dangling: CoreDocument = document("did:web:example.com:dangling");
+    dangling.verification_method_mut().clear();
+    client.0.insert(
+      "https://example.com/dangling/did.json".to_owned(),
+      dangling.to_json_vec().unwrap(),
+    );
+    // A malformed document.
+    client
+      .0
+      .insert("https://example.com/malformed/did.json".to_owned(), b"{}".to_vec());
+    let resolver: WebResolver<MockClient> = WebResolver::new(client);
+
+    for (did, reason) in [
+      ("did:web:example.com", "id does not match the did:web DID"),
+      (
+        "did:web:example.com:dangling",
+        "unresolvable verification method reference",
+      ),
+      ("did:web:example.com:malformed", "malformed did:web document"),
+    ] {
+      let did: CoreDID = CoreDID::parse(did).unwrap();
+      let result: Result<Option<MetaDocument>> = resolver.read(&did, InputMetadata::new()).await;
+      assert!(matches!(result, Err(Error::InvalidDocument(message, _)) if message == reason));
+    }
+  }
+}
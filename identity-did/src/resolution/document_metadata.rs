@@ -17,6 +17,20 @@ pub struct DocumentMetadata {
   /// [More Info](https://www.w3.org/TR/did-spec-registries/#updated)
   #[serde(skip_serializing_if = "Option::is_none")]
   pub updated: Option<Timestamp>,
+  /// Whether the DID has been deactivated.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-spec-registries/#deactivated)
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub deactivated: Option<bool>,
+  /// The version identifier of the resolved DID Document.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-spec-registries/#versionId)
+  #[serde(rename = "versionId", skip_serializing_if = "Option::is_none")]
+  pub version_id: Option<String>,
+  /// Whether the `proof` embedded in or published with the DID Document was
+  /// successfully verified, if the DID Document is signed.
+  #[serde(rename = "proofVerified", skip_serializing_if = "Option::is_none")]
+  pub proof_verified: Option<bool>,
   /// Additional document metadata properties.
   #[serde(flatten)]
   pub properties: Object,
@@ -28,6 +42,9 @@ impl DocumentMetadata {
     Self {
       created: None,
       updated: None,
+      deactivated: None,
+      version_id: None,
+      proof_verified: None,
       properties: Object::new(),
     }
   }
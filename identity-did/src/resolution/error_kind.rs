@@ -11,8 +11,16 @@ pub enum ErrorKind {
   #[serde(rename = "invalidDid")]
   InvalidDID,
   /// The DID resolver does not support the specified method.
-  #[serde(rename = "representationNotSupported")]
+  #[serde(rename = "methodNotSupported")]
   NotSupported,
+  /// The DID resolver does not support the representation requested via the
+  /// `accept` input metadata property.
+  #[serde(rename = "representationNotSupported")]
+  RepresentationNotSupported,
+  /// The DID document returned by the DID method does not conform to the
+  /// DID document data model or does not belong to the resolved DID.
+  #[serde(rename = "invalidDidDocument")]
+  InvalidDIDDocument,
   /// The DID resolver was unable to return the DID document resulting from
   /// this resolution request.
   #[serde(rename = "notFound")]
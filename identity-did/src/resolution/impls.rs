@@ -5,7 +5,13 @@ use std::time::Instant;
 
 use identity_core::common::KeyComparable;
 use identity_core::common::OrderedSet;
+use identity_core::common::Timestamp;
 use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use identity_core::crypto::GetSignature;
+use identity_core::crypto::Proof;
 
 use crate::did::CoreDID;
 use crate::did::CoreDIDUrl;
@@ -18,11 +24,35 @@ use crate::resolution::ErrorKind;
 use crate::resolution::InputMetadata;
 use crate::resolution::MetaDocument;
 use crate::resolution::PrimaryResource;
+use crate::resolution::RepresentationResolution;
 use crate::resolution::Resolution;
 use crate::resolution::ResolverMethod;
 use crate::resolution::Resource;
 use crate::resolution::SecondaryResource;
+use crate::resolution::MIME_DID;
+use crate::resolution::MIME_DID_CBOR;
+use crate::resolution::MIME_DID_LD;
 use crate::service::ServiceEndpoint;
+use crate::verifiable::VerifierOptions;
+
+/// The DID parameter selecting a service of the DID Document.
+const SERVICE_PARAM: &str = "service";
+
+/// The DID parameter holding a relative reference to a resource at a service endpoint,
+/// including its name in earlier revisions of the DID specification.
+const RELATIVE_REF_PARAMS: &[&str] = &["relativeRef", "relative-ref"];
+
+/// The DID parameter identifying a specific version of the DID Document.
+const VERSION_ID_PARAM: &str = "versionId";
+
+/// The DID parameter selecting the version of the DID Document valid at a given time.
+const VERSION_TIME_PARAM: &str = "versionTime";
+
+/// The DID parameters selecting a version of the DID Document.
+const VERSION_PARAMS: &[&str] = &[VERSION_ID_PARAM, VERSION_TIME_PARAM];
+
+/// The JSON-LD context of DID Documents.
+const DID_V1_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
 
 /// Resolves a DID into a DID Document by using the "Read" operation of the DID method.
 ///
@@ -49,19 +79,22 @@ where
 
   // 3. Obtain the DID document for the input DID by executing the Read
   //    operation against the input DID's verifiable data registry.
-  let doc: MetaDocument = match method.read(&did, input).await? {
+  let mut doc: MetaDocument = match method.read(&did, input).await? {
     Some(doc) => doc,
     None => return Ok(context.finish_error(ErrorKind::NotFound)),
   };
 
   // 4. Validate that the output DID document conforms to a conformant
   //    serialization of the DID document data model.
-  // if did.method() != doc.data.id.method() || did.method_id() != doc.data.id.method_id() {
-  //   return Ok(context.finish_error(ErrorKind::InvalidDID));
-  // }
+  if doc.data.id() != &did {
+    return Ok(context.finish_error(ErrorKind::InvalidDIDDocument));
+  }
 
-  // TODO: Handle deactivated DIDs
-  // TODO: Handle signature verification
+  // Verify an embedded self-signature, unless the DID method already verified
+  // the DID document when reading it from its verifiable data registry.
+  if doc.meta.proof_verified.is_none() {
+    doc.meta.proof_verified = verify_document_proof(&doc.data);
+  }
 
   context.set_document(doc.data);
   context.set_metadata(doc.meta);
@@ -70,6 +103,50 @@ where
   Ok(context.finish())
 }
 
+/// Resolves a DID into a representation of its DID Document by using the "Read"
+/// operation of the DID method.
+///
+/// The representation is selected by the `accept` property of the [`InputMetadata`], which
+/// defaults to [`MIME_DID`]. Supported are [`MIME_DID`], [`MIME_DID_LD`] and [`MIME_DID_CBOR`].
+///
+/// See [DID Resolution][SPEC] for more information.
+///
+/// [SPEC]: https://www.w3.org/TR/did-core/#did-resolution
+pub async fn resolve_representation<R>(
+  did: impl AsRef<str>,
+  input: InputMetadata,
+  method: R,
+) -> Result<RepresentationResolution>
+where
+  R: ResolverMethod,
+{
+  let instant: Instant = Instant::now();
+
+  // Select the representation before resolving, to fail early on unsupported types.
+  let content_type: Option<&'static str> = negotiate_representation(input.accept.as_deref());
+
+  let mut output: RepresentationResolution = RepresentationResolution::new();
+
+  match content_type {
+    Some(content_type) => {
+      let resolution: Resolution = resolve(did, input, method).await?;
+
+      output.metadata = resolution.metadata;
+      output.document_metadata = resolution.document_metadata;
+
+      if let Some(document) = resolution.document {
+        output.document_stream = Some(produce_representation(document, content_type)?);
+        output.metadata.content_type = Some(content_type.to_owned());
+      }
+    }
+    None => output.metadata.error = Some(ErrorKind::RepresentationNotSupported),
+  }
+
+  output.metadata.duration = instant.elapsed();
+
+  Ok(output)
+}
+
 /// Dereferences a [`DIDUrl`] into a primary or secondary resource.
 ///
 /// See [DID Url Dereferencing][SPEC] for more information.
@@ -88,7 +165,12 @@ where
     Ok(did) => did,
     Err(_) => return Ok(context.finish_error(ErrorKind::InvalidDID)),
   };
-  let resolution: Resolution = resolve(did_url.did(), input, method).await?;
+  // DID parameters selecting a version of the DID document are resolution options.
+  let input: InputMetadata = match version_input(&did_url, input) {
+    Some(input) => input,
+    None => return Ok(context.finish_error(ErrorKind::InvalidDID)),
+  };
+  let resolution: Resolution = resolve(did_url.did(), input, &method).await?;
 
   // If the resolution result contains an error, bail early.
   if let Some(error) = resolution.metadata.error {
@@ -113,7 +195,7 @@ where
   context.set_metadata(metadata);
 
   // 2. Execute the algorithm for Dereferencing the Primary Resource.
-  let primary: PrimaryResource = match dereference_primary(document, did_url.clone())? {
+  let primary: PrimaryResource = match dereference_primary(document, did_url.clone(), &method).await? {
     Some(primary) => primary,
     None => return Ok(context.finish_error(ErrorKind::NotFound)),
   };
@@ -141,6 +223,17 @@ where
         // 2.2. Return the output service endpoint URL.
         context.set_content(PrimaryResource::Service(inner));
       }
+      // 3. Otherwise, if the result is an output service endpoint set or map,
+      //    append the DID fragment to each of its URLs.
+      PrimaryResource::Services(inner) => {
+        let endpoint: ServiceEndpoint = map_service_endpoint(&inner, |url| {
+          let mut url: Url = url.clone();
+          url.set_fragment(Some(fragment));
+          Ok(url)
+        })?;
+
+        context.set_content(PrimaryResource::Services(endpoint));
+      }
     }
   } else {
     context.set_content(primary);
@@ -215,12 +308,91 @@ impl DerefContext {
   }
 }
 
-fn dereference_primary(document: CoreDocument, mut did_url: CoreDIDUrl) -> Result<Option<PrimaryResource>> {
+/// A DID Document signed with an embedded `proof` property.
+#[derive(Serialize)]
+struct SignedDocument {
+  #[serde(flatten)]
+  document: CoreDocument,
+  proof: Proof,
+}
+
+impl GetSignature for SignedDocument {
+  fn signature(&self) -> Option<&Proof> {
+    Some(&self.proof)
+  }
+}
+
+/// Verifies the `proof` property of a DID Document with one of its own verification methods.
+///
+/// Returns `None` if the DID Document is not signed.
+fn verify_document_proof(document: &CoreDocument) -> Option<bool> {
+  let proof: &Value = document.properties().get("proof")?;
+
+  let proof: Proof = match Proof::from_json_value(proof.clone()) {
+    Ok(proof) => proof,
+    Err(_) => return Some(false),
+  };
+
+  let mut unsigned: CoreDocument = document.clone();
+  unsigned.properties_mut().remove("proof");
+
+  let signed: SignedDocument = SignedDocument {
+    document: unsigned,
+    proof,
+  };
+
+  Some(document.verify_data(&signed, &VerifierOptions::default()).is_ok())
+}
+
+/// Returns the first representation of the `accept` media type list supported by [`resolve_representation`].
+fn negotiate_representation(accept: Option<&str>) -> Option<&'static str> {
+  let accept: &str = match accept {
+    Some(accept) => accept,
+    None => return Some(MIME_DID),
+  };
+
+  accept
+    .split(',')
+    // Ignore media type parameters, e.g. quality values.
+    .filter_map(|media_type| media_type.split(';').next())
+    .map(str::trim)
+    .find_map(|media_type| match media_type {
+      MIME_DID | "application/json" | "*/*" => Some(MIME_DID),
+      MIME_DID_LD | "application/ld+json" => Some(MIME_DID_LD),
+      MIME_DID_CBOR | "application/cbor" => Some(MIME_DID_CBOR),
+      _ => None,
+    })
+}
+
+/// Serializes a DID Document into the representation identified by `content_type`.
+fn produce_representation(mut document: CoreDocument, content_type: &str) -> Result<Vec<u8>> {
+  match content_type {
+    MIME_DID_LD => {
+      // The JSON-LD representation MUST contain the DID v1 context.
+      document
+        .properties_mut()
+        .entry("@context".to_owned())
+        .or_insert_with(|| Value::String(DID_V1_CONTEXT.to_owned()));
+      document.to_json_vec().map_err(Into::into)
+    }
+    MIME_DID_CBOR => serde_cbor::to_vec(&document).map_err(Error::EncodeCBOR),
+    _ => document.to_json_vec().map_err(Into::into),
+  }
+}
+
+async fn dereference_primary<R>(
+  document: CoreDocument,
+  mut did_url: CoreDIDUrl,
+  method: R,
+) -> Result<Option<PrimaryResource>>
+where
+  R: ResolverMethod,
+{
   // Remove the DID fragment from the input DID URL.
   let _ = did_url.set_fragment(None).expect("clearing the fragment is infallible");
 
   // 1. If the input DID URL contains the DID parameter service...
-  if let Some((_, target)) = did_url.query_pairs().find(|(key, _)| key == "service") {
+  if let Some((_, target)) = did_url.query_pairs().find(|(key, _)| key == SERVICE_PARAM) {
     // 1.1. From the resolved DID document, select the service endpoint whose
     //      id property contains a fragment which matches the value of the
     //      service DID parameter of the input DID URL.
@@ -229,24 +401,57 @@ fn dereference_primary(document: CoreDocument, mut did_url: CoreDIDUrl) -> Resul
       .iter()
       .find(|service| matches!(service.id().fragment(), Some(fragment) if fragment == target))
       .map(|service| service.service_endpoint())
-      // 1.2. Execute the Service Endpoint Construction algorithm.
-      .map(|endpoint| match endpoint {
-        ServiceEndpoint::One(url) => service_endpoint_ctor(did_url, url),
-        // TODO: support service endpoint sets and map? Dereferencing spec does not address them.
-        ServiceEndpoint::Set(_) => Err(Error::InvalidResolutionService),
-        ServiceEndpoint::Map(_) => Err(Error::InvalidResolutionService),
-      })
+      // 1.2. Execute the Service Endpoint Construction algorithm, for each URL
+      //      of service endpoint sets and maps.
+      .map(|endpoint| map_service_endpoint(endpoint, |url| service_endpoint_ctor(&did_url, url)))
       .transpose()?
       // 1.3. Return the output service endpoint URL.
       .map(Into::into)
       .map(Ok)
       .transpose()
     // 3. Otherwise, if the input DID URL contains no DID path and no DID query.
-  } else if did_url.path().unwrap_or_default().is_empty() && did_url.query().is_none() {
+  } else if did_url.path().unwrap_or_default().is_empty()
+    && did_url
+      .query_pairs()
+      .all(|(key, _)| VERSION_PARAMS.contains(&key.as_ref()))
+  {
     // 3.1. Return the resolved DID document.
     Ok(Some(document.into()))
   } else {
-    todo!("Handle Method-Specific Dereference")
+    // 4. Otherwise, dereference the DID URL with the method-specific algorithm.
+    method.dereference(&document, &did_url).await
+  }
+}
+
+/// Copies the DID parameters selecting a version of the DID document from the `did_url` to the
+/// `input` metadata.
+///
+/// Returns `None` if the `versionTime` parameter is not a valid timestamp.
+fn version_input(did_url: &CoreDIDUrl, mut input: InputMetadata) -> Option<InputMetadata> {
+  for (key, value) in did_url.query_pairs() {
+    match key.as_ref() {
+      VERSION_ID_PARAM => input.version_id = Some(value.into_owned()),
+      VERSION_TIME_PARAM => input.version_time = Some(Timestamp::parse(&value).ok()?),
+      _ => {}
+    }
+  }
+
+  Some(input)
+}
+
+/// Applies `f` to every URL of the service `endpoint`.
+fn map_service_endpoint<F>(endpoint: &ServiceEndpoint, mut f: F) -> Result<ServiceEndpoint>
+where
+  F: FnMut(&Url) -> Result<Url>,
+{
+  match endpoint {
+    ServiceEndpoint::One(url) => f(url).map(ServiceEndpoint::One),
+    ServiceEndpoint::Set(set) => set.iter().map(f).collect::<Result<_>>().map(ServiceEndpoint::Set),
+    ServiceEndpoint::Map(map) => map
+      .iter()
+      .map(|(key, set)| Ok((key.clone(), set.iter().map(&mut f).collect::<Result<_>>()?)))
+      .collect::<Result<_>>()
+      .map(ServiceEndpoint::Map),
   }
 }
 
@@ -312,10 +517,24 @@ fn dereference_document(document: CoreDocument, fragment: &str) -> Result<Option
 // Service Endpoint Construction
 //
 // [Ref](https://w3c-ccg.github.io/did-resolution/#service-endpoint-construction)
-fn service_endpoint_ctor(did: CoreDIDUrl, url: &Url) -> Result<Url> {
+fn service_endpoint_ctor(did: &CoreDIDUrl, url: &Url) -> Result<Url> {
+  // The DID parameters selecting the service are not forwarded to the service endpoint.
+  let query: Vec<(String, String)> = did
+    .query_pairs()
+    .filter(|(key, _)| {
+      key != SERVICE_PARAM && !RELATIVE_REF_PARAMS.contains(&key.as_ref()) && !VERSION_PARAMS.contains(&key.as_ref())
+    })
+    .map(|(key, value)| (key.into_owned(), value.into_owned()))
+    .collect();
+  let relative_ref: Option<String> = did
+    .query_pairs()
+    .find(|(key, _)| RELATIVE_REF_PARAMS.contains(&key.as_ref()))
+    .map(|(_, value)| value.into_owned());
+  let path: &str = did.path().unwrap_or_default();
+
   // The input DID URL and input service endpoint URL MUST NOT both have a
   // query component.
-  if did.query().is_some() && url.query().is_some() {
+  if !query.is_empty() && url.query().is_some() {
     return Err(Error::InvalidDIDQuery);
   }
 
@@ -325,11 +544,20 @@ fn service_endpoint_ctor(did: CoreDIDUrl, url: &Url) -> Result<Url> {
     return Err(Error::InvalidDIDFragment);
   }
 
-  // The input service endpoint URL MUST be an HTTP(S) URL.
-  if url.scheme() != "https" {
+  // A DID URL path or relative reference can only be applied to a
+  // hierarchical service endpoint URL, e.g. `https:` or `wss:`.
+  if url.cannot_be_a_base() && (!path.is_empty() || relative_ref.is_some()) {
     return Err(Error::InvalidResolutionService);
   }
 
+  // The `relativeRef` DID parameter MUST be a relative reference without a
+  // scheme or authority, so it cannot redirect to a different origin.
+  if let Some(relative_ref) = relative_ref.as_deref() {
+    if Url::parse(relative_ref).is_ok() || relative_ref.replace('\\', "/").starts_with("//") {
+      return Err(Error::InvalidResolutionService);
+    }
+  }
+
   // 1. Initialize a string output service endpoint URL to the value of
   //    the input service endpoint URL.
   let mut output: Url = url.clone();
@@ -340,32 +568,29 @@ fn service_endpoint_ctor(did: CoreDIDUrl, url: &Url) -> Result<Url> {
   // 3. If the output service endpoint URL has a fragment component, remove it.
   output.set_fragment(None);
 
-  // Decode and join the `relative-ref` query param, if it exists.
-  if let Some((_, relative)) = did.query_pairs().find(|(key, _)| key == "relative-ref") {
-    output = output.join(&relative)?;
+  // Resolve the `relativeRef` DID parameter against the service endpoint URL.
+  if let Some(relative_ref) = relative_ref {
+    output = output.join(relative_ref)?;
   }
 
   // 4. Append the path component of the input DID URL to the output
   //    service endpoint URL.
-  output
-    .path_segments_mut()
-    .unwrap()
-    .pop_if_empty()
-    .extend(did.path().unwrap_or_default().split('/'));
+  if !path.is_empty() {
+    output
+      .path_segments_mut()
+      .map_err(|_| Error::InvalidResolutionService)?
+      .pop_if_empty()
+      .extend(path.trim_start_matches('/').split('/'));
+  }
 
   // 5. If the input service endpoint URL has a query component, append ?
   //    plus the query to the output service endpoint URL.
   // 6. If the input DID URL has a query component, append ? plus the
   //    query to the output service endpoint URL.
-  match (did.query(), url.query()) {
-    (Some(_), None) => {
-      output.query_pairs_mut().extend_pairs(did.query_pairs());
-    }
-    (None, Some(_)) => {
-      output.query_pairs_mut().extend_pairs(url.query_pairs());
-    }
-    (Some(_), Some(_)) => unreachable!(),
-    (None, None) => {}
+  if !query.is_empty() {
+    output.query_pairs_mut().extend_pairs(query);
+  } else if output.query().is_none() {
+    output.set_query(url.query());
   }
 
   // 7. If the input service endpoint URL has a fragment component, append
@@ -384,15 +609,66 @@ fn service_endpoint_ctor(did: CoreDIDUrl, url: &Url) -> Result<Url> {
 
 #[cfg(test)]
 mod test {
+  use async_trait::async_trait;
+  use identity_core::common::Object;
+  use identity_core::crypto::GetSignatureMut;
+  use identity_core::crypto::KeyPair;
+  use identity_core::crypto::KeyType;
+  use identity_core::crypto::SetSignature;
+  use indexmap::IndexMap;
+
   use crate::did::DID;
   use crate::service::Service;
   use crate::utils::Queryable;
   use crate::verification::MethodData;
   use crate::verification::MethodType;
+  use crate::verification::MethodUriType;
+  use crate::verification::TryMethod;
   use crate::verification::VerificationMethod;
 
   use super::*;
 
+  /// A [`ResolverMethod`] returning the same DID Document for every `did:example` DID.
+  struct MockResolver(CoreDocument);
+
+  #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+  #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+  impl ResolverMethod for MockResolver {
+    fn is_supported(&self, did: &CoreDID) -> bool {
+      did.method() == "example"
+    }
+
+    async fn read(&self, _did: &CoreDID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+      Ok(Some(MetaDocument {
+        data: self.0.clone(),
+        meta: DocumentMetadata::new(),
+      }))
+    }
+  }
+
+  impl GetSignatureMut for SignedDocument {
+    fn signature_mut(&mut self) -> Option<&mut Proof> {
+      Some(&mut self.proof)
+    }
+  }
+
+  impl SetSignature for SignedDocument {
+    fn set_signature(&mut self, signature: Proof) {
+      self.proof = signature;
+    }
+  }
+
+  impl TryMethod for SignedDocument {
+    const TYPE: MethodUriType = MethodUriType::Relative;
+  }
+
+  fn input(accept: &str) -> InputMetadata {
+    InputMetadata {
+      accept: Some(accept.to_owned()),
+      ..InputMetadata::new()
+    }
+  }
+
   fn did() -> CoreDIDUrl {
     "did:test:1234".parse().unwrap()
   }
@@ -400,7 +676,7 @@ mod test {
   #[test]
   fn test_service_endpoint_valid() {
     let did = did();
-    assert!(service_endpoint_ctor(did, &Url::parse("https://my-service.endpoint.net").unwrap()).is_ok());
+    assert!(service_endpoint_ctor(&did, &Url::parse("https://my-service.endpoint.net").unwrap()).is_ok());
   }
 
   #[test]
@@ -408,19 +684,19 @@ mod test {
     let did_url = did();
     assert!(matches!(
       service_endpoint_ctor(
-        did_url.clone().join("?query=this").unwrap(),
+        &did_url.clone().join("?query=this").unwrap(),
         &Url::parse("https://my-service.endpoint.net?query=this").unwrap()
       ),
       Err(Error::InvalidDIDQuery)
     ));
 
     assert!(service_endpoint_ctor(
-      did_url.clone().join("?query=this").unwrap(),
+      &did_url.clone().join("?query=this").unwrap(),
       &Url::parse("https://my-service.endpoint.net").unwrap(),
     )
     .is_ok());
     assert!(service_endpoint_ctor(
-      did_url,
+      &did_url,
       &Url::parse("https://my-service.endpoint.net?query=this").unwrap(),
     )
     .is_ok());
@@ -431,19 +707,19 @@ mod test {
     let did_url = did();
     assert!(matches!(
       service_endpoint_ctor(
-        did_url.clone().join("#fragment").unwrap(),
+        &did_url.clone().join("#fragment").unwrap(),
         &Url::parse("https://my-service.endpoint.net#fragment").unwrap()
       ),
       Err(Error::InvalidDIDFragment)
     ));
 
     assert!(service_endpoint_ctor(
-      did_url.clone().join("#fragment").unwrap(),
+      &did_url.clone().join("#fragment").unwrap(),
       &Url::parse("https://my-service.endpoint.net").unwrap(),
     )
     .is_ok());
     assert!(service_endpoint_ctor(
-      did_url,
+      &did_url,
       &Url::parse("https://my-service.endpoint.net#fragment").unwrap(),
     )
     .is_ok());
@@ -482,26 +758,285 @@ mod test {
   }
 
   #[rustfmt::skip]
-  #[test]
-  fn test_dereference_primary() {
+  #[tokio::test]
+  async fn test_dereference_primary() {
     let document = generate_document();
     let did = document.id();
+    let method = MockResolver(document.clone());
 
     // Dereference document
-    match dereference_primary(document.clone(), did.to_url()).unwrap().unwrap() {
+    match dereference_primary(document.clone(), did.to_url(), &method).await.unwrap().unwrap() {
       PrimaryResource::Document(actual) => assert_eq!(actual, document),
       actual => panic!("Expected document got {:?}", actual),
     }
 
-    // TODO: test dereference services
-    // match dereference_primary(document.clone(), did.to_url().join("?service=service-1").unwrap()).unwrap().unwrap() {
-    //   PrimaryResource::Service(actual) => assert_eq!(actual, document.service().query("#service-1").unwrap().service_endpoint().clone()),
-    //   actual => panic!("Expected #service-1 got {:?}", actual),
-    // }
-    // match dereference_primary(document.clone(), did.to_url().join("?service=service-2").unwrap()).unwrap().unwrap() {
-    //   PrimaryResource::Service(actual) => assert_eq!(actual, document.service().query("#service-2").unwrap().service_endpoint().clone()),
-    //   actual => panic!("Expected #service-2 got {:?}", actual),
-    // }
+    // Dereference services
+    match dereference_primary(document.clone(), did.to_url().join("?service=service-1").unwrap(), &method).await.unwrap().unwrap() {
+      PrimaryResource::Service(actual) => assert_eq!(actual.as_str(), "https://127.0.0.1/"),
+      actual => panic!("Expected #service-1 got {:?}", actual),
+    }
+    match dereference_primary(document.clone(), did.to_url().join("?service=service-2").unwrap(), &method).await.unwrap().unwrap() {
+      PrimaryResource::Service(actual) => assert_eq!(actual.as_str(), "https://example.com/"),
+      actual => panic!("Expected #service-2 got {:?}", actual),
+    }
+    assert!(dereference_primary(document.clone(), did.to_url().join("?service=service-3").unwrap(), &method).await.unwrap().is_none());
+
+    // Paths and other queries are dereferenced by the method, which does not support any by default.
+    assert!(dereference_primary(document.clone(), did.to_url().join("/path").unwrap(), &method).await.unwrap().is_none());
+    assert!(dereference_primary(document.clone(), did.to_url().join("?query=1").unwrap(), &method).await.unwrap().is_none());
+
+    // Version parameters select the version of the document, which was resolved before.
+    match dereference_primary(document.clone(), did.to_url().join("?versionId=1").unwrap(), &method).await.unwrap().unwrap() {
+      PrimaryResource::Document(actual) => assert_eq!(actual, document),
+      actual => panic!("Expected document got {:?}", actual),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_dereference_method_specific() {
+    /// A [`ResolverMethod`] dereferencing the `/self` path to the DID Document.
+    struct PathResolver(MockResolver);
+
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    impl ResolverMethod for PathResolver {
+      fn is_supported(&self, did: &CoreDID) -> bool {
+        self.0.is_supported(did)
+      }
+
+      async fn read(&self, did: &CoreDID, input: InputMetadata) -> Result<Option<MetaDocument>> {
+        self.0.read(did, input).await
+      }
+
+      async fn dereference(&self, document: &CoreDocument, did_url: &CoreDIDUrl) -> Result<Option<PrimaryResource>> {
+        Ok((did_url.path() == Some("/self")).then(|| document.clone().into()))
+      }
+    }
+
+    let document: CoreDocument = generate_document();
+    let method: PathResolver = PathResolver(MockResolver(document.clone()));
+
+    let output: Dereference = dereference("did:example:1234/self", InputMetadata::new(), &method)
+      .await
+      .unwrap();
+    assert_eq!(
+      output.content,
+      Some(Resource::Primary(PrimaryResource::Document(document.clone())))
+    );
+
+    // Secondary resources are dereferenced from the method-specific primary resource.
+    let output: Dereference = dereference("did:example:1234/self#key-1", InputMetadata::new(), &method)
+      .await
+      .unwrap();
+    assert!(matches!(
+      output.content,
+      Some(Resource::Secondary(SecondaryResource::VerificationKey(_)))
+    ));
+
+    // Unsupported DID URLs are not found instead of causing a panic.
+    let output: Dereference = dereference("did:example:1234/other?query=1", InputMetadata::new(), &method)
+      .await
+      .unwrap();
+    assert_eq!(output.metadata.error, Some(ErrorKind::NotFound));
+    assert_eq!(output.content, None);
+  }
+
+  #[tokio::test]
+  async fn test_dereference_version() {
+    /// A [`ResolverMethod`] resolving only version `1` of DID Documents.
+    struct VersionResolver(MockResolver);
+
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    impl ResolverMethod for VersionResolver {
+      fn is_supported(&self, did: &CoreDID) -> bool {
+        self.0.is_supported(did)
+      }
+
+      async fn read(&self, did: &CoreDID, input: InputMetadata) -> Result<Option<MetaDocument>> {
+        let version_time: Timestamp = Timestamp::parse("2022-01-01T00:00:00Z").unwrap();
+        if input.version_id.as_deref() != Some("1") && input.version_time != Some(version_time) {
+          return Ok(None);
+        }
+
+        let mut document: Option<MetaDocument> = self.0.read(did, input).await?;
+        if let Some(document) = document.as_mut() {
+          document.meta.version_id = Some("1".to_owned());
+        }
+        Ok(document)
+      }
+    }
+
+    let document: CoreDocument = generate_document();
+    let method: VersionResolver = VersionResolver(MockResolver(document.clone()));
+
+    for did_url in [
+      "did:example:1234?versionId=1",
+      "did:example:1234?versionTime=2022-01-01T00:00:00Z",
+    ] {
+      let output: Dereference = dereference(did_url, InputMetadata::new(), &method).await.unwrap();
+      assert_eq!(
+        output.content,
+        Some(Resource::Primary(PrimaryResource::Document(document.clone())))
+      );
+      assert_eq!(output.content_metadata.unwrap().version_id.as_deref(), Some("1"));
+    }
+
+    let output: Dereference = dereference("did:example:1234?versionId=2", InputMetadata::new(), &method)
+      .await
+      .unwrap();
+    assert_eq!(output.metadata.error, Some(ErrorKind::NotFound));
+
+    let output: Dereference = dereference("did:example:1234?versionTime=yesterday", InputMetadata::new(), &method)
+      .await
+      .unwrap();
+    assert_eq!(output.metadata.error, Some(ErrorKind::InvalidDID));
+  }
+
+  #[tokio::test]
+  async fn test_dereference_service_endpoint_set_and_map() {
+    let did: CoreDID = CoreDID::parse("did:example:1234").unwrap();
+    let set: OrderedSet<Url> = [
+      Url::parse("https://a.example.com").unwrap(),
+      Url::parse("https://b.example.com/base/").unwrap(),
+    ]
+    .into_iter()
+    .collect();
+    let mut map: IndexMap<String, OrderedSet<Url>> = IndexMap::new();
+    map.insert("origins".to_owned(), set.clone());
+    let document: CoreDocument = CoreDocument::builder(Default::default())
+      .id(did.clone())
+      .service(
+        Service::builder(Default::default())
+          .id(did.to_url().join("#set").unwrap())
+          .service_endpoint(ServiceEndpoint::Set(set))
+          .type_("LinkedDomains")
+          .build()
+          .unwrap(),
+      )
+      .service(
+        Service::builder(Default::default())
+          .id(did.to_url().join("#map").unwrap())
+          .service_endpoint(ServiceEndpoint::Map(map))
+          .type_("LinkedDomains")
+          .build()
+          .unwrap(),
+      )
+      .build()
+      .unwrap();
+    let method: MockResolver = MockResolver(document);
+
+    let output: Dereference = dereference(
+      "did:example:1234?service=set&relativeRef=file.json#frag",
+      InputMetadata::new(),
+      &method,
+    )
+    .await
+    .unwrap();
+    let expected: OrderedSet<Url> = [
+      Url::parse("https://a.example.com/file.json#frag").unwrap(),
+      Url::parse("https://b.example.com/base/file.json#frag").unwrap(),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+      output.content,
+      Some(Resource::Primary(PrimaryResource::Services(ServiceEndpoint::Set(
+        expected.clone()
+      ))))
+    );
+
+    let output: Dereference = dereference(
+      "did:example:1234?service=map&relativeRef=file.json#frag",
+      InputMetadata::new(),
+      &method,
+    )
+    .await
+    .unwrap();
+    match output.content {
+      Some(Resource::Primary(PrimaryResource::Services(ServiceEndpoint::Map(map)))) => {
+        assert_eq!(map.len(), 1);
+        assert_eq!(map["origins"], expected);
+      }
+      content => panic!("Expected service endpoint map got {:?}", content),
+    }
+  }
+
+  #[test]
+  fn test_service_endpoint_relative_ref() {
+    let endpoint: Url = Url::parse("https://example.com/files/").unwrap();
+
+    let did_url: CoreDIDUrl = did().join("?service=files&relativeRef=%2Fresume.pdf").unwrap();
+    assert_eq!(
+      service_endpoint_ctor(&did_url, &endpoint).unwrap().as_str(),
+      "https://example.com/resume.pdf"
+    );
+
+    let did_url: CoreDIDUrl = did().join("?service=files&relativeRef=resume.pdf").unwrap();
+    assert_eq!(
+      service_endpoint_ctor(&did_url, &endpoint).unwrap().as_str(),
+      "https://example.com/files/resume.pdf"
+    );
+
+    // The legacy parameter name is supported, and other query parameters are forwarded.
+    let did_url: CoreDIDUrl = did().join("?service=files&relative-ref=resume.pdf&version=1").unwrap();
+    assert_eq!(
+      service_endpoint_ctor(&did_url, &endpoint).unwrap().as_str(),
+      "https://example.com/files/resume.pdf?version=1"
+    );
+
+    // DID URL paths are appended to the service endpoint.
+    let did_url: CoreDIDUrl = did().join("/docs/resume.pdf?service=files").unwrap();
+    assert_eq!(
+      service_endpoint_ctor(&did_url, &endpoint).unwrap().as_str(),
+      "https://example.com/files/docs/resume.pdf"
+    );
+  }
+
+  #[test]
+  fn test_service_endpoint_relative_ref_origin() {
+    let endpoint: Url = Url::parse("https://example.com/files/").unwrap();
+
+    for relative_ref in [
+      "https%3A%2F%2Fevil.example.com%2Fresume.pdf",
+      "http%3Aevil.example.com",
+      "%2F%2Fevil.example.com%2Fresume.pdf",
+      "%5C%5Cevil.example.com%2Fresume.pdf",
+      "%2F%5Cevil.example.com%2Fresume.pdf",
+    ] {
+      let did_url: CoreDIDUrl = did()
+        .join(format!("?service=files&relativeRef={}", relative_ref))
+        .unwrap();
+      assert!(matches!(
+        service_endpoint_ctor(&did_url, &endpoint),
+        Err(Error::InvalidResolutionService)
+      ));
+    }
+  }
+
+  #[test]
+  fn test_service_endpoint_non_https() {
+    let did_url: CoreDIDUrl = did().join("?service=messaging").unwrap();
+
+    for endpoint in [
+      "http://example.com/",
+      "wss://example.com/ws",
+      "mailto:alice@example.com",
+    ] {
+      let endpoint: Url = Url::parse(endpoint).unwrap();
+      assert_eq!(service_endpoint_ctor(&did_url, &endpoint).unwrap(), endpoint);
+    }
+
+    // Paths and relative references cannot be appended to non-hierarchical URLs.
+    for did_url in [
+      did().join("/path?service=messaging"),
+      did().join("?service=messaging&relativeRef=path"),
+    ] {
+      assert!(matches!(
+        service_endpoint_ctor(&did_url.unwrap(), &Url::parse("mailto:alice@example.com").unwrap()),
+        Err(Error::InvalidResolutionService)
+      ));
+    }
   }
 
   #[rustfmt::skip]
@@ -533,4 +1068,111 @@ mod test {
       actual => panic!("Expected #service-2 got {:?}", actual),
     }
   }
+
+  #[tokio::test]
+  async fn test_resolve_conformance() {
+    let document: CoreDocument = generate_document();
+
+    let resolution: Resolution = resolve("did:example:1234", InputMetadata::new(), MockResolver(document.clone()))
+      .await
+      .unwrap();
+    assert_eq!(resolution.metadata.error, None);
+    assert_eq!(resolution.document, Some(document.clone()));
+
+    let metadata: DocumentMetadata = resolution.document_metadata.unwrap();
+    // Only the DID method knows whether a DID has been deactivated.
+    assert_eq!(metadata.deactivated, None);
+    assert_eq!(metadata.proof_verified, None);
+
+    // The DID Document of another DID is rejected.
+    let resolution: Resolution = resolve("did:example:5678", InputMetadata::new(), MockResolver(document))
+      .await
+      .unwrap();
+    assert_eq!(resolution.metadata.error, Some(ErrorKind::InvalidDIDDocument));
+    assert_eq!(resolution.document, None);
+  }
+
+  #[tokio::test]
+  async fn test_resolve_proof() {
+    let keypair: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
+    let did: CoreDID = CoreDID::parse("did:example:1234").unwrap();
+    let mut document: CoreDocument = CoreDocument::builder(Default::default())
+      .id(did.clone())
+      .verification_method(VerificationMethod::new(did, KeyType::Ed25519, keypair.public(), "key-1").unwrap())
+      .build()
+      .unwrap();
+
+    let mut signed: SignedDocument = SignedDocument {
+      document: document.clone(),
+      proof: Proof::new("", ""),
+    };
+    document
+      .signer(keypair.private())
+      .method("#key-1")
+      .sign(&mut signed)
+      .unwrap();
+    document
+      .properties_mut()
+      .insert("proof".to_owned(), signed.proof.to_json_value().unwrap());
+
+    let resolution: Resolution = resolve("did:example:1234", InputMetadata::new(), MockResolver(document.clone()))
+      .await
+      .unwrap();
+    assert_eq!(resolution.document_metadata.unwrap().proof_verified, Some(true));
+
+    // Modifying the DID Document invalidates the proof.
+    document
+      .properties_mut()
+      .insert("foo".to_owned(), Value::String("bar".to_owned()));
+    let resolution: Resolution = resolve("did:example:1234", InputMetadata::new(), MockResolver(document))
+      .await
+      .unwrap();
+    assert_eq!(resolution.document_metadata.unwrap().proof_verified, Some(false));
+  }
+
+  #[tokio::test]
+  async fn test_resolve_representation() {
+    let document: CoreDocument = generate_document();
+    let method: MockResolver = MockResolver(document.clone());
+
+    // JSON is the default representation.
+    let resolution: RepresentationResolution =
+      resolve_representation("did:example:1234", InputMetadata::new(), &method)
+        .await
+        .unwrap();
+    assert_eq!(resolution.metadata.content_type.as_deref(), Some(MIME_DID));
+    assert_eq!(resolution.document_stream, Some(document.to_json_vec().unwrap()));
+    assert!(resolution.document_metadata.is_some());
+
+    let resolution: RepresentationResolution =
+      resolve_representation("did:example:1234", input("application/did+ld+json;q=0.9, */*"), &method)
+        .await
+        .unwrap();
+    assert_eq!(resolution.metadata.content_type.as_deref(), Some(MIME_DID_LD));
+    let json: Object = Object::from_json_slice(&resolution.document_stream.unwrap()).unwrap();
+    assert_eq!(json["@context"], DID_V1_CONTEXT);
+    assert_eq!(json["id"], "did:example:1234");
+
+    let resolution: RepresentationResolution =
+      resolve_representation("did:example:1234", input(MIME_DID_CBOR), &method)
+        .await
+        .unwrap();
+    assert_eq!(resolution.metadata.content_type.as_deref(), Some(MIME_DID_CBOR));
+    let decoded: CoreDocument = serde_cbor::from_slice(&resolution.document_stream.unwrap()).unwrap();
+    assert_eq!(decoded, document);
+
+    let resolution: RepresentationResolution = resolve_representation("did:example:1234", input("text/html"), &method)
+      .await
+      .unwrap();
+    assert_eq!(resolution.metadata.error, Some(ErrorKind::RepresentationNotSupported));
+    assert_eq!(resolution.document_stream, None);
+    assert_eq!(resolution.metadata.content_type, None);
+
+    // Resolution errors are forwarded.
+    let resolution: RepresentationResolution = resolve_representation("did:other:1234", InputMetadata::new(), &method)
+      .await
+      .unwrap();
+    assert_eq!(resolution.metadata.error, Some(ErrorKind::NotSupported));
+    assert_eq!(resolution.document_stream, None);
+  }
 }
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_core::common::Object;
+use identity_core::common::Timestamp;
 
 /// The content type of a JSON DID Document.
 pub const MIME_DID: &str = "application/did+json";
@@ -9,7 +10,9 @@ pub const MIME_DID: &str = "application/did+json";
 /// The content type of a JSON-LD DID Document.
 pub const MIME_DID_LD: &str = "application/did+ld+json";
 
-// TODO: Support versioning via `version-id`/`version-time`
+/// The content type of a CBOR DID Document.
+pub const MIME_DID_CBOR: &str = "application/did+cbor";
+
 // TODO: Support caching via `no-cache`
 
 /// Input options used to configure a [DID resolution][SPEC] process.
@@ -19,11 +22,22 @@ pub const MIME_DID_LD: &str = "application/did+ld+json";
 pub struct InputMetadata {
   /// The MIME type of the preferred representation of the DID document.
   ///
-  /// Note: This is only relevant when using stream-based resolution.
+  /// Note: This is only relevant when using stream-based resolution via
+  /// [`resolve_representation`](crate::resolution::resolve_representation).
   ///
   /// [More Info](https://www.w3.org/TR/did-spec-registries/#accept)
   #[serde(skip_serializing_if = "Option::is_none")]
   pub accept: Option<String>,
+  /// The identifier of a specific version of the DID document to resolve.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-spec-registries/#versionId-param)
+  #[serde(rename = "versionId", skip_serializing_if = "Option::is_none")]
+  pub version_id: Option<String>,
+  /// Resolve the version of the DID document that was valid at the given time.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-spec-registries/#versionTime-param)
+  #[serde(rename = "versionTime", skip_serializing_if = "Option::is_none")]
+  pub version_time: Option<Timestamp>,
   /// Additional input metadata properties.
   #[serde(flatten)]
   pub properties: Object,
@@ -34,6 +48,8 @@ impl InputMetadata {
   pub fn new() -> Self {
     Self {
       accept: None,
+      version_id: None,
+      version_time: None,
       properties: Object::new(),
     }
   }
@@ -10,6 +10,7 @@ mod document_metadata;
 mod error_kind;
 mod impls;
 mod input_metadata;
+mod representation;
 mod resolution;
 mod resolution_metadata;
 mod resource;
@@ -20,9 +21,12 @@ pub use self::document_metadata::DocumentMetadata;
 pub use self::error_kind::ErrorKind;
 pub use self::impls::dereference;
 pub use self::impls::resolve;
+pub use self::impls::resolve_representation;
 pub use self::input_metadata::InputMetadata;
 pub use self::input_metadata::MIME_DID;
+pub use self::input_metadata::MIME_DID_CBOR;
 pub use self::input_metadata::MIME_DID_LD;
+pub use self::representation::RepresentationResolution;
 pub use self::resolution::Resolution;
 pub use self::resolution_metadata::ResolutionMetadata;
 pub use self::resource::PrimaryResource;
@@ -0,0 +1,34 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::resolution::DocumentMetadata;
+use crate::resolution::ResolutionMetadata;
+
+/// The output returned from [DID resolution][SPEC] of a DID Document representation.
+///
+/// [SPEC]: https://www.w3.org/TR/did-core/#did-resolution
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct RepresentationResolution {
+  /// Resolution-specific metadata.
+  ///
+  /// The `content_type` property holds the MIME type of the `document_stream`.
+  #[serde(rename = "didResolutionMetadata")]
+  pub metadata: ResolutionMetadata,
+  /// The DID Document of a successful resolution, in the requested representation.
+  #[serde(rename = "didDocumentStream", skip_serializing_if = "Option::is_none")]
+  pub document_stream: Option<Vec<u8>>,
+  /// Document-specific metadata.
+  #[serde(rename = "didDocumentMetadata", skip_serializing_if = "Option::is_none")]
+  pub document_metadata: Option<DocumentMetadata>,
+}
+
+impl RepresentationResolution {
+  /// Creates a new [`RepresentationResolution`].
+  pub fn new() -> Self {
+    Self {
+      metadata: ResolutionMetadata::new(),
+      document_stream: None,
+      document_metadata: None,
+    }
+  }
+}
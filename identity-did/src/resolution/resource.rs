@@ -6,6 +6,7 @@ use identity_core::common::Url;
 use crate::did::CoreDIDUrl;
 use crate::document::CoreDocument;
 use crate::service::Service;
+use crate::service::ServiceEndpoint;
 use crate::verification::MethodRef;
 use crate::verification::VerificationMethod;
 
@@ -43,6 +44,9 @@ pub enum PrimaryResource {
   Document(CoreDocument),
   /// A dereferenced DID Document service endpoint.
   Service(Url),
+  /// A dereferenced DID Document service endpoint set or map, with the service endpoint
+  /// construction applied to each of its URLs.
+  Services(ServiceEndpoint),
 }
 
 impl From<CoreDocument> for PrimaryResource {
@@ -57,6 +61,15 @@ impl From<Url> for PrimaryResource {
   }
 }
 
+impl From<ServiceEndpoint> for PrimaryResource {
+  fn from(other: ServiceEndpoint) -> Self {
+    match other {
+      ServiceEndpoint::One(url) => Self::Service(url),
+      _ => Self::Services(other),
+    }
+  }
+}
+
 /// A secondary resource returned from a [DID URL dereferencing][SPEC] process.
 ///
 /// [SPEC]: https://www.w3.org/TR/did-core/#dfn-did-url-dereferencing
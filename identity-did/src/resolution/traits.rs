@@ -4,10 +4,12 @@
 use async_trait::async_trait;
 
 use crate::did::CoreDID;
+use crate::did::CoreDIDUrl;
 use crate::document::CoreDocument;
 use crate::error::Result;
 use crate::resolution::DocumentMetadata;
 use crate::resolution::InputMetadata;
+use crate::resolution::PrimaryResource;
 
 /// A resolved [`Document`] and associated [`DocumentMetadata`].
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -19,19 +21,30 @@ pub struct MetaDocument {
 }
 
 /// A trait for generic DID Resolvers.
-#[async_trait(?Send)]
-pub trait ResolverMethod {
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait ResolverMethod: Send + Sync {
   /// Returns `true` if the given `did` is supported by this DID Resolver.
   fn is_supported(&self, did: &CoreDID) -> bool;
 
   /// Performs the "Read" operation of the DID method.
   async fn read(&self, did: &CoreDID, input: InputMetadata) -> Result<Option<MetaDocument>>;
+
+  /// Performs method-specific dereferencing of a DID URL with a path or query that does not
+  /// select a service of the resolved `document`.
+  ///
+  /// Returns `Ok(None)` if the DID URL does not identify a resource, which is the default for DID
+  /// methods that do not define dereferencing of DID URL paths and queries.
+  async fn dereference(&self, _document: &CoreDocument, _did_url: &CoreDIDUrl) -> Result<Option<PrimaryResource>> {
+    Ok(None)
+  }
 }
 
-#[async_trait(?Send)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl<T> ResolverMethod for &'_ T
 where
-  T: ResolverMethod + Send + Sync,
+  T: ResolverMethod,
 {
   fn is_supported(&self, did: &CoreDID) -> bool {
     (**self).is_supported(did)
@@ -40,4 +53,8 @@ where
   async fn read(&self, did: &CoreDID, input: InputMetadata) -> Result<Option<MetaDocument>> {
     (**self).read(did, input).await
   }
+
+  async fn dereference(&self, document: &CoreDocument, did_url: &CoreDIDUrl) -> Result<Option<PrimaryResource>> {
+    (**self).dereference(document, did_url).await
+  }
 }
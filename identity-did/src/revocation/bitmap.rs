@@ -0,0 +1,224 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::io::Read;
+use std::io::Write;
+
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use roaring::RoaringBitmap;
+
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::utils::decode_b64;
+use identity_core::utils::encode_b64;
+
+use crate::did::DIDUrl;
+use crate::did::DID;
+use crate::error::Error;
+use crate::error::Result;
+use crate::service::Service;
+use crate::service::ServiceEndpoint;
+
+const DATA_URL_PREFIX: &str = "data:,";
+// The maximum size of a serialized bitmap, which bounds the memory needed to decompress an untrusted endpoint.
+const MAX_SERIALIZED_SIZE: usize = 8 * 1024 * 1024;
+
+/// A compressed bitmap of revoked credential indices, published as a [`Service`] of the issuer's DID Document.
+///
+/// Credentials reference their index in the bitmap of their issuer, which revokes them by setting the index.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RevocationBitmap(RoaringBitmap);
+
+impl RevocationBitmap {
+  /// The type name of the `Service` publishing a `RevocationBitmap`.
+  pub const TYPE: &'static str = "RevocationBitmap2022";
+
+  /// Creates a new `RevocationBitmap` in which no index is revoked.
+  pub fn new() -> Self {
+    Self(RoaringBitmap::new())
+  }
+
+  /// Returns `true` if the credential at `index` is revoked.
+  pub fn is_revoked(&self, index: u32) -> bool {
+    self.0.contains(index)
+  }
+
+  /// Revokes the credential at `index`.
+  ///
+  /// Returns `true` if the credential was not revoked before.
+  pub fn revoke(&mut self, index: u32) -> bool {
+    self.0.insert(index)
+  }
+
+  /// Lifts the revocation of the credential at `index`.
+  ///
+  /// Returns `true` if the credential was revoked before.
+  pub fn unrevoke(&mut self, index: u32) -> bool {
+    self.0.remove(index)
+  }
+
+  /// Returns the number of revoked credentials.
+  pub fn len(&self) -> u64 {
+    self.0.len()
+  }
+
+  /// Returns `true` if no credential is revoked.
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// Returns a `Service` with the given `id` publishing this `RevocationBitmap`.
+  pub fn to_service<D: DID>(&self, id: DIDUrl<D>) -> Result<Service<D>> {
+    Service::builder(Object::new())
+      .id(id)
+      .type_(Self::TYPE)
+      .service_endpoint(self.to_endpoint()?)
+      .build()
+  }
+
+  /// Returns the `serviceEndpoint` representing this `RevocationBitmap`: a data url holding the zlib-compressed,
+  /// base64url-encoded serialization of the bitmap.
+  pub fn to_endpoint(&self) -> Result<ServiceEndpoint> {
+    let mut serialized: Vec<u8> = Vec::with_capacity(self.0.serialized_size());
+    self
+      .0
+      .serialize_into(&mut serialized)
+      .map_err(|_| Error::InvalidRevocationBitmap("serialization failed"))?;
+
+    let mut encoder: ZlibEncoder<Vec<u8>> = ZlibEncoder::new(Vec::new(), Compression::default());
+    let compressed: Vec<u8> = encoder
+      .write_all(&serialized)
+      .and_then(|_| encoder.finish())
+      .map_err(|_| Error::InvalidRevocationBitmap("compression failed"))?;
+
+    let url: Url = Url::parse(format!("{}{}", DATA_URL_PREFIX, encode_b64(&compressed)))?;
+
+    Ok(ServiceEndpoint::One(url))
+  }
+
+  /// Decodes a `RevocationBitmap` from the `serviceEndpoint` of a [`RevocationBitmap::TYPE`] service.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the endpoint is malformed or the decompressed bitmap exceeds 8 MiB.
+  pub fn from_endpoint(endpoint: &ServiceEndpoint) -> Result<Self> {
+    let encoded: &str = match endpoint {
+      ServiceEndpoint::One(url) => url
+        .as_str()
+        .strip_prefix(DATA_URL_PREFIX)
+        .ok_or(Error::InvalidRevocationBitmap("expected a data url"))?,
+      _ => return Err(Error::InvalidRevocationBitmap("expected a single endpoint")),
+    };
+
+    let compressed: Vec<u8> =
+      decode_b64(encoded).map_err(|_| Error::InvalidRevocationBitmap("invalid base64url encoding"))?;
+
+    let mut serialized: Vec<u8> = Vec::new();
+    ZlibDecoder::new(compressed.as_slice())
+      .take(MAX_SERIALIZED_SIZE as u64 + 1)
+      .read_to_end(&mut serialized)
+      .map_err(|_| Error::InvalidRevocationBitmap("invalid compression"))?;
+
+    if serialized.len() > MAX_SERIALIZED_SIZE {
+      return Err(Error::InvalidRevocationBitmap("exceeds maximum size"));
+    }
+
+    RoaringBitmap::deserialize_from(serialized.as_slice())
+      .map(Self)
+      .map_err(|_| Error::InvalidRevocationBitmap("invalid serialization"))
+  }
+}
+
+impl<D, T> TryFrom<&Service<D, T>> for RevocationBitmap
+where
+  D: DID,
+{
+  type Error = Error;
+
+  fn try_from(service: &Service<D, T>) -> Result<Self> {
+    if service.type_() != Self::TYPE {
+      return Err(Error::InvalidService("invalid type"));
+    }
+
+    Self::from_endpoint(service.service_endpoint())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::io::Write;
+
+  use flate2::write::ZlibEncoder;
+  use flate2::Compression;
+  use identity_core::common::Url;
+  use identity_core::utils::encode_b64;
+
+  use crate::did::CoreDIDUrl;
+  use crate::error::Error;
+  use crate::revocation::RevocationBitmap;
+  use crate::service::Service;
+  use crate::service::ServiceEndpoint;
+
+  #[test]
+  fn test_revocation_bitmap_revoke() {
+    let mut bitmap: RevocationBitmap = RevocationBitmap::new();
+    assert!(bitmap.is_empty());
+
+    assert!(bitmap.revoke(5));
+    assert!(!bitmap.revoke(5));
+    assert!(bitmap.revoke(u32::MAX));
+    assert!(bitmap.is_revoked(5));
+    assert!(!bitmap.is_revoked(6));
+    assert_eq!(bitmap.len(), 2);
+
+    assert!(bitmap.unrevoke(5));
+    assert!(!bitmap.unrevoke(5));
+    assert!(!bitmap.is_revoked(5));
+    assert!(bitmap.is_revoked(u32::MAX));
+  }
+
+  #[test]
+  fn test_revocation_bitmap_service() {
+    let mut bitmap: RevocationBitmap = RevocationBitmap::new();
+    for index in [0, 7, 1_000, 70_000] {
+      bitmap.revoke(index);
+    }
+
+    let id: CoreDIDUrl = CoreDIDUrl::parse("did:example:1234#revocation").unwrap();
+    let service: Service = bitmap.to_service(id).unwrap();
+    assert_eq!(service.type_(), RevocationBitmap::TYPE);
+    assert!(matches!(service.service_endpoint(), ServiceEndpoint::One(url) if url.as_str().starts_with("data:,")));
+
+    assert_eq!(RevocationBitmap::try_from(&service).unwrap(), bitmap);
+  }
+
+  #[test]
+  fn test_revocation_bitmap_invalid_endpoint() {
+    let endpoint: ServiceEndpoint = ServiceEndpoint::One(Url::parse("https://example.com/").unwrap());
+    assert!(matches!(
+      RevocationBitmap::from_endpoint(&endpoint),
+      Err(Error::InvalidRevocationBitmap(_))
+    ));
+
+    let endpoint: ServiceEndpoint = ServiceEndpoint::One(Url::parse("data:,not-compressed").unwrap());
+    assert!(matches!(
+      RevocationBitmap::from_endpoint(&endpoint),
+      Err(Error::InvalidRevocationBitmap(_))
+    ));
+  }
+
+  #[test]
+  fn test_revocation_bitmap_max_size() {
+    let mut encoder: ZlibEncoder<Vec<u8>> = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&vec![0; super::MAX_SERIALIZED_SIZE + 1]).unwrap();
+    let compressed: Vec<u8> = encoder.finish().unwrap();
+
+    let url: Url = Url::parse(format!("data:,{}", encode_b64(&compressed))).unwrap();
+    assert!(matches!(
+      RevocationBitmap::from_endpoint(&ServiceEndpoint::One(url)),
+      Err(Error::InvalidRevocationBitmap("exceeds maximum size"))
+    ));
+  }
+}
@@ -0,0 +1,8 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Publishing the revocation state of credentials in a DID Document.
+
+mod bitmap;
+
+pub use self::bitmap::RevocationBitmap;
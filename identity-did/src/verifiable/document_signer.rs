@@ -8,16 +8,30 @@ use crate::did::DID;
 use identity_core::common::KeyComparable;
 use identity_core::common::Object;
 use identity_core::common::Timestamp;
+#[cfg(feature = "bbs-experimental")]
+use identity_core::crypto::Bls12381G2;
+use identity_core::crypto::EcdsaSecp256k1;
 use identity_core::crypto::Ed25519;
+#[cfg(feature = "bbs-experimental")]
+use identity_core::crypto::JcsBbs;
 use identity_core::crypto::JcsEd25519;
+use identity_core::crypto::JwsP256;
+use identity_core::crypto::KeyType;
 use identity_core::crypto::PrivateKey;
 use identity_core::crypto::ProofOptions;
 use identity_core::crypto::ProofPurpose;
+use identity_core::crypto::Secp256k1;
 use identity_core::crypto::SetSignature;
 use identity_core::crypto::Signer;
+use identity_core::crypto::P256;
+use libjose::jws::Encoder;
+use libjose::jws::JwsAlgorithm;
+use libjose::jws::JwsFormat;
+use libjose::jws::JwsHeader;
 
 use crate::document::CoreDocument;
 use crate::utils::DIDUrlQuery;
+use crate::verifiable::jws_algorithm;
 use crate::verification::MethodType;
 use crate::verification::TryMethod;
 use crate::verification::VerificationMethod;
@@ -134,7 +148,55 @@ where
       MethodType::X25519KeyAgreementKey2019 => {
         return Err(Error::InvalidMethodType);
       }
+      MethodType::EcdsaSecp256k1VerificationKey2019 => {
+        EcdsaSecp256k1::<Secp256k1>::create_signature(that, method_uri, self.private.as_ref(), self.options.clone())?;
+      }
+      MethodType::JsonWebKey2020 => match method.key_type()? {
+        KeyType::Ed25519 => {
+          JcsEd25519::<Ed25519>::create_signature(that, method_uri, self.private.as_ref(), self.options.clone())?;
+        }
+        KeyType::Secp256k1 => {
+          EcdsaSecp256k1::<Secp256k1>::create_signature(that, method_uri, self.private.as_ref(), self.options.clone())?;
+        }
+        KeyType::P256 => {
+          JwsP256::<P256>::create_signature(that, method_uri, self.private.as_ref(), self.options.clone())?;
+        }
+        KeyType::X25519 | KeyType::Bls12381G2 => return Err(Error::InvalidMethodType),
+      },
+      #[cfg(feature = "bbs-experimental")]
+      MethodType::Bls12381G2Key2020 => {
+        JcsBbs::<Bls12381G2>::create_signature(that, method_uri, self.private.as_ref(), self.options.clone())?;
+      }
+      #[cfg(not(feature = "bbs-experimental"))]
+      MethodType::Bls12381G2Key2020 => return Err(Error::InvalidMethodType),
     }
     Ok(())
   }
+
+  /// Signs the provided claims as a compact JSON Web Signature with the configured verification method.
+  ///
+  /// The `kid` header is set to the DID Url of the method, which verifiers use to look up the public
+  /// key. The [`ProofOptions`] do not apply to JWTs.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the method cannot sign JWTs, claims serialization fails, or the signature operation fails.
+  pub fn sign_jwt<X>(&self, claims: &X) -> Result<String>
+  where
+    X: Serialize,
+  {
+    let query: DIDUrlQuery<'_> = self.method.clone().ok_or(Error::MethodNotFound)?;
+    let method: &VerificationMethod<D, U> = self.document.resolve_method(query, None).ok_or(Error::MethodNotFound)?;
+    let algorithm: JwsAlgorithm = jws_algorithm(method.key_type()?).ok_or(Error::InvalidMethodType)?;
+
+    let mut header: JwsHeader = JwsHeader::new(algorithm);
+    header.set_kid(method.id().to_string());
+    header.set_typ("JWT");
+
+    Encoder::new()
+      .format(JwsFormat::Compact)
+      .recipient((self.private, &header))
+      .encode_serde(claims)
+      .map_err(Into::into)
+  }
 }
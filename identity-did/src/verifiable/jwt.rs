@@ -0,0 +1,15 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::crypto::KeyType;
+use libjose::jws::JwsAlgorithm;
+
+/// Returns the JSON Web Signature algorithm used to sign JWTs with keys of the given type, if any.
+pub(crate) const fn jws_algorithm(key_type: KeyType) -> Option<JwsAlgorithm> {
+  match key_type {
+    KeyType::Ed25519 => Some(JwsAlgorithm::EdDSA),
+    KeyType::Secp256k1 => Some(JwsAlgorithm::ES256K),
+    KeyType::P256 => Some(JwsAlgorithm::ES256),
+    KeyType::X25519 | KeyType::Bls12381G2 => None,
+  }
+}
@@ -5,7 +5,10 @@ pub use self::document_signer::DocumentSigner;
 pub use self::properties::VerifiableProperties;
 pub use self::verifier_options::VerifierOptions;
 
+pub(crate) use self::jwt::jws_algorithm;
+
 mod document_signer;
+mod jwt;
 mod properties;
 mod verifier_options;
 
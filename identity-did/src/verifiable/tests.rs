@@ -1,7 +1,9 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Object;
 use identity_core::common::Timestamp;
+use identity_core::convert::FromJson;
 use identity_core::crypto::GetSignature;
 use identity_core::crypto::GetSignatureMut;
 use identity_core::crypto::KeyPair;
@@ -9,6 +11,7 @@ use identity_core::crypto::KeyType;
 use identity_core::crypto::Proof;
 use identity_core::crypto::ProofPurpose;
 use identity_core::crypto::SetSignature;
+use identity_core::utils::encode_b64;
 
 use crate::did::CoreDID;
 use crate::did::DID;
@@ -21,6 +24,7 @@ use crate::verification::MethodType;
 use crate::verification::MethodUriType;
 use crate::verification::TryMethod;
 use crate::verification::VerificationMethod;
+use crate::Error;
 
 #[derive(Debug, Serialize)]
 struct MockObject {
@@ -91,6 +95,153 @@ fn test_sign_verify_data_ed25519() {
   }
 }
 
+#[test]
+fn test_sign_verify_data_secp256k1() {
+  for method_data_base in [MethodData::new_base58, MethodData::new_multibase] {
+    let key: KeyPair = KeyPair::new(KeyType::Secp256k1).unwrap();
+    let controller: CoreDID = "did:example:1234".parse().unwrap();
+    let public_key = key.public().as_ref().to_vec();
+
+    let method: VerificationMethod = VerificationMethod::builder(Default::default())
+      .id(controller.to_url().join("#key-1").unwrap())
+      .controller(controller.clone())
+      .type_(MethodType::EcdsaSecp256k1VerificationKey2019)
+      .data(method_data_base(public_key))
+      .build()
+      .unwrap();
+
+    let document: CoreDocument = CoreDocument::builder(Default::default())
+      .id(controller)
+      .verification_method(method)
+      .build()
+      .unwrap();
+
+    let mut data: MockObject = MockObject::new(123);
+
+    assert!(document.verify_data(&data, &VerifierOptions::default()).is_err());
+
+    document.signer(key.private()).method("#key-1").sign(&mut data).unwrap();
+
+    assert_eq!(data.signature().unwrap().type_(), "JcsEcdsaSecp256k1Signature2022");
+    assert!(document.verify_data(&data, &VerifierOptions::default()).is_ok());
+
+    // Tampering with the data invalidates the signature.
+    data.data = 456;
+    assert!(document.verify_data(&data, &VerifierOptions::default()).is_err());
+  }
+}
+
+#[test]
+fn test_sign_verify_data_p256() {
+  let key: KeyPair = KeyPair::new(KeyType::P256).unwrap();
+  let controller: CoreDID = "did:example:1234".parse().unwrap();
+
+  // A P-256 key is represented as a JsonWebKey2020 method with `publicKeyJwk` data.
+  let method: VerificationMethod =
+    VerificationMethod::new(controller.clone(), KeyType::P256, key.public(), "#key-1").unwrap();
+  assert_eq!(method.type_(), MethodType::JsonWebKey2020);
+  assert!(matches!(method.data(), MethodData::PublicKeyJwk(_)));
+
+  let ed25519: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
+  let ed25519_method: VerificationMethod =
+    VerificationMethod::new(controller.clone(), KeyType::Ed25519, ed25519.public(), "#key-2").unwrap();
+
+  let document: CoreDocument = CoreDocument::builder(Default::default())
+    .id(controller)
+    .verification_method(method)
+    .verification_method(ed25519_method)
+    .build()
+    .unwrap();
+
+  let mut data: MockObject = MockObject::new(123);
+
+  assert!(document.verify_data(&data, &VerifierOptions::default()).is_err());
+
+  document.signer(key.private()).method("#key-1").sign(&mut data).unwrap();
+
+  assert_eq!(data.signature().unwrap().type_(), "JcsJwsP256Signature2022");
+  assert!(data.signature().unwrap().value().as_jws().is_some());
+  assert!(document.verify_data(&data, &VerifierOptions::default()).is_ok());
+
+  // Side by side with an Ed25519 method.
+  let mut data2: MockObject = MockObject::new(456);
+  document
+    .signer(ed25519.private())
+    .method("#key-2")
+    .sign(&mut data2)
+    .unwrap();
+  assert!(document.verify_data(&data2, &VerifierOptions::default()).is_ok());
+
+  // A signature created with a key not matching the method fails verification.
+  let mut data3: MockObject = MockObject::new(789);
+  let _ = document.signer(ed25519.private()).method("#key-1").sign(&mut data3);
+  assert!(document.verify_data(&data3, &VerifierOptions::default()).is_err());
+}
+
+#[test]
+fn test_sign_verify_jwt() {
+  let controller: CoreDID = "did:example:1234".parse().unwrap();
+  let ed25519: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
+  let p256: KeyPair = KeyPair::new(KeyType::P256).unwrap();
+  let x25519: KeyPair = KeyPair::new(KeyType::X25519).unwrap();
+
+  let document: CoreDocument = CoreDocument::builder(Default::default())
+    .id(controller.clone())
+    .verification_method(
+      VerificationMethod::new(controller.clone(), KeyType::Ed25519, ed25519.public(), "#key-1").unwrap(),
+    )
+    .verification_method(VerificationMethod::new(controller.clone(), KeyType::P256, p256.public(), "#key-2").unwrap())
+    .verification_method(VerificationMethod::new(controller, KeyType::X25519, x25519.public(), "#key-3").unwrap())
+    .build()
+    .unwrap();
+
+  let claims: Object = Object::from_json(r#"{"iss":"did:example:1234","nbf":1262304000}"#).unwrap();
+
+  for (key, fragment) in [(&ed25519, "#key-1"), (&p256, "#key-2")] {
+    let jwt: String = document
+      .signer(key.private())
+      .method(fragment)
+      .sign_jwt(&claims)
+      .unwrap();
+
+    let decoded: Object = document.verify_jwt(&jwt, &VerifierOptions::default()).unwrap();
+    assert_eq!(decoded, claims);
+
+    // Tampering with the payload invalidates the signature.
+    let segments: Vec<&str> = jwt.split('.').collect();
+    let tampered: String = format!(
+      "{}.{}.{}",
+      segments[0],
+      encode_b64(br#"{"iss":"did:example:5678"}"#),
+      segments[2]
+    );
+    assert!(document
+      .verify_jwt::<Object>(&tampered, &VerifierOptions::default())
+      .is_err());
+  }
+
+  // The method type constraint of the options applies.
+  let jwt: String = document
+    .signer(ed25519.private())
+    .method("#key-1")
+    .sign_jwt(&claims)
+    .unwrap();
+  let options: VerifierOptions = VerifierOptions::new().method_type(vec![MethodType::JsonWebKey2020]);
+  assert!(document.verify_jwt::<Object>(&jwt, &options).is_err());
+
+  // Key agreement methods cannot sign JWTs.
+  assert!(matches!(
+    document.signer(x25519.private()).method("#key-3").sign_jwt(&claims),
+    Err(Error::InvalidMethodType)
+  ));
+
+  // A malformed token fails.
+  assert!(matches!(
+    document.verify_jwt::<Object>("not a jwt", &VerifierOptions::default()),
+    Err(Error::InvalidJwt(_))
+  ));
+}
+
 // ===========================================================================
 // Test DocumentVerifier
 // ===========================================================================
@@ -4,10 +4,19 @@
 use core::fmt::Debug;
 use core::fmt::Formatter;
 
+use identity_core::crypto::KeyType;
 use identity_core::utils::decode_b58;
+use identity_core::utils::decode_b64;
 use identity_core::utils::decode_multibase;
 use identity_core::utils::encode_b58;
+use identity_core::utils::encode_b64;
 use identity_core::utils::encode_multibase;
+use libjose::jwk::EcCurve;
+use libjose::jwk::EcxCurve;
+use libjose::jwk::EdCurve;
+use libjose::jwk::Jwk;
+use libjose::jwk::JwkParams;
+use libjose::jwk::JwkParamsEc;
 
 use crate::error::Error;
 use crate::error::Result;
@@ -19,6 +28,7 @@ use crate::error::Result;
 pub enum MethodData {
   PublicKeyMultibase(String),
   PublicKeyBase58(String),
+  PublicKeyJwk(Jwk),
 }
 
 impl MethodData {
@@ -34,10 +44,20 @@ impl MethodData {
     Self::PublicKeyMultibase(encode_multibase(&data, None))
   }
 
+  /// Creates a new `MethodData` variant from a [JSON Web Key](https://tools.ietf.org/html/rfc7517).
+  ///
+  /// NOTE: the key must not contain private key material.
+  pub fn new_jwk(jwk: Jwk) -> Self {
+    Self::PublicKeyJwk(jwk)
+  }
+
   /// Returns a `Vec<u8>` containing the decoded bytes of the `MethodData`.
   ///
   /// This is generally a public key identified by a `MethodType` value.
   ///
+  /// Elliptic curve JWKs are decoded as uncompressed SEC1 points and octet key pair JWKs
+  /// as the raw public key bytes.
+  ///
   /// # Errors
   ///
   /// Decoding can fail if `MethodData` has invalid content or cannot be
@@ -46,15 +66,166 @@ impl MethodData {
     match self {
       Self::PublicKeyMultibase(input) => decode_multibase(input).map_err(|_| Error::InvalidKeyDataMultibase),
       Self::PublicKeyBase58(input) => decode_b58(input).map_err(|_| Error::InvalidKeyDataBase58),
+      Self::PublicKeyJwk(jwk) => decode_jwk(jwk),
+    }
+  }
+
+  /// Returns the [`KeyType`] of the JSON Web Key held by a `PublicKeyJwk` variant, as
+  /// determined by its `crv` parameter.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `MethodData` does not hold a JWK or the curve of the JWK is not supported.
+  pub fn jwk_key_type(&self) -> Result<KeyType> {
+    match self {
+      Self::PublicKeyJwk(jwk) => jwk_key_type(jwk),
+      _ => Err(Error::InvalidKeyDataJwk),
     }
   }
 }
 
+/// Creates a public P-256 JWK from an uncompressed SEC1-encoded public key.
+pub(crate) fn p256_public_jwk(public_key: &[u8]) -> Result<Jwk> {
+  match public_key {
+    [0x04, point @ ..] if point.len() == 64 => Ok(Jwk::from_params(JwkParamsEc {
+      crv: EcCurve::P256.name().to_owned(),
+      x: encode_b64(&point[..32]),
+      y: encode_b64(&point[32..]),
+      d: None,
+    })),
+    _ => Err(Error::InvalidKeyDataJwk),
+  }
+}
+
+fn jwk_key_type(jwk: &Jwk) -> Result<KeyType> {
+  match jwk.params() {
+    JwkParams::Ec(params) => match params.try_ec_curve() {
+      Ok(EcCurve::P256) => Ok(KeyType::P256),
+      Ok(EcCurve::Secp256K1) => Ok(KeyType::Secp256k1),
+      _ => Err(Error::UnsupportedJwkCurve),
+    },
+    JwkParams::Okp(params) => match (params.try_ed_curve(), params.try_ecx_curve()) {
+      (Ok(EdCurve::Ed25519), _) => Ok(KeyType::Ed25519),
+      (_, Ok(EcxCurve::X25519)) => Ok(KeyType::X25519),
+      _ => Err(Error::UnsupportedJwkCurve),
+    },
+    _ => Err(Error::InvalidKeyDataJwk),
+  }
+}
+
+fn decode_jwk(jwk: &Jwk) -> Result<Vec<u8>> {
+  // All supported curves use 32-byte coordinates.
+  let decode_coordinate = |coordinate: &str| -> Result<Vec<u8>> {
+    decode_b64(coordinate)
+      .ok()
+      .filter(|bytes| bytes.len() == 32)
+      .ok_or(Error::InvalidKeyDataJwk)
+  };
+
+  jwk_key_type(jwk)?;
+
+  match jwk.params() {
+    JwkParams::Ec(params) if params.d.is_none() => {
+      let x: Vec<u8> = decode_coordinate(&params.x)?;
+      let y: Vec<u8> = decode_coordinate(&params.y)?;
+
+      // Uncompressed SEC1 encoding: 0x04 || x || y
+      let mut output: Vec<u8> = Vec::with_capacity(1 + x.len() + y.len());
+      output.push(0x04);
+      output.extend(x);
+      output.extend(y);
+
+      Ok(output)
+    }
+    JwkParams::Okp(params) if params.d.is_none() => decode_coordinate(&params.x),
+    _ => Err(Error::InvalidKeyDataJwk),
+  }
+}
+
 impl Debug for MethodData {
   fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
     match self {
       Self::PublicKeyMultibase(inner) => f.write_fmt(format_args!("PublicKeyMultibase({})", inner)),
       Self::PublicKeyBase58(inner) => f.write_fmt(format_args!("PublicKeyBase58({})", inner)),
+      Self::PublicKeyJwk(inner) => f.write_fmt(format_args!("PublicKeyJwk({:?})", inner)),
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+
+  fn ec_jwk(d: Option<String>) -> Jwk {
+    Jwk::from_params(JwkParamsEc {
+      crv: "P-256".to_owned(),
+      x: encode_b64(&[1_u8; 32]),
+      y: encode_b64(&[2_u8; 32]),
+      d,
+    })
+  }
+
+  #[test]
+  fn test_jwk_serde() {
+    let data: MethodData = MethodData::new_jwk(ec_jwk(None));
+    let json: String = data.to_json().unwrap();
+    assert!(json.starts_with(r#"{"publicKeyJwk":{"#));
+    assert_eq!(MethodData::from_json(&json).unwrap(), data);
+  }
+
+  #[test]
+  fn test_p256_public_jwk_roundtrip() {
+    let mut public_key: Vec<u8> = vec![0x04];
+    public_key.extend([7_u8; 64]);
+
+    let jwk: Jwk = p256_public_jwk(&public_key).unwrap();
+    assert_eq!(jwk.try_ec_params().unwrap().crv, "P-256");
+    assert_eq!(MethodData::new_jwk(jwk).try_decode().unwrap(), public_key);
+
+    // Compressed points are not supported.
+    assert!(p256_public_jwk(&[0x02; 33]).is_err());
+  }
+
+  #[test]
+  fn test_jwk_try_decode() {
+    let decoded: Vec<u8> = MethodData::new_jwk(ec_jwk(None)).try_decode().unwrap();
+    assert_eq!(decoded.len(), 65);
+    assert_eq!(decoded[0], 0x04);
+    assert_eq!(&decoded[1..33], &[1_u8; 32]);
+    assert_eq!(&decoded[33..], &[2_u8; 32]);
+
+    // Private key material is rejected.
+    let private: MethodData = MethodData::new_jwk(ec_jwk(Some(encode_b64(&[3_u8; 32]))));
+    assert!(matches!(private.try_decode(), Err(Error::InvalidKeyDataJwk)));
+
+    // Coordinates must match the size of the curve.
+    let short: MethodData = MethodData::new_jwk(Jwk::from_params(JwkParamsEc {
+      crv: "P-256".to_owned(),
+      x: encode_b64(&[1_u8; 31]),
+      y: encode_b64(&[2_u8; 32]),
+      d: None,
+    }));
+    assert!(matches!(short.try_decode(), Err(Error::InvalidKeyDataJwk)));
+  }
+
+  #[test]
+  fn test_jwk_key_type() {
+    assert_eq!(MethodData::new_jwk(ec_jwk(None)).jwk_key_type().unwrap(), KeyType::P256);
+
+    let p384: MethodData = MethodData::new_jwk(Jwk::from_params(JwkParamsEc {
+      crv: "P-384".to_owned(),
+      x: encode_b64(&[1_u8; 48]),
+      y: encode_b64(&[2_u8; 48]),
+      d: None,
+    }));
+    assert!(matches!(p384.jwk_key_type(), Err(Error::UnsupportedJwkCurve)));
+    assert!(matches!(p384.try_decode(), Err(Error::UnsupportedJwkCurve)));
+
+    assert!(matches!(
+      MethodData::new_multibase([1_u8; 32]).jwk_key_type(),
+      Err(Error::InvalidKeyDataJwk)
+    ));
+  }
+}
@@ -10,3 +10,14 @@ pub enum MethodRelationship {
   CapabilityDelegation,
   CapabilityInvocation,
 }
+
+impl MethodRelationship {
+  /// All verification relationships.
+  pub const ALL: [Self; 5] = [
+    Self::Authentication,
+    Self::AssertionMethod,
+    Self::KeyAgreement,
+    Self::CapabilityDelegation,
+    Self::CapabilityInvocation,
+  ];
+}
@@ -13,6 +13,9 @@ use crate::error::Result;
 pub enum MethodType {
   Ed25519VerificationKey2018,
   X25519KeyAgreementKey2019,
+  EcdsaSecp256k1VerificationKey2019,
+  JsonWebKey2020,
+  Bls12381G2Key2020,
 }
 
 impl MethodType {
@@ -20,6 +23,9 @@ impl MethodType {
     match self {
       Self::Ed25519VerificationKey2018 => "Ed25519VerificationKey2018",
       Self::X25519KeyAgreementKey2019 => "X25519KeyAgreementKey2019",
+      Self::EcdsaSecp256k1VerificationKey2019 => "EcdsaSecp256k1VerificationKey2019",
+      Self::JsonWebKey2020 => "JsonWebKey2020",
+      Self::Bls12381G2Key2020 => "Bls12381G2Key2020",
     }
   }
 }
@@ -37,6 +43,9 @@ impl FromStr for MethodType {
     match string {
       "Ed25519VerificationKey2018" => Ok(Self::Ed25519VerificationKey2018),
       "X25519KeyAgreementKey2019" => Ok(Self::X25519KeyAgreementKey2019),
+      "EcdsaSecp256k1VerificationKey2019" => Ok(Self::EcdsaSecp256k1VerificationKey2019),
+      "JsonWebKey2020" => Ok(Self::JsonWebKey2020),
+      "Bls12381G2Key2020" => Ok(Self::Bls12381G2Key2020),
       _ => Err(Error::UnknownMethodType),
     }
   }
@@ -53,6 +62,9 @@ mod tests {
     for method_type in [
       MethodType::Ed25519VerificationKey2018,
       MethodType::X25519KeyAgreementKey2019,
+      MethodType::EcdsaSecp256k1VerificationKey2019,
+      MethodType::JsonWebKey2020,
+      MethodType::Bls12381G2Key2020,
     ] {
       let ser: Value = serde_json::to_value(&method_type).unwrap();
       assert_eq!(ser.as_str().unwrap(), method_type.as_str());
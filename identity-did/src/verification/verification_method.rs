@@ -19,6 +19,7 @@ use crate::did::DIDUrl;
 use crate::did::DID;
 use crate::error::Error;
 use crate::error::Result;
+use crate::verification::method_data::p256_public_jwk;
 use crate::verification::MethodBuilder;
 use crate::verification::MethodData;
 use crate::verification::MethodRef;
@@ -138,6 +139,23 @@ where
     &mut self.data
   }
 
+  /// Returns the [`KeyType`] of the public key held by the verification `Method`.
+  ///
+  /// The key type of `JsonWebKey2020` methods is determined by the curve of the JWK.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the curve of a `JsonWebKey2020` method is not supported.
+  pub fn key_type(&self) -> Result<KeyType> {
+    match self.type_ {
+      MethodType::Ed25519VerificationKey2018 => Ok(KeyType::Ed25519),
+      MethodType::X25519KeyAgreementKey2019 => Ok(KeyType::X25519),
+      MethodType::EcdsaSecp256k1VerificationKey2019 => Ok(KeyType::Secp256k1),
+      MethodType::JsonWebKey2020 => self.data.jwk_key_type(),
+      MethodType::Bls12381G2Key2020 => Ok(KeyType::Bls12381G2),
+    }
+  }
+
   /// Returns a reference to the custom verification `Method` properties.
   pub fn properties(&self) -> &T {
     &self.properties
@@ -213,6 +231,18 @@ where
         builder = builder.type_(MethodType::X25519KeyAgreementKey2019);
         builder = builder.data(MethodData::new_multibase(public_key));
       }
+      KeyType::Secp256k1 => {
+        builder = builder.type_(MethodType::EcdsaSecp256k1VerificationKey2019);
+        builder = builder.data(MethodData::new_multibase(public_key));
+      }
+      KeyType::P256 => {
+        builder = builder.type_(MethodType::JsonWebKey2020);
+        builder = builder.data(MethodData::new_jwk(p256_public_jwk(public_key.as_ref())?));
+      }
+      KeyType::Bls12381G2 => {
+        builder = builder.type_(MethodType::Bls12381G2Key2020);
+        builder = builder.data(MethodData::new_multibase(public_key));
+      }
     }
     builder.build()
   }
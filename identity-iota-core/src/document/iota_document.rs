@@ -22,8 +22,10 @@ use identity_core::crypto::PublicKey;
 use identity_core::crypto::SetSignature;
 use identity_core::crypto::Signer;
 use identity_did::document::CoreDocument;
+use identity_did::revocation::RevocationBitmap;
 use identity_did::service::Service;
 use identity_did::utils::DIDUrlQuery;
+use identity_did::utils::Queryable;
 use identity_did::verifiable::DocumentSigner;
 use identity_did::verifiable::VerifierOptions;
 use identity_did::verification::MethodRef;
@@ -34,6 +36,7 @@ use identity_did::verification::MethodUriType;
 use identity_did::verification::TryMethod;
 use identity_did::verification::VerificationMethod;
 use serde;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -256,6 +259,75 @@ impl IotaDocument {
     Ok(())
   }
 
+  /// Returns the [`RevocationBitmap`] published by the service matching `service_query`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if no service matches the query or it does not publish a valid [`RevocationBitmap`].
+  pub fn resolve_revocation_bitmap<'query, Q>(&self, service_query: Q) -> Result<RevocationBitmap>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+  {
+    self
+      .document
+      .service()
+      .query(service_query.into())
+      .ok_or(Error::ServiceNotFound)
+      .and_then(|service| RevocationBitmap::try_from(service).map_err(Into::into))
+  }
+
+  /// Revokes the credentials with the given `indices` in the [`RevocationBitmap`] published by the service
+  /// matching `service_query`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if no service matches the query or it does not publish a valid [`RevocationBitmap`].
+  pub fn revoke_credentials<'query, Q>(&mut self, service_query: Q, indices: &[u32]) -> Result<()>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+  {
+    self.update_revocation_bitmap(service_query, |bitmap| {
+      for index in indices {
+        bitmap.revoke(*index);
+      }
+    })
+  }
+
+  /// Lifts the revocation of the credentials with the given `indices` in the [`RevocationBitmap`] published by the
+  /// service matching `service_query`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if no service matches the query or it does not publish a valid [`RevocationBitmap`].
+  pub fn unrevoke_credentials<'query, Q>(&mut self, service_query: Q, indices: &[u32]) -> Result<()>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+  {
+    self.update_revocation_bitmap(service_query, |bitmap| {
+      for index in indices {
+        bitmap.unrevoke(*index);
+      }
+    })
+  }
+
+  fn update_revocation_bitmap<'query, Q, F>(&mut self, service_query: Q, f: F) -> Result<()>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+    F: FnOnce(&mut RevocationBitmap),
+  {
+    let service: &mut IotaService = self
+      .document
+      .service_mut()
+      .query_mut(service_query.into())
+      .ok_or(Error::ServiceNotFound)?;
+
+    let mut bitmap: RevocationBitmap = RevocationBitmap::try_from(&*service)?;
+    f(&mut bitmap);
+    *service.service_endpoint_mut() = bitmap.to_endpoint()?;
+
+    Ok(())
+  }
+
   // ===========================================================================
   // Verification Methods
   // ===========================================================================
@@ -376,6 +448,33 @@ impl IotaDocument {
       .map_err(Into::into)
   }
 
+  /// Signs the provided `claims` as a compact JSON Web Token with the verification method
+  /// specified by `method_query`. See [`IotaDocument::signer`] for the builder pattern.
+  ///
+  /// NOTE: does not validate whether `private_key` corresponds to the verification method.
+  /// See [`IotaDocument::verify_jwt`].
+  ///
+  /// # Errors
+  ///
+  /// Fails if the method cannot sign JWTs, claims serialization fails, or the signature
+  /// operation fails.
+  pub fn sign_jwt<'query, 'this: 'query, X, Q>(
+    &'this self,
+    claims: &X,
+    private_key: &'this PrivateKey,
+    method_query: Q,
+  ) -> Result<String>
+  where
+    X: Serialize,
+    Q: Into<DIDUrlQuery<'query>>,
+  {
+    self
+      .signer(private_key)
+      .method(method_query)
+      .sign_jwt(claims)
+      .map_err(Into::into)
+  }
+
   /// Signs this DID document with the verification method specified by `method_query`.
   /// The `method_query` may be the full [`IotaDIDUrl`] of the method or just its fragment,
   /// e.g. "#sign-0". The signing method must have a capability invocation verification
@@ -420,6 +519,24 @@ impl IotaDocument {
           None,
         ));
       }
+      MethodType::EcdsaSecp256k1VerificationKey2019 => {
+        // Only methods in `UPDATE_METHOD_TYPES` may sign documents.
+        return Err(Error::DocumentSignError(
+          "EcdsaSecp256k1VerificationKey2019 cannot sign documents",
+          None,
+        ));
+      }
+      MethodType::JsonWebKey2020 => {
+        // Only methods in `UPDATE_METHOD_TYPES` may sign documents.
+        return Err(Error::DocumentSignError("JsonWebKey2020 cannot sign documents", None));
+      }
+      MethodType::Bls12381G2Key2020 => {
+        // Only methods in `UPDATE_METHOD_TYPES` may sign documents.
+        return Err(Error::DocumentSignError(
+          "Bls12381G2Key2020 cannot sign documents",
+          None,
+        ));
+      }
     }
 
     Ok(())
@@ -443,6 +560,20 @@ impl IotaDocument {
     self.document.verify_data(data, options).map_err(Into::into)
   }
 
+  /// Decodes the claims of a compact JSON Web Token after verifying its signature was created
+  /// using a verification method in this DID Document.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the token is malformed, an unsupported verification method is used, the
+  /// verification operation fails or the claims cannot be deserialized.
+  pub fn verify_jwt<X>(&self, jwt: &str, options: &VerifierOptions) -> Result<X>
+  where
+    X: DeserializeOwned,
+  {
+    self.document.verify_jwt(jwt, options).map_err(Into::into)
+  }
+
   /// Verifies that the signature on the DID document `signed` was generated by a valid method from
   /// this DID document.
   ///
@@ -1297,6 +1428,39 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_verify_data_p256() {
+    let key: KeyPair = generate_testkey();
+    let mut document: IotaDocument = IotaDocument::new(&key).unwrap();
+
+    // Add a P-256 method side by side with the default Ed25519 method.
+    let key_p256: KeyPair = KeyPair::new(KeyType::P256).unwrap();
+    let method: IotaVerificationMethod =
+      IotaVerificationMethod::new(document.id().clone(), key_p256.type_(), key_p256.public(), "p256-1").unwrap();
+    assert_eq!(method.type_(), MethodType::JsonWebKey2020);
+    document.insert_method(method, MethodScope::assertion_method()).unwrap();
+
+    // The method survives a serialization round-trip.
+    let document: IotaDocument = IotaDocument::from_json(&document.to_json().unwrap()).unwrap();
+
+    let mut data: VerifiableProperties = VerifiableProperties::default();
+    data.properties.insert("foo".to_owned(), 42.into());
+    document
+      .sign_data(&mut data, key_p256.private(), "p256-1", ProofOptions::default())
+      .unwrap();
+    assert!(document.verify_data(&data, &VerifierOptions::default()).is_ok());
+    assert!(document
+      .verify_data(
+        &data,
+        &VerifierOptions::new().method_type(vec![MethodType::JsonWebKey2020])
+      )
+      .is_ok());
+
+    // JsonWebKey2020 methods cannot sign the document itself.
+    let mut document: IotaDocument = document;
+    assert!(document.sign_self(key_p256.private(), "p256-1").is_err());
+  }
+
   #[test]
   fn test_root_document() {
     let keypair: KeyPair = generate_testkey();
@@ -1497,6 +1661,32 @@ mod tests {
     assert_eq!(0, document.service().len());
   }
 
+  #[test]
+  fn test_revoke_credentials() {
+    let keypair: KeyPair = generate_testkey();
+    let mut document: IotaDocument = IotaDocument::new(&keypair).unwrap();
+    let service_id: IotaDIDUrl = document.id().to_url().join("#revocation").unwrap();
+    let service: IotaService = RevocationBitmap::new().to_service(service_id.clone()).unwrap();
+    assert!(document.insert_service(service));
+
+    document.revoke_credentials("#revocation", &[0, 5, 1_000]).unwrap();
+    document.unrevoke_credentials(&service_id, &[5]).unwrap();
+
+    let bitmap: RevocationBitmap = document.resolve_revocation_bitmap("#revocation").unwrap();
+    assert!(bitmap.is_revoked(0));
+    assert!(!bitmap.is_revoked(5));
+    assert!(bitmap.is_revoked(1_000));
+
+    // The revocation state survives a serialization round trip.
+    let document: IotaDocument = IotaDocument::from_json(&document.to_json().unwrap()).unwrap();
+    assert_eq!(document.resolve_revocation_bitmap(&service_id).unwrap(), bitmap);
+
+    assert!(matches!(
+      document.resolve_revocation_bitmap("#missing"),
+      Err(Error::ServiceNotFound)
+    ));
+  }
+
   #[test]
   fn test_relative_method_uri() {
     let keypair: KeyPair = generate_testkey();
@@ -17,28 +17,30 @@ use serde::Serialize;
 
 use crate::chain::milestone::sort_by_milestone;
 use crate::chain::IntegrationChain;
+use crate::chain::Rejection;
 use crate::document::ResolvedIotaDocument;
 use crate::error::Error;
 use crate::error::Result;
-use crate::tangle::Client;
 use crate::tangle::MessageExt;
 use crate::tangle::MessageIndex;
 use crate::tangle::PublishType;
+use crate::tangle::TangleBackend;
 use crate::tangle::TangleRef;
 
 #[deprecated(since = "0.5.0", note = "diff chain features are slated for removal")]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(transparent)]
 pub struct DiffChain {
+  #[serde(with = "serde_diff_messages")]
   inner: Vec<DiffMessage>,
 }
 
 impl DiffChain {
   /// Constructs a new [`DiffChain`] for the given [`IntegrationChain`] from a slice of [`Messages`][Message].
-  pub async fn try_from_messages(
+  pub async fn try_from_messages<C: TangleBackend + ?Sized>(
     integration_chain: &IntegrationChain,
     messages: &[Message],
-    client: &Client,
+    client: &C,
   ) -> Result<Self> {
     let did: &IotaDID = integration_chain.current().document.id();
 
@@ -53,10 +55,10 @@ impl DiffChain {
   }
 
   /// Constructs a new [`DiffChain`] for the given [`IntegrationChain`] from the given [`MessageIndex`].
-  pub async fn try_from_index(
+  pub async fn try_from_index<C: TangleBackend + ?Sized>(
     integration_chain: &IntegrationChain,
     index: MessageIndex<DiffMessage>,
-    client: &Client,
+    client: &C,
   ) -> Result<Self> {
     log::trace!("[Diff] Message Index = {:#?}", index);
     Self::try_from_index_with_document(integration_chain.current(), index, client).await
@@ -64,28 +66,44 @@ impl DiffChain {
 
   /// Constructs a new [`DiffChain`] from the given [`MessageIndex`], using an integration document
   /// to validate.
-  pub(in crate::chain) async fn try_from_index_with_document(
+  pub(in crate::chain) async fn try_from_index_with_document<C: TangleBackend + ?Sized>(
     integration_document: &ResolvedIotaDocument,
-    mut index: MessageIndex<DiffMessage>,
-    client: &Client,
+    index: MessageIndex<DiffMessage>,
+    client: &C,
   ) -> Result<Self> {
+    let mut this: Self = Self::new();
+    this.extend_from_index(integration_document, index, client).await?;
+    Ok(this)
+  }
+
+  /// Appends the valid diffs from the given [`MessageIndex`] that continue the chain from its current
+  /// diff, or from the integration document if the chain is empty.
+  pub(crate) async fn extend_from_index<C: TangleBackend + ?Sized>(
+    &mut self,
+    integration_document: &ResolvedIotaDocument,
+    mut index: MessageIndex<DiffMessage>,
+    client: &C,
+  ) -> Result<()> {
     if index.is_empty() {
-      return Ok(Self::new());
+      return Ok(());
     }
 
-    let mut this: Self = Self::new();
     let mut current_document: ResolvedIotaDocument = integration_document.clone();
+    for diff in self.iter() {
+      current_document.merge_diff_message(diff)?;
+    }
+
     while let Some(diffs) = index.remove(
-      this
+      self
         .current_message_id()
-        .unwrap_or_else(|| current_document.message_id()),
+        .unwrap_or_else(|| integration_document.message_id()),
     ) {
       // Extract diffs that reference the last message (either the integration message or the
       // diff message from the previous iteration). If more than one references the
       // same message, they are conflicting.
-      let expected_prev_message_id: &MessageId = this
+      let expected_prev_message_id: &MessageId = self
         .current_message_id()
-        .unwrap_or_else(|| current_document.message_id());
+        .unwrap_or_else(|| integration_document.message_id());
       // Filter out diffs with invalid signatures.
       let valid_diffs: Vec<DiffMessage> = diffs
         .into_iter()
@@ -108,12 +126,12 @@ impl DiffChain {
         // Update the document for the next diff to allow updating sections added by previous diffs.
         current_document = merged_document;
         // Checked by verify_diff and try_merge above.
-        this.push_unchecked(diff);
+        self.push_unchecked(diff);
       }
       // If no diff is appended, the chain ends.
     }
 
-    Ok(this)
+    Ok(())
   }
 
   /// Creates a new [`DiffChain`].
@@ -121,6 +139,11 @@ impl DiffChain {
     Self { inner: Vec::new() }
   }
 
+  /// Creates a new [`DiffChain`] from the given diffs without validating them.
+  pub(crate) fn new_unchecked(inner: Vec<DiffMessage>) -> Self {
+    Self { inner }
+  }
+
   /// Returns the total number of diffs.
   pub fn len(&self) -> usize {
     self.inner.len()
@@ -183,32 +206,41 @@ impl DiffChain {
     document: &ResolvedIotaDocument,
     expected_prev_message_id: &MessageId,
   ) -> Result<()> {
+    Self::check_diff(diff, document, expected_prev_message_id).map_err(|rejection| Error::ChainError {
+      error: match rejection {
+        Rejection::InvalidDid => "invalid DID",
+        Rejection::MissingMessageId => "invalid message id",
+        Rejection::MissingPreviousMessageId | Rejection::InvalidPreviousMessageId => "invalid previous message id",
+        Rejection::InvalidSignature => "invalid diff signature",
+      },
+    })
+  }
+
+  /// Checks whether the [`DiffMessage`] attributes and signature are valid, returning the
+  /// [`Rejection`] reason if not.
+  pub(crate) fn check_diff(
+    diff: &DiffMessage,
+    document: &ResolvedIotaDocument,
+    expected_prev_message_id: &MessageId,
+  ) -> core::result::Result<(), Rejection> {
     if document.document.id() != diff.id() {
-      return Err(Error::ChainError { error: "invalid DID" });
+      return Err(Rejection::InvalidDid);
     }
 
     if diff.message_id().is_null() {
-      return Err(Error::ChainError {
-        error: "invalid message id",
-      });
+      return Err(Rejection::MissingMessageId);
     }
 
     if diff.previous_message_id().is_null() {
-      return Err(Error::ChainError {
-        error: "invalid previous message id",
-      });
+      return Err(Rejection::MissingPreviousMessageId);
     }
 
     if diff.previous_message_id() != expected_prev_message_id {
-      return Err(Error::ChainError {
-        error: "invalid previous message id",
-      });
+      return Err(Rejection::InvalidPreviousMessageId);
     }
 
     if document.document.verify_diff(diff).is_err() {
-      return Err(Error::ChainError {
-        error: "invalid diff signature",
-      });
+      return Err(Rejection::InvalidSignature);
     }
 
     Ok(())
@@ -247,6 +279,28 @@ impl DiffChain {
     Self::verify_diff(diff, document, expected_prev_message_id)?;
     Self::try_merge(diff, document)
   }
+
+  /// Verifies that the [`DiffChain`] is a valid chain of diffs for the given integration
+  /// document, e.g. after deserializing it. Returns the integration document merged with all diffs.
+  ///
+  /// See [`DocumentChain::verify`](crate::chain::DocumentChain::verify) for the limitations of
+  /// verifying without access to the Tangle.
+  ///
+  /// # Errors
+  ///
+  /// Fails if any diff is not a valid addition to the chain.
+  pub fn verify(&self, integration_document: &ResolvedIotaDocument) -> Result<ResolvedIotaDocument> {
+    let mut current_document: ResolvedIotaDocument = integration_document.clone();
+    let mut expected_prev_message_id: &MessageId = integration_document.message_id();
+
+    for diff in self.iter() {
+      Self::verify_diff(diff, integration_document, expected_prev_message_id)?;
+      current_document = Self::try_merge(diff, &current_document)?;
+      expected_prev_message_id = diff.message_id();
+    }
+
+    Ok(current_document)
+  }
 }
 
 impl Default for DiffChain {
@@ -267,6 +321,57 @@ impl From<DiffChain> for Vec<DiffMessage> {
   }
 }
 
+/// (De)serializes [`DiffMessages`](DiffMessage) along with their [`MessageIds`](MessageId), which
+/// are otherwise skipped as they are not part of the signed diff.
+pub(crate) mod serde_diff_messages {
+  use identity_iota_core::diff::DiffMessage;
+  use identity_iota_core::tangle::MessageId;
+  use identity_iota_core::tangle::MessageIdExt;
+  use serde::Deserialize;
+  use serde::Deserializer;
+  use serde::Serialize;
+  use serde::Serializer;
+
+  #[derive(Deserialize, Serialize)]
+  struct DiffEntry<T> {
+    #[serde(flatten)]
+    diff: T,
+    #[serde(
+      rename = "messageId",
+      default = "MessageId::null",
+      skip_serializing_if = "MessageIdExt::is_null"
+    )]
+    message_id: MessageId,
+  }
+
+  pub(crate) fn serialize<S>(diffs: &[DiffMessage], serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_seq(diffs.iter().map(|diff| DiffEntry {
+      diff,
+      message_id: *diff.message_id(),
+    }))
+  }
+
+  pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Vec<DiffMessage>, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let entries: Vec<DiffEntry<DiffMessage>> = Vec::deserialize(deserializer)?;
+
+    Ok(
+      entries
+        .into_iter()
+        .map(|DiffEntry { mut diff, message_id }| {
+          diff.set_message_id(message_id);
+          diff
+        })
+        .collect(),
+    )
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use identity_core::convert::FromJson;
@@ -281,6 +386,7 @@ mod tests {
   use identity_iota_core::tangle::MessageId;
 
   use crate::document::ResolvedIotaDocument;
+  use crate::tangle::Client;
   use crate::tangle::ClientBuilder;
   use crate::tangle::MessageIndex;
   use crate::tangle::TangleRef;
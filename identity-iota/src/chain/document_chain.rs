@@ -3,10 +3,15 @@
 
 use core::fmt::Display;
 use core::fmt::Formatter;
+use std::collections::HashSet;
 
+use futures::stream::StreamExt;
+use futures::stream::TryStreamExt;
 use identity_core::convert::FmtJson;
 use identity_iota_core::did::IotaDID;
 use identity_iota_core::diff::DiffMessage;
+use identity_iota_core::document::IotaDocument;
+use identity_iota_core::tangle::Message;
 use identity_iota_core::tangle::MessageId;
 use serde::Deserialize;
 use serde::Serialize;
@@ -14,7 +19,15 @@ use serde::Serialize;
 use crate::chain::DiffChain;
 use crate::chain::IntegrationChain;
 use crate::document::ResolvedIotaDocument;
+use crate::error::Error;
 use crate::error::Result;
+use crate::tangle::MessageExt;
+use crate::tangle::MessageIndex;
+use crate::tangle::TangleBackend;
+use crate::tangle::TangleRef;
+
+/// The maximum number of messages downloaded concurrently when extending a chain.
+pub(crate) const MAX_CONCURRENT_DOWNLOADS: usize = 16;
 
 /// Holds an [`IntegrationChain`] and its corresponding [`DiffChain`] that can be used to resolve the
 /// latest version of a [`ResolvedIotaDocument`].
@@ -125,6 +138,35 @@ impl DocumentChain {
     Self::__diff_message_id(&self.chain_i, &self.chain_d)
   }
 
+  /// Verifies the [`IntegrationChain`] and [`DiffChain`], e.g. after deserializing a
+  /// [`DocumentChain`] exported from another system, without requiring access to the Tangle.
+  ///
+  /// NOTE: this cannot detect whether a conflicting message was referenced by an earlier milestone,
+  /// which requires access to the Tangle. The chains are only checked to be valid on their own, so
+  /// a chain containing a message that lost such a conflict still verifies. The same applies to
+  /// [`IntegrationChain::verify`], [`DiffChain::verify`] and
+  /// [`DocumentHistory::verify`](crate::chain::DocumentHistory::verify).
+  ///
+  /// Likewise, a truncated snapshot still verifies: a chain missing its latest messages, e.g. later
+  /// key rotations or removed verification methods, is indistinguishable from an up-to-date chain,
+  /// so its document may list methods which were since revoked.
+  ///
+  /// # Errors
+  ///
+  /// Fails if either chain is invalid or the latest document does not match the merged chains.
+  pub fn verify(&self) -> Result<()> {
+    self.chain_i.verify()?;
+    let document: ResolvedIotaDocument = self.chain_d.verify(self.chain_i.current())?;
+
+    if self.current() != &document {
+      return Err(Error::ChainError {
+        error: "Invalid Document State",
+      });
+    }
+
+    Ok(())
+  }
+
   /// Adds a new integration document to the chain.
   ///
   /// # Errors
@@ -154,6 +196,99 @@ impl DocumentChain {
 
     Ok(())
   }
+
+  /// Fetches the integration and diff messages of the chain from the Tangle and appends the valid
+  /// ones published after the current state of the chain.
+  ///
+  /// Only the messages which are not already part of the chain are downloaded, and only messages
+  /// continuing the chain are validated; the existing documents and diffs are assumed to be valid.
+  ///
+  /// The ids of downloaded messages which are not documents or diffs of the DID are added to `spam`,
+  /// and messages already in `spam` are not downloaded again.
+  pub(crate) async fn extend_from_tangle<C: TangleBackend + ?Sized>(
+    &mut self,
+    client: &C,
+    spam: &mut HashSet<MessageId>,
+  ) -> Result<()> {
+    let did: IotaDID = self.id().clone();
+
+    let known: HashSet<MessageId> = self
+      .chain_i
+      .history()
+      .into_iter()
+      .flatten()
+      .chain(core::iter::once(self.chain_i.current()))
+      .map(|document| *document.message_id())
+      .collect();
+    let messages: Vec<Message> = read_unknown_messages(client, did.tag(), &known, spam).await?;
+    let index: MessageIndex<ResolvedIotaDocument> =
+      extract_messages(&messages, spam, |message| message.try_extract_document(&did))
+        .into_iter()
+        .collect();
+
+    let integration_message_id: MessageId = *self.integration_message_id();
+    self.chain_i.extend_from_index(index, client).await?;
+    if self.chain_i.current_message_id() != &integration_message_id {
+      // Diffs only apply to the integration document they were published for.
+      self.chain_d.clear();
+    }
+
+    let known: HashSet<MessageId> = self.chain_d.iter().map(|diff| *diff.message_id()).collect();
+    let diff_index: String = IotaDocument::diff_index(self.chain_i.current_message_id())?;
+    let messages: Vec<Message> = read_unknown_messages(client, &diff_index, &known, spam).await?;
+    let index: MessageIndex<DiffMessage> = extract_messages(&messages, spam, |message| message.try_extract_diff(&did))
+      .into_iter()
+      .collect();
+    self
+      .chain_d
+      .extend_from_index(self.chain_i.current(), index, client)
+      .await?;
+
+    self.document = if self.chain_d.is_empty() {
+      None
+    } else {
+      Some(Self::__fold(&self.chain_i, &self.chain_d)?)
+    };
+
+    Ok(())
+  }
+}
+
+/// Fetches the messages published to the given `index`, except the ones identified by `known` or
+/// `spam`, downloading at most [`MAX_CONCURRENT_DOWNLOADS`] messages at a time.
+async fn read_unknown_messages<C: TangleBackend + ?Sized>(
+  client: &C,
+  index: &str,
+  known: &HashSet<MessageId>,
+  spam: &HashSet<MessageId>,
+) -> Result<Vec<Message>> {
+  let message_ids: Vec<MessageId> = client.read_message_ids(index).await?;
+
+  futures::stream::iter(message_ids)
+    .filter(|message_id| futures::future::ready(!known.contains(message_id) && !spam.contains(message_id)))
+    .map(|message_id| async move { client.read_message(&message_id).await })
+    .buffer_unordered(MAX_CONCURRENT_DOWNLOADS)
+    .try_collect()
+    .await
+}
+
+/// Extracts the documents or diffs of the `messages`, adding the ids of the messages which cannot
+/// be extracted to `spam`.
+fn extract_messages<T>(
+  messages: &[Message],
+  spam: &mut HashSet<MessageId>,
+  extract: impl Fn(&Message) -> Option<T>,
+) -> Vec<T> {
+  messages
+    .iter()
+    .filter_map(|message| {
+      let extracted: Option<T> = extract(message);
+      if extracted.is_none() {
+        spam.insert(message.id().0);
+      }
+      extracted
+    })
+    .collect()
 }
 
 impl Display for DocumentChain {
@@ -466,6 +601,45 @@ mod test {
     ));
   }
 
+  #[test]
+  fn test_check_valid_addition_accepts_earlier_timestamp() {
+    // =========================================================================
+    // Create Initial Document
+    // =========================================================================
+    let (resolved, keypair): (ResolvedIotaDocument, KeyPair) = create_initial_document();
+    let mut chain: DocumentChain = DocumentChain::new(IntegrationChain::new(resolved.clone()).unwrap());
+
+    // =========================================================================
+    // Create DiffMessage Setting an Earlier Timestamp
+    // =========================================================================
+    let mut new_resolved: ResolvedIotaDocument = resolved.clone();
+    new_resolved.document.properties_mut().insert("foo".into(), 123.into());
+    new_resolved.document.metadata.updated = Some(Timestamp::from_unix(0).unwrap());
+    new_resolved.document.metadata.previous_message_id = *chain.integration_message_id();
+
+    let diff_msg: DiffMessage =
+      create_signed_diff_message(&resolved.document, &new_resolved.document, &chain, keypair.private());
+
+    // Timestamps are not enforced by the chain, its order is authoritative.
+    assert!(DiffChain::check_valid_addition(&diff_msg, &resolved, chain.integration_message_id()).is_ok());
+
+    // =========================================================================
+    // Create Integration Update Setting an Earlier Timestamp
+    // =========================================================================
+    new_resolved.set_message_id(MessageId::new([2; 32]));
+    resolved
+      .document
+      .sign_data(
+        &mut new_resolved.document,
+        keypair.private(),
+        resolved.document.default_signing_method().unwrap().id(),
+        ProofOptions::default(),
+      )
+      .unwrap();
+
+    assert!(chain.try_push_integration(new_resolved).is_ok());
+  }
+
   fn create_initial_document() -> (ResolvedIotaDocument, KeyPair) {
     let keypair: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
     let mut document: IotaDocument = IotaDocument::new(&keypair).unwrap();
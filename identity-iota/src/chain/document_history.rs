@@ -13,13 +13,18 @@ use serde;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::chain::history_report;
 use crate::chain::DiffChain;
+use crate::chain::DocumentChain;
+use crate::chain::HistoryReport;
 use crate::chain::IntegrationChain;
+use crate::chain::MessageReport;
 use crate::document::ResolvedIotaDocument;
+use crate::error::Error;
 use crate::error::Result;
-use crate::tangle::Client;
 use crate::tangle::MessageExt;
 use crate::tangle::MessageIndex;
+use crate::tangle::TangleBackend;
 use crate::tangle::TangleRef;
 
 /// A DID Document's history and current state.
@@ -34,7 +39,7 @@ pub struct DocumentHistory {
   pub integration_chain_spam: Vec<MessageId>,
   /// List of diffs for the last integration chain document.
   #[deprecated(since = "0.5.0", note = "diff chain features are slated for removal")]
-  #[serde(rename = "diffChainData")]
+  #[serde(rename = "diffChainData", with = "crate::chain::diff_chain::serde_diff_messages")]
   pub diff_chain_data: Vec<DiffMessage>,
   /// List of "spam" messages on the diff chain index.
   #[deprecated(since = "0.5.0", note = "diff chain features are slated for removal")]
@@ -45,7 +50,7 @@ pub struct DocumentHistory {
 impl DocumentHistory {
   /// Read the [`DocumentHistory`] of the DID Document identified by the given [`IotaDID`] from the
   /// Tangle.
-  pub async fn read(client: &Client, did: &IotaDID) -> Result<Self> {
+  pub async fn read<C: TangleBackend + ?Sized>(client: &C, did: &IotaDID) -> Result<Self> {
     // Fetch and parse the integration chain
     let integration_messages: Vec<Message> = client.read_messages(did.tag()).await?;
     let integration_chain = IntegrationChain::try_from_messages(did, &integration_messages, client).await?;
@@ -65,6 +70,54 @@ impl DocumentHistory {
       diff_chain_spam: diff_chain_history.spam,
     })
   }
+
+  /// Verifies the integration and diff chain data of the [`DocumentHistory`] without requiring
+  /// access to the Tangle, e.g. after deserializing a [`DocumentHistory`] exported from another
+  /// system. Returns the validated [`DocumentChain`].
+  ///
+  /// Spam messages are not verified. See [`DocumentChain::verify`] for the limitations of
+  /// verifying without access to the Tangle.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the integration chain data is empty or either chain is invalid.
+  pub fn verify(&self) -> Result<DocumentChain> {
+    let integration_chain: IntegrationChain =
+      IntegrationChain::try_from_documents(self.integration_chain_data.iter().cloned())?;
+    let diff_chain: DiffChain = DiffChain::new_unchecked(self.diff_chain_data.clone());
+    diff_chain.verify(integration_chain.current())?;
+
+    DocumentChain::new_with_diff_chain(integration_chain, diff_chain)
+  }
+
+  /// Produces a [`HistoryReport`] explaining how each message of the [`DocumentHistory`] was
+  /// resolved, fetching the spam messages and their milestones from the Tangle to determine why
+  /// they were rejected.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the integration chain data is empty or a message cannot be fetched.
+  pub async fn report<C: TangleBackend + ?Sized>(&self, client: &C) -> Result<HistoryReport> {
+    let integration_document: &ResolvedIotaDocument = self.integration_chain_data.last().ok_or(Error::ChainError {
+      error: "Missing Root Document",
+    })?;
+
+    let integration_chain: Vec<MessageReport> =
+      history_report::report_integration_chain(&self.integration_chain_data, &self.integration_chain_spam, client)
+        .await?;
+    let diff_chain: Vec<MessageReport> = history_report::report_diff_chain(
+      integration_document,
+      &self.diff_chain_data,
+      &self.diff_chain_spam,
+      client,
+    )
+    .await?;
+
+    Ok(HistoryReport {
+      integration_chain,
+      diff_chain,
+    })
+  }
 }
 
 /// A list of messages on an integration chain or diff chain.
@@ -103,10 +156,10 @@ impl ChainHistory<DiffMessage> {
   ///
   /// This is useful for constructing histories of old diff chains no longer at the end of an
   /// integration chain.
-  pub async fn try_from_raw_messages(
+  pub async fn try_from_raw_messages<C: TangleBackend + ?Sized>(
     document: &ResolvedIotaDocument,
     messages: &[Message],
-    client: &Client,
+    client: &C,
   ) -> Result<Self> {
     let did: &IotaDID = document.document.id();
     let index: MessageIndex<DiffMessage> = messages
@@ -139,3 +192,232 @@ impl From<(DiffChain, &[Message])> for ChainHistory<DiffMessage> {
     Self { chain_data, spam }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use identity_core::common::Timestamp;
+  use identity_core::common::Value;
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+  use identity_core::crypto::KeyPair;
+
+  use super::*;
+  use crate::chain::MessageIssue;
+  use crate::tangle::pack_did_message;
+  use crate::tangle::Client;
+  use crate::tangle::DIDMessageEncoding;
+  use crate::tangle::MemoryTangle;
+  use crate::test_utils::add_service;
+  use crate::test_utils::generate_document;
+  use crate::test_utils::sign;
+  use crate::test_utils::Identity;
+
+  /// Publishes a root document, an integration update and two diffs.
+  async fn publish_identity(client: &Client) -> IotaDID {
+    let mut identity: Identity = Identity::publish(client).await;
+    let updated: IotaDocument = identity.publish_integration(client, "integration").await;
+
+    let mut previous_message_id: MessageId = identity.message_id;
+    let mut current: IotaDocument = updated.clone();
+    for fragment in ["diff-1", "diff-2"] {
+      let next: IotaDocument = add_service(&current, fragment);
+      let diff: DiffMessage = current
+        .diff(
+          &next,
+          previous_message_id,
+          identity.keypair.private(),
+          updated.default_signing_method().unwrap().id(),
+        )
+        .unwrap();
+      previous_message_id = *client
+        .publish_diff(&identity.message_id, &diff)
+        .await
+        .unwrap()
+        .message_id();
+      current = next;
+    }
+
+    updated.id().clone()
+  }
+
+  async fn client() -> Client {
+    Client::builder()
+      .backend(Arc::new(MemoryTangle::new()))
+      .build()
+      .await
+      .unwrap()
+  }
+
+  #[tokio::test]
+  async fn test_document_chain_snapshot() {
+    let client: Client = client().await;
+    let did: IotaDID = publish_identity(&client).await;
+    let chain: DocumentChain = client.read_document_chain(&did).await.unwrap();
+    assert_eq!(chain.diff().len(), 2);
+
+    let json: String = chain.to_json().unwrap();
+    let restored: DocumentChain = DocumentChain::from_json(&json).unwrap();
+    restored.verify().unwrap();
+    assert_eq!(restored.current(), chain.current());
+    assert_eq!(restored.diff_message_id(), chain.diff_message_id());
+
+    // Tampering with the message ids of the diff chain is detected.
+    let mut value: Value = chain.to_json_value().unwrap();
+    value["chain_d"][0]["messageId"] = Value::String(MessageId::new([1; 32]).to_string());
+    let tampered: DocumentChain = DocumentChain::from_json_value(value).unwrap();
+    assert!(tampered.verify().is_err());
+  }
+
+  #[tokio::test]
+  async fn test_document_history_snapshot() {
+    let client: Client = client().await;
+    let did: IotaDID = publish_identity(&client).await;
+    let history: DocumentHistory = client.resolve_history(&did).await.unwrap();
+
+    let restored: DocumentHistory = DocumentHistory::from_json(&history.to_json().unwrap()).unwrap();
+    let chain: DocumentChain = restored.verify().unwrap();
+    assert_eq!(chain.current(), &client.read_document(&did).await.unwrap());
+
+    // A history missing its root document is invalid.
+    let mut tampered: DocumentHistory = restored.clone();
+    tampered.integration_chain_data.remove(0);
+    assert!(tampered.verify().is_err());
+
+    // A diff chain with a broken link is invalid.
+    let mut tampered: DocumentHistory = restored.clone();
+    tampered.diff_chain_data[0].set_message_id(MessageId::new([1; 32]));
+    assert!(tampered.verify().is_err());
+
+    // Empty histories are invalid.
+    let mut tampered: DocumentHistory = restored;
+    tampered.integration_chain_data.clear();
+    tampered.diff_chain_data.clear();
+    assert!(tampered.verify().is_err());
+  }
+
+  #[tokio::test]
+  async fn test_document_history_report() {
+    let tangle: Arc<MemoryTangle> = Arc::new(MemoryTangle::new());
+    let client: Client = Client::builder().backend(Arc::clone(&tangle)).build().await.unwrap();
+
+    let (document, keypair): (IotaDocument, KeyPair) = generate_document();
+    let (other, other_keypair): (IotaDocument, KeyPair) = generate_document();
+    let root_message_id: MessageId = *client.publish_document(&document).await.unwrap().message_id();
+    let index: &str = document.id().tag();
+
+    let update = |fragment: &str, previous_message_id: MessageId, keypair: &KeyPair| -> IotaDocument {
+      let mut updated: IotaDocument = add_service(&document, fragment);
+      updated.metadata.previous_message_id = previous_message_id;
+      sign(&mut updated, keypair);
+      updated
+    };
+
+    // An accepted update which was last updated before the root document.
+    let mut accepted: IotaDocument = add_service(&document, "accepted");
+    accepted.metadata.previous_message_id = root_message_id;
+    accepted.metadata.updated = Some(Timestamp::from_unix(0).unwrap());
+    sign(&mut accepted, &keypair);
+    let accepted_message_id: MessageId = *client.publish_document(&accepted).await.unwrap().message_id();
+
+    let superseded: MessageId = *client
+      .publish_document(&update("superseded", root_message_id, &keypair))
+      .await
+      .unwrap()
+      .message_id();
+    let unreferenced: MessageId = tangle
+      .insert(
+        index,
+        &pack_did_message(
+          &update("unreferenced", root_message_id, &keypair),
+          DIDMessageEncoding::Json,
+        )
+        .unwrap(),
+        None,
+      )
+      .unwrap()
+      .id()
+      .0;
+    let invalid_signature: MessageId = *client
+      .publish_document(&update("invalid-signature", accepted_message_id, &other_keypair))
+      .await
+      .unwrap()
+      .message_id();
+    let dangling: MessageId = *client
+      .publish_document(&update("dangling", MessageId::new([1; 32]), &keypair))
+      .await
+      .unwrap()
+      .message_id();
+    let unparsable: MessageId = *client
+      .publish_json(index, &Value::from("spam"))
+      .await
+      .unwrap()
+      .message_id();
+    let wrong_did: MessageId = *client.publish_json(index, &other).await.unwrap().message_id();
+
+    // Diffs of the accepted integration document.
+    let diff = |keypair: &KeyPair| -> DiffMessage {
+      accepted
+        .diff(
+          &add_service(&accepted, "diff"),
+          accepted_message_id,
+          keypair.private(),
+          accepted.default_signing_method().unwrap().id(),
+        )
+        .unwrap()
+    };
+    let accepted_diff: MessageId = *client
+      .publish_diff(&accepted_message_id, &diff(&keypair))
+      .await
+      .unwrap()
+      .message_id();
+    let invalid_diff: MessageId = *client
+      .publish_diff(&accepted_message_id, &diff(&other_keypair))
+      .await
+      .unwrap()
+      .message_id();
+
+    let history: DocumentHistory = client.resolve_history(document.id()).await.unwrap();
+    let report: HistoryReport = history.report(&client).await.unwrap();
+
+    let issues = |reports: &[MessageReport], message_id: &MessageId| -> (bool, Vec<MessageIssue>) {
+      let report: &MessageReport = reports.iter().find(|report| &report.message_id == message_id).unwrap();
+      (report.accepted, report.issues.clone())
+    };
+    let integration: &[MessageReport] = &report.integration_chain;
+    assert_eq!(integration.len(), 8);
+    assert_eq!(issues(integration, &root_message_id), (true, vec![]));
+    assert_eq!(issues(integration, &accepted_message_id), (true, vec![]));
+    assert_eq!(
+      issues(integration, &superseded),
+      (false, vec![MessageIssue::Superseded(accepted_message_id)])
+    );
+    assert_eq!(
+      issues(integration, &unreferenced),
+      (false, vec![MessageIssue::NotReferencedByMilestone])
+    );
+    assert_eq!(
+      issues(integration, &invalid_signature),
+      (false, vec![MessageIssue::InvalidSignature])
+    );
+    assert_eq!(
+      issues(integration, &dangling),
+      (false, vec![MessageIssue::InvalidPreviousMessageId])
+    );
+    assert_eq!(
+      issues(integration, &unparsable),
+      (false, vec![MessageIssue::UnparsablePayload])
+    );
+    assert_eq!(issues(integration, &wrong_did), (false, vec![MessageIssue::WrongDid]));
+
+    let diffs: &[MessageReport] = &report.diff_chain;
+    assert_eq!(diffs.len(), 2);
+    assert_eq!(issues(diffs, &accepted_diff), (true, vec![]));
+    assert_eq!(
+      issues(diffs, &invalid_diff),
+      (false, vec![MessageIssue::InvalidSignature])
+    );
+    assert_eq!(report.rejected().count(), 7);
+  }
+}
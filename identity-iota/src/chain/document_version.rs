@@ -0,0 +1,248 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use identity_core::common::Timestamp;
+use identity_iota_core::diff::DiffMessage;
+use identity_iota_core::document::IotaDocument;
+use identity_iota_core::tangle::MessageId;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::document::ResolvedIotaDocument;
+use crate::error::Result;
+use crate::tangle::TangleRef;
+
+/// Identifies a historical version of a DID Document on the Tangle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum DocumentVersion {
+  /// The version published by the integration or diff message with the given [`MessageId`].
+  #[serde(rename = "versionId")]
+  MessageId(MessageId),
+  /// The latest version that was updated at or before the given time.
+  ///
+  /// NOTE: versions are compared by the `updated` timestamp of the DID Document, which is signed
+  /// by the controller of the DID Document and need not reflect when an update was published.
+  /// Timestamps are not enforced to increase along the chain, so the position in the chain is
+  /// authoritative: the selected version is the latest one preceding the first update with a
+  /// later timestamp, and backdated updates after it are never selected.
+  #[serde(rename = "versionTime")]
+  Time(Timestamp),
+}
+
+impl DocumentVersion {
+  /// Returns the integration chain documents whose diff chains may contain this version, ordered
+  /// from the most recent.
+  ///
+  /// The `documents` must form a valid integration chain, in order.
+  pub(crate) fn candidates<'a>(
+    &self,
+    documents: &'a [ResolvedIotaDocument],
+  ) -> impl Iterator<Item = &'a ResolvedIotaDocument> + 'a {
+    let documents: &[ResolvedIotaDocument] = match self {
+      Self::MessageId(_) => documents,
+      // Only the last document preceding the first one updated after the time can contain it.
+      Self::Time(time) => {
+        let count: usize = documents
+          .iter()
+          .take_while(|document| Self::version_time(&document.document) <= Some(*time))
+          .count();
+        &documents[count.saturating_sub(1)..count]
+      }
+    };
+    documents.iter().rev()
+  }
+
+  /// Returns the state of the DID Document at this version, if this version is part of the given
+  /// integration chain `document` or its `diffs`.
+  ///
+  /// The `diffs` must form the valid diff chain of `document`, in order.
+  ///
+  /// # Errors
+  ///
+  /// Fails if merging a [`DiffMessage`] fails.
+  pub fn select(&self, document: &ResolvedIotaDocument, diffs: &[DiffMessage]) -> Result<Option<ResolvedIotaDocument>> {
+    match self {
+      Self::MessageId(message_id) => {
+        if document.message_id() == message_id {
+          return Ok(Some(document.clone()));
+        }
+
+        match diffs.iter().position(|diff| diff.message_id() == message_id) {
+          Some(index) => Self::merge(document, &diffs[..=index]).map(Some),
+          None => Ok(None),
+        }
+      }
+      Self::Time(time) => {
+        if Self::version_time(&document.document) > Some(*time) {
+          return Ok(None);
+        }
+
+        let mut resolved: ResolvedIotaDocument = document.clone();
+
+        for diff in diffs {
+          let mut merged: ResolvedIotaDocument = resolved.clone();
+          merged.merge_diff_message(diff)?;
+
+          if Self::version_time(&merged.document) > Some(*time) {
+            break;
+          }
+
+          resolved = merged;
+        }
+
+        Ok(Some(resolved))
+      }
+    }
+  }
+
+  /// Returns the time of the last update of the `document`, if any.
+  ///
+  /// Documents without timestamps are ordered before all others.
+  pub(crate) fn version_time(document: &IotaDocument) -> Option<Timestamp> {
+    document.metadata.updated.or(document.metadata.created)
+  }
+
+  fn merge(document: &ResolvedIotaDocument, diffs: &[DiffMessage]) -> Result<ResolvedIotaDocument> {
+    let mut resolved: ResolvedIotaDocument = document.clone();
+
+    for diff in diffs {
+      resolved.merge_diff_message(diff)?;
+    }
+
+    Ok(resolved)
+  }
+}
+
+impl Display for DocumentVersion {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    match self {
+      Self::MessageId(message_id) => write!(f, "versionId={}", message_id),
+      Self::Time(time) => write!(f, "versionTime={}", time),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::crypto::KeyPair;
+  use identity_core::crypto::KeyType;
+
+  use super::*;
+
+  fn timestamp(value: &str) -> Timestamp {
+    Timestamp::parse(value).unwrap()
+  }
+
+  /// Creates an integration document updated on 2022-01-01 and two diffs updating it on the
+  /// following days.
+  fn create_chain() -> (ResolvedIotaDocument, Vec<DiffMessage>) {
+    let keypair: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
+    let mut document: IotaDocument = IotaDocument::new(&keypair).unwrap();
+    document.metadata.created = Some(timestamp("2022-01-01T00:00:00Z"));
+    document.metadata.updated = Some(timestamp("2022-01-01T00:00:00Z"));
+    document
+      .sign_self(
+        keypair.private(),
+        document.default_signing_method().unwrap().id().clone(),
+      )
+      .unwrap();
+    let mut original: ResolvedIotaDocument = ResolvedIotaDocument::from(document);
+    original.set_message_id(MessageId::new([1; 32]));
+
+    let mut diffs: Vec<DiffMessage> = Vec::new();
+    let mut previous: IotaDocument = original.document.clone();
+    let mut previous_message_id: MessageId = original.integration_message_id;
+
+    for (index, updated) in [(2, "2022-01-02T00:00:00Z"), (3, "2022-01-03T00:00:00Z")] {
+      let mut next: IotaDocument = previous.clone();
+      next.metadata.updated = Some(timestamp(updated));
+
+      let mut diff: DiffMessage = previous
+        .diff(
+          &next,
+          previous_message_id,
+          keypair.private(),
+          previous.default_signing_method().unwrap().id(),
+        )
+        .unwrap();
+      diff.set_message_id(MessageId::new([index; 32]));
+
+      previous_message_id = *diff.message_id();
+      previous = next;
+      diffs.push(diff);
+    }
+
+    (original, diffs)
+  }
+
+  #[test]
+  fn test_select_message_id() {
+    let (original, diffs) = create_chain();
+
+    let version: DocumentVersion = DocumentVersion::MessageId(MessageId::new([1; 32]));
+    assert_eq!(version.select(&original, &diffs).unwrap(), Some(original.clone()));
+
+    let version: DocumentVersion = DocumentVersion::MessageId(MessageId::new([2; 32]));
+    let resolved: ResolvedIotaDocument = version.select(&original, &diffs).unwrap().unwrap();
+    assert_eq!(resolved.diff_message_id, MessageId::new([2; 32]));
+    assert_eq!(
+      resolved.document.metadata.updated,
+      Some(timestamp("2022-01-02T00:00:00Z"))
+    );
+
+    let version: DocumentVersion = DocumentVersion::MessageId(MessageId::new([4; 32]));
+    assert_eq!(version.select(&original, &diffs).unwrap(), None);
+  }
+
+  #[test]
+  fn test_select_time() {
+    let (original, diffs) = create_chain();
+
+    // Before the integration document was published.
+    let version: DocumentVersion = DocumentVersion::Time(timestamp("2021-12-31T00:00:00Z"));
+    assert_eq!(version.select(&original, &diffs).unwrap(), None);
+
+    let version: DocumentVersion = DocumentVersion::Time(timestamp("2022-01-01T12:00:00Z"));
+    assert_eq!(version.select(&original, &diffs).unwrap(), Some(original.clone()));
+
+    let version: DocumentVersion = DocumentVersion::Time(timestamp("2022-01-02T00:00:00Z"));
+    let resolved: ResolvedIotaDocument = version.select(&original, &diffs).unwrap().unwrap();
+    assert_eq!(resolved.diff_message_id, MessageId::new([2; 32]));
+
+    let version: DocumentVersion = DocumentVersion::Time(timestamp("2023-01-01T00:00:00Z"));
+    let resolved: ResolvedIotaDocument = version.select(&original, &diffs).unwrap().unwrap();
+    assert_eq!(resolved.diff_message_id, MessageId::new([3; 32]));
+  }
+
+  #[test]
+  fn test_select_time_chain_order() {
+    let (original, diffs) = create_chain();
+
+    // A diff backdated before an earlier diff is only selected after the earlier diff.
+    let mut backdated: Vec<DiffMessage> = diffs.clone();
+    backdated.swap(0, 1);
+    let version: DocumentVersion = DocumentVersion::Time(timestamp("2022-01-02T12:00:00Z"));
+    assert_eq!(version.select(&original, &backdated).unwrap(), Some(original.clone()));
+
+    // A backdated integration document is not selected before the documents preceding it.
+    let mut documents: Vec<ResolvedIotaDocument> = vec![original.clone(), original.clone(), original.clone()];
+    documents[1].document.metadata.updated = Some(timestamp("2022-02-01T00:00:00Z"));
+    documents[1].set_message_id(MessageId::new([5; 32]));
+    documents[2].document.metadata.updated = Some(timestamp("2021-12-31T00:00:00Z"));
+    documents[2].set_message_id(MessageId::new([6; 32]));
+
+    let version: DocumentVersion = DocumentVersion::Time(timestamp("2022-01-15T00:00:00Z"));
+    let candidates: Vec<&ResolvedIotaDocument> = version.candidates(&documents).collect();
+    assert_eq!(candidates, vec![&documents[0]]);
+
+    let version: DocumentVersion = DocumentVersion::Time(timestamp("2023-01-01T00:00:00Z"));
+    let candidates: Vec<&ResolvedIotaDocument> = version.candidates(&documents).collect();
+    assert_eq!(candidates, vec![&documents[2]]);
+
+    let version: DocumentVersion = DocumentVersion::MessageId(MessageId::new([5; 32]));
+    assert_eq!(version.candidates(&documents).count(), 3);
+  }
+}
@@ -0,0 +1,255 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use futures::stream::StreamExt;
+use futures::stream::TryStreamExt;
+use futures::TryFutureExt;
+use identity_iota_core::did::IotaDID;
+use identity_iota_core::diff::DiffMessage;
+use identity_iota_core::tangle::Message;
+use identity_iota_core::tangle::MessageId;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::chain::document_chain::MAX_CONCURRENT_DOWNLOADS;
+use crate::chain::DiffChain;
+use crate::chain::IntegrationChain;
+use crate::chain::Rejection;
+use crate::document::ResolvedIotaDocument;
+use crate::error::Result;
+use crate::tangle::try_parse_message;
+use crate::tangle::TangleBackend;
+use crate::tangle::TangleRef;
+
+/// A diagnostic report explaining how the messages on the indexes of a
+/// [`DocumentHistory`](crate::chain::DocumentHistory) were resolved.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryReport {
+  /// Reports for the messages on the integration chain index.
+  #[serde(rename = "integrationChain")]
+  pub integration_chain: Vec<MessageReport>,
+  /// Reports for the messages on the diff chain index of the last integration chain document.
+  #[serde(rename = "diffChain")]
+  pub diff_chain: Vec<MessageReport>,
+}
+
+impl HistoryReport {
+  /// Returns an iterator over the reports of the messages which are not part of either chain.
+  pub fn rejected(&self) -> impl Iterator<Item = &MessageReport> + '_ {
+    self
+      .integration_chain
+      .iter()
+      .chain(self.diff_chain.iter())
+      .filter(|report| !report.accepted)
+  }
+}
+
+/// A diagnostic report for a single message published on an integration chain or diff chain index.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageReport {
+  #[serde(rename = "messageId")]
+  pub message_id: MessageId,
+  /// Whether the message is part of the resolved chain.
+  pub accepted: bool,
+  /// The issues found with the message, which list the reasons a rejected message was rejected.
+  /// Accepted messages have no issues.
+  pub issues: Vec<MessageIssue>,
+}
+
+impl MessageReport {
+  fn new(message_id: MessageId, accepted: bool, issues: Vec<MessageIssue>) -> Self {
+    Self {
+      message_id,
+      accepted,
+      issues,
+    }
+  }
+}
+
+/// An issue found with a message published on an integration chain or diff chain index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MessageIssue {
+  /// The payload is not a DID message or could not be decoded.
+  UnparsablePayload,
+  /// The message contains a DID Document or diff of a different DID.
+  WrongDid,
+  /// The message contains a root document which is not signed by its own verification method.
+  InvalidRootDocument,
+  /// The previous message id does not reference a message of the chain.
+  InvalidPreviousMessageId,
+  /// The signature is not valid for the integration document preceding the message.
+  InvalidSignature,
+  /// The changes of the diff cannot be merged, e.g. because it alters the signing methods.
+  InvalidDiff,
+  /// The message is valid but not referenced by a milestone.
+  NotReferencedByMilestone,
+  /// The message is valid but conflicts with the message continuing the chain, which was referenced
+  /// by an earlier milestone.
+  Superseded(MessageId),
+}
+
+/// Reports on the documents of an integration chain, ordered from the root document, and on the
+/// `spam` messages published on the same index.
+pub(crate) async fn report_integration_chain<C: TangleBackend + ?Sized>(
+  chain_data: &[ResolvedIotaDocument],
+  spam: &[MessageId],
+  client: &C,
+) -> Result<Vec<MessageReport>> {
+  let mut reports: Vec<MessageReport> = Vec::with_capacity(chain_data.len() + spam.len());
+  reports.extend(
+    chain_data
+      .iter()
+      .map(|document| MessageReport::new(*document.message_id(), true, Vec::new())),
+  );
+
+  let spam_reports: Vec<MessageReport> = futures::stream::iter(spam)
+    .map(|message_id| {
+      diagnose_document(message_id, chain_data, client)
+        .map_ok(move |issues| MessageReport::new(*message_id, false, issues))
+    })
+    .buffered(MAX_CONCURRENT_DOWNLOADS)
+    .try_collect()
+    .await?;
+  reports.extend(spam_reports);
+
+  Ok(reports)
+}
+
+/// Reports on the diffs of a diff chain for the given `integration_document`, and on the `spam`
+/// messages published on the same index.
+pub(crate) async fn report_diff_chain<C: TangleBackend + ?Sized>(
+  integration_document: &ResolvedIotaDocument,
+  chain_data: &[DiffMessage],
+  spam: &[MessageId],
+  client: &C,
+) -> Result<Vec<MessageReport>> {
+  let mut reports: Vec<MessageReport> = chain_data
+    .iter()
+    .map(|diff| MessageReport::new(*diff.message_id(), true, Vec::new()))
+    .collect();
+
+  let spam_reports: Vec<MessageReport> = futures::stream::iter(spam)
+    .map(|message_id| {
+      diagnose_diff(message_id, integration_document, chain_data, client)
+        .map_ok(move |issues| MessageReport::new(*message_id, false, issues))
+    })
+    .buffered(MAX_CONCURRENT_DOWNLOADS)
+    .try_collect()
+    .await?;
+  reports.extend(spam_reports);
+
+  Ok(reports)
+}
+
+async fn diagnose_document<C: TangleBackend + ?Sized>(
+  message_id: &MessageId,
+  chain_data: &[ResolvedIotaDocument],
+  client: &C,
+) -> Result<Vec<MessageIssue>> {
+  let did: &IotaDID = match chain_data.first() {
+    Some(root_document) => root_document.document.id(),
+    None => return Ok(vec![MessageIssue::UnparsablePayload]),
+  };
+  let message: Message = client.read_message(message_id).await?;
+  let document: ResolvedIotaDocument = match try_parse_message(&message) {
+    Some(document) => document,
+    None => return Ok(vec![MessageIssue::UnparsablePayload]),
+  };
+  if document.did().authority() != did.authority() {
+    return Ok(vec![MessageIssue::WrongDid]);
+  }
+
+  let successor: Option<&ResolvedIotaDocument> = if document.previous_message_id().is_null() {
+    if IntegrationChain::new(document.clone()).is_err() {
+      return Ok(vec![MessageIssue::InvalidRootDocument]);
+    }
+    chain_data.first()
+  } else {
+    let position: usize = match chain_data
+      .iter()
+      .position(|previous| previous.message_id() == document.previous_message_id())
+    {
+      Some(position) => position,
+      None => return Ok(vec![MessageIssue::InvalidPreviousMessageId]),
+    };
+    if let Err(rejection) = IntegrationChain::check_addition(&chain_data[position], &document) {
+      return Ok(vec![rejection_issue(rejection)]);
+    }
+    chain_data.get(position + 1)
+  };
+
+  diagnose_inclusion(document.message_id(), successor.map(TangleRef::message_id), client).await
+}
+
+async fn diagnose_diff<C: TangleBackend + ?Sized>(
+  message_id: &MessageId,
+  integration_document: &ResolvedIotaDocument,
+  chain_data: &[DiffMessage],
+  client: &C,
+) -> Result<Vec<MessageIssue>> {
+  let message: Message = client.read_message(message_id).await?;
+  let diff: DiffMessage = match try_parse_message(&message) {
+    Some(diff) => diff,
+    None => return Ok(vec![MessageIssue::UnparsablePayload]),
+  };
+  if diff.did().authority() != integration_document.did().authority() {
+    return Ok(vec![MessageIssue::WrongDid]);
+  }
+
+  // The number of diffs of the chain preceding the diff.
+  let preceding: usize = if diff.previous_message_id() == integration_document.message_id() {
+    0
+  } else {
+    match chain_data
+      .iter()
+      .position(|previous| previous.message_id() == diff.previous_message_id())
+    {
+      Some(position) => position + 1,
+      None => return Ok(vec![MessageIssue::InvalidPreviousMessageId]),
+    }
+  };
+
+  // Diffs are signed by the verification methods of the integration document.
+  if let Err(rejection) = DiffChain::check_diff(&diff, integration_document, diff.previous_message_id()) {
+    return Ok(vec![rejection_issue(rejection)]);
+  }
+
+  let mut document: ResolvedIotaDocument = integration_document.clone();
+  for previous in &chain_data[..preceding] {
+    document = DiffChain::try_merge(previous, &document)?;
+  }
+  if DiffChain::try_merge(&diff, &document).is_err() {
+    return Ok(vec![MessageIssue::InvalidDiff]);
+  }
+
+  let successor: Option<&MessageId> = chain_data.get(preceding).map(TangleRef::message_id);
+  diagnose_inclusion(diff.message_id(), successor, client).await
+}
+
+/// Maps the reason a message was rejected by the chain validation to a [`MessageIssue`].
+fn rejection_issue(rejection: Rejection) -> MessageIssue {
+  match rejection {
+    Rejection::InvalidDid => MessageIssue::WrongDid,
+    // Documents and diffs parsed from a message always have a message id.
+    Rejection::MissingMessageId | Rejection::MissingPreviousMessageId | Rejection::InvalidPreviousMessageId => {
+      MessageIssue::InvalidPreviousMessageId
+    }
+    Rejection::InvalidSignature => MessageIssue::InvalidSignature,
+  }
+}
+
+/// Explains why a valid message continuing the chain was not included.
+async fn diagnose_inclusion<C: TangleBackend + ?Sized>(
+  message_id: &MessageId,
+  successor: Option<&MessageId>,
+  client: &C,
+) -> Result<Vec<MessageIssue>> {
+  if client.milestone_index(message_id).await?.is_none() {
+    Ok(vec![MessageIssue::NotReferencedByMilestone])
+  } else if let Some(successor) = successor {
+    Ok(vec![MessageIssue::Superseded(*successor)])
+  } else {
+    Ok(Vec::new())
+  }
+}
@@ -16,12 +16,13 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::chain::milestone::sort_by_milestone;
+use crate::chain::Rejection;
 use crate::document::ResolvedIotaDocument;
 use crate::error::Error;
 use crate::error::Result;
-use crate::tangle::Client;
 use crate::tangle::MessageExt;
 use crate::tangle::MessageIndex;
+use crate::tangle::TangleBackend;
 use crate::tangle::TangleRef;
 
 /// Primary chain of full [`ResolvedIotaDocuments`](ResolvedIotaDocument) holding the latest version
@@ -37,7 +38,11 @@ pub struct IntegrationChain {
 
 impl IntegrationChain {
   /// Constructs a new [`IntegrationChain`] from a slice of [`Message`]s.
-  pub async fn try_from_messages(did: &IotaDID, messages: &[Message], client: &Client) -> Result<Self> {
+  pub async fn try_from_messages<C: TangleBackend + ?Sized>(
+    did: &IotaDID,
+    messages: &[Message],
+    client: &C,
+  ) -> Result<Self> {
     let index: MessageIndex<ResolvedIotaDocument> = messages
       .iter()
       .flat_map(|message| message.try_extract_document(did))
@@ -49,7 +54,10 @@ impl IntegrationChain {
   }
 
   /// Constructs a new [`IntegrationChain`] from the given [`MessageIndex`].
-  pub async fn try_from_index(mut index: MessageIndex<ResolvedIotaDocument>, client: &Client) -> Result<Self> {
+  pub async fn try_from_index<C: TangleBackend + ?Sized>(
+    mut index: MessageIndex<ResolvedIotaDocument>,
+    client: &C,
+  ) -> Result<Self> {
     log::trace!("[Int] Message Index = {:#?}", index);
 
     // Extract root document.
@@ -74,20 +82,31 @@ impl IntegrationChain {
 
     // Construct the rest of the integration chain.
     let mut this: Self = Self::new(root_document)?;
-    while let Some(documents) = index.remove(this.current_message_id()) {
+    this.extend_from_index(index, client).await?;
+    Ok(this)
+  }
+
+  /// Appends the valid documents from the given [`MessageIndex`] that continue the chain from its
+  /// current document.
+  pub(crate) async fn extend_from_index<C: TangleBackend + ?Sized>(
+    &mut self,
+    mut index: MessageIndex<ResolvedIotaDocument>,
+    client: &C,
+  ) -> Result<()> {
+    while let Some(documents) = index.remove(self.current_message_id()) {
       // Extract valid documents.
       let valid_documents: Vec<ResolvedIotaDocument> = documents
         .into_iter()
-        .filter(|document| this.check_valid_addition(document).is_ok())
+        .filter(|document| self.check_valid_addition(document).is_ok())
         .collect();
 
       // Sort and push the one referenced by the oldest milestone.
       if let Some(next) = sort_by_milestone(valid_documents, client).await?.into_iter().next() {
-        this.push_unchecked(next); // checked above
+        self.push_unchecked(next); // checked above
       }
       // If no document is appended, the chain ends.
     }
-    Ok(this)
+    Ok(())
   }
 
   /// Creates a new [`IntegrationChain`] with `current` as the root [`ResolvedIotaDocument`] and no history.
@@ -107,6 +126,14 @@ impl IntegrationChain {
     Ok(Self { current, history: None })
   }
 
+  /// Creates a new [`IntegrationChain`] with `current` as the latest [`ResolvedIotaDocument`] and no
+  /// history, without checking that it is a valid root document.
+  ///
+  /// Used to continue a chain from a previously validated document.
+  pub(crate) fn new_unchecked(current: ResolvedIotaDocument) -> Self {
+    Self { current, history: None }
+  }
+
   /// Returns a reference to the latest [`ResolvedIotaDocument`].
   pub fn current(&self) -> &ResolvedIotaDocument {
     &self.current
@@ -141,6 +168,43 @@ impl IntegrationChain {
     Ok(())
   }
 
+  /// Verifies that the [`IntegrationChain`] starts with a valid root document and every following
+  /// document is a valid addition to the chain, e.g. after deserializing it.
+  ///
+  /// See [`DocumentChain::verify`](crate::chain::DocumentChain::verify) for conflicts which cannot
+  /// be detected without access to the Tangle.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the root document or any following document is invalid.
+  pub fn verify(&self) -> Result<()> {
+    Self::try_from_documents(
+      self
+        .history
+        .iter()
+        .flatten()
+        .chain(core::iter::once(&self.current))
+        .cloned(),
+    )
+    .map(|_| ())
+  }
+
+  /// Constructs a new [`IntegrationChain`] from an ordered list of documents, validating the root
+  /// document and each following document.
+  pub(crate) fn try_from_documents(documents: impl IntoIterator<Item = ResolvedIotaDocument>) -> Result<Self> {
+    let mut documents = documents.into_iter();
+    let root_document: ResolvedIotaDocument = documents.next().ok_or(Error::ChainError {
+      error: "Missing Root Document",
+    })?;
+
+    let mut this: Self = Self::new(root_document)?;
+    for document in documents {
+      this.try_push(document)?;
+    }
+
+    Ok(this)
+  }
+
   /// Adds a new [`ResolvedIotaDocument`] to this [`IntegrationChain`] without validating it.
   fn push_unchecked(&mut self, document: ResolvedIotaDocument) {
     self
@@ -166,33 +230,42 @@ impl IntegrationChain {
   /// Fails if the document signature is invalid or the Tangle message
   /// references within the [`ResolvedIotaDocument`] are invalid.
   pub fn check_valid_addition(&self, document: &ResolvedIotaDocument) -> Result<()> {
-    if document.document.id() != self.current.document.id() {
-      return Err(Error::ChainError { error: "Invalid DID" });
+    Self::check_addition(&self.current, document).map_err(|rejection| Error::ChainError {
+      error: match rejection {
+        Rejection::InvalidDid => "Invalid DID",
+        Rejection::MissingMessageId => "Missing Message Id",
+        Rejection::MissingPreviousMessageId => "Missing Previous Message Id",
+        Rejection::InvalidPreviousMessageId => "Invalid Previous Message Id",
+        Rejection::InvalidSignature => "Invalid Signature",
+      },
+    })
+  }
+
+  /// Checks if the `document` can follow the `previous` document of an integration chain,
+  /// returning the [`Rejection`] reason if not.
+  pub(crate) fn check_addition(
+    previous: &ResolvedIotaDocument,
+    document: &ResolvedIotaDocument,
+  ) -> core::result::Result<(), Rejection> {
+    if document.document.id() != previous.document.id() {
+      return Err(Rejection::InvalidDid);
     }
 
     if document.message_id().is_null() {
-      return Err(Error::ChainError {
-        error: "Missing Message Id",
-      });
+      return Err(Rejection::MissingMessageId);
     }
 
     if document.previous_message_id().is_null() {
-      return Err(Error::ChainError {
-        error: "Missing Previous Message Id",
-      });
+      return Err(Rejection::MissingPreviousMessageId);
     }
 
-    if self.current_message_id() != document.previous_message_id() {
-      return Err(Error::ChainError {
-        error: "Invalid Previous Message Id",
-      });
+    if previous.message_id() != document.previous_message_id() {
+      return Err(Rejection::InvalidPreviousMessageId);
     }
 
-    // Verify the next document was signed by a valid method from the previous "current" document.
-    if self.current.document.verify_document(&document.document).is_err() {
-      return Err(Error::ChainError {
-        error: "Invalid Signature",
-      });
+    // Verify the next document was signed by a valid method from the previous document.
+    if previous.document.verify_document(&document.document).is_err() {
+      return Err(Rejection::InvalidSignature);
     }
 
     Ok(())
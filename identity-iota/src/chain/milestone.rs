@@ -8,7 +8,7 @@ use futures::TryStreamExt;
 use itertools::Itertools;
 
 use crate::error::Result;
-use crate::tangle::Client;
+use crate::tangle::TangleBackend;
 use crate::tangle::TangleRef;
 
 /// Fetches the milestones of messages and sorts them in ascending order of the milestone
@@ -21,7 +21,11 @@ use crate::tangle::TangleRef;
 /// # Errors
 ///
 /// [`ClientError`](crate::error::Error::ClientError) if fetching a milestone fails.
-pub(crate) async fn sort_by_milestone<T: TangleRef>(messages: Vec<T>, client: &Client) -> Result<Vec<T>> {
+pub(crate) async fn sort_by_milestone<T, C>(messages: Vec<T>, client: &C) -> Result<Vec<T>>
+where
+  T: TangleRef,
+  C: TangleBackend + ?Sized,
+{
   if messages.len() == 1 || messages.is_empty() {
     return Ok(messages);
   }
@@ -31,11 +35,9 @@ pub(crate) async fn sort_by_milestone<T: TangleRef>(messages: Vec<T>, client: &C
     .into_iter()
     .map(|message| async {
       client
-        .client
-        .get_message()
-        .metadata(message.message_id())
+        .milestone_index(message.message_id())
         .await
-        .map(|metadata| (metadata.referenced_by_milestone_index, message))
+        .map(|milestone_index| (milestone_index, message))
     })
     .collect::<FuturesUnordered<_>>()
     .try_collect()
@@ -5,10 +5,18 @@ pub use self::diff_chain::DiffChain;
 pub use self::document_chain::DocumentChain;
 pub use self::document_history::ChainHistory;
 pub use self::document_history::DocumentHistory;
+pub use self::document_version::DocumentVersion;
+pub use self::history_report::HistoryReport;
+pub use self::history_report::MessageIssue;
+pub use self::history_report::MessageReport;
 pub use self::integration_chain::IntegrationChain;
+pub(crate) use self::rejection::Rejection;
 
 mod diff_chain;
 mod document_chain;
 mod document_history;
+mod document_version;
+mod history_report;
 mod integration_chain;
 mod milestone;
+mod rejection;
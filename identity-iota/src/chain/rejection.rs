@@ -0,0 +1,17 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// The reason a document or diff is not a valid addition to a chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Rejection {
+  /// The document or diff belongs to a different DID.
+  InvalidDid,
+  /// The document or diff has no message id.
+  MissingMessageId,
+  /// The document or diff has no previous message id.
+  MissingPreviousMessageId,
+  /// The previous message id does not reference the message the document or diff should follow.
+  InvalidPreviousMessageId,
+  /// The signature is not valid for the preceding integration document.
+  InvalidSignature,
+}
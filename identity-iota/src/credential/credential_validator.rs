@@ -4,17 +4,28 @@
 use identity_core::common::OneOrMany;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
+use identity_core::convert::FromJson;
+use identity_core::utils::decode_b64;
 use identity_credential::credential::Credential;
+use identity_credential::credential::CredentialJwtClaims;
+use identity_credential::credential::RevocationBitmapStatus;
+use identity_credential::credential::StatusList2021;
+use identity_credential::credential::StatusList2021Entry;
+use identity_credential::credential::StatusPurpose;
+use identity_did::did::CoreDID;
+use identity_did::did::DID;
 use identity_did::verifiable::VerifierOptions;
-use identity_iota_core::did::IotaDID;
-use identity_iota_core::document::IotaDocument;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use super::errors::SignerContext;
 use super::errors::ValidationError;
 use super::CredentialValidationOptions;
 use super::FailFast;
+use super::StatusCheck;
+use super::StatusListFetcher;
 use super::SubjectHolderRelationship;
+use super::ValidatorDocument;
 use crate::credential::errors::CompoundCredentialValidationError;
 use crate::Result;
 
@@ -48,11 +59,12 @@ impl CredentialValidator {
   /// ## Properties that are not validated
   ///  There are many properties defined in [The Verifiable Credentials Data Model](https://www.w3.org/TR/vc-data-model/) that are **not** validated, such as:
   /// `credentialStatus`, `type`, `credentialSchema`, `refreshService`, **and more**.
-  /// These should be manually checked after validation, according to your requirements.
+  /// These should be manually checked after validation, according to your requirements. See
+  /// [`CredentialValidator::check_status`] for checking the status of the credential.
   ///
   /// # Errors
   /// An error is returned whenever a validated condition is not satisfied.
-  pub fn validate<T: Serialize, D: AsRef<IotaDocument>>(
+  pub fn validate<T: Serialize, D: ValidatorDocument>(
     credential: &Credential<T>,
     issuer: &D,
     options: &CredentialValidationOptions,
@@ -61,6 +73,25 @@ impl CredentialValidator {
     Self::validate_extended(credential, std::slice::from_ref(issuer), options, None, fail_fast)
   }
 
+  /// Decodes and validates a [`Credential`] encoded as a JSON Web Token (VC-JWT).
+  ///
+  /// The issuer's signature is verified with the verification method referenced by the `kid` header of the token.
+  /// The decoded credential is then validated according to `options`, as in [`CredentialValidator::validate`].
+  ///
+  /// # Warning
+  /// The warnings of [`CredentialValidator::validate`] apply equally to this method.
+  ///
+  /// # Errors
+  /// An error is returned whenever the token cannot be decoded or a validated condition is not satisfied.
+  pub fn validate_jwt<T: DeserializeOwned, D: ValidatorDocument>(
+    jwt: &str,
+    issuer: &D,
+    options: &CredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> std::result::Result<Credential<T>, CompoundCredentialValidationError> {
+    Self::validate_jwt_extended(jwt, std::slice::from_ref(issuer), options, None, fail_fast)
+  }
+
   /// Validates the semantic structure of the [`Credential`].
   ///
   /// # Warning
@@ -97,26 +128,25 @@ impl CredentialValidator {
   /// This method immediately returns an error if
   /// the credential issuer' url cannot be parsed to a DID belonging to one of the trusted issuers. Otherwise an attempt
   /// to verify the credential's signature will be made and an error is returned upon failure.
-  pub fn verify_signature<T: Serialize, D: AsRef<IotaDocument>>(
+  pub fn verify_signature<T: Serialize, D: ValidatorDocument>(
     credential: &Credential<T>,
     trusted_issuers: &[D],
     options: &VerifierOptions,
   ) -> ValidationUnitResult {
     // try to extract the corresponding issuer from `trusted_issuers`
-    let extracted_issuer_result: std::result::Result<&IotaDocument, ValidationError> = {
-      let issuer_did: Result<IotaDID> = credential.issuer.url().as_str().parse().map_err(Into::into);
+    let extracted_issuer_result: std::result::Result<&D, ValidationError> = {
+      let issuer_did: Result<CoreDID> = CoreDID::parse(credential.issuer.url().as_str()).map_err(Into::into);
       match issuer_did {
         Ok(did) => {
           // if the issuer_did corresponds to one of the trusted issuers we use the corresponding DID Document to verify
           // the signature
           trusted_issuers
             .iter()
-            .map(|issuer_doc| issuer_doc.as_ref())
-            .find(|issuer_doc| issuer_doc.id() == &did)
+            .find(|issuer_doc| issuer_doc.did_str() == did.as_str())
             .ok_or(ValidationError::DocumentMismatch(SignerContext::Issuer))
         }
         Err(error) => {
-          // the issuer's url could not be parsed to a valid IotaDID
+          // the issuer's url could not be parsed to a valid DID
           Err(ValidationError::SignerUrl {
             source: error.into(),
             signer_ctx: SignerContext::Issuer,
@@ -135,6 +165,157 @@ impl CredentialValidator {
     })
   }
 
+  /// Decode a [`Credential`] encoded as a JSON Web Token (VC-JWT) after verifying its signature using the DID
+  /// Document of a trusted issuer.
+  ///
+  /// # Warning
+  /// The caller must ensure that the DID Documents of the trusted issuers are up-to-date.
+  ///
+  /// # Errors
+  /// This method immediately returns an error if the token is malformed or its `iss` claim cannot be parsed to a DID
+  /// belonging to one of the trusted issuers. Otherwise an attempt to verify the token's signature will be made and
+  /// an error is returned upon failure, or if the verified claims do not represent a valid credential.
+  pub fn verify_jwt_signature<T: DeserializeOwned, D: ValidatorDocument>(
+    jwt: &str,
+    trusted_issuers: &[D],
+    options: &VerifierOptions,
+  ) -> std::result::Result<Credential<T>, ValidationError> {
+    // The unverified `iss` claim is only used to select the DID Document the signature is verified with.
+    let claims: CredentialJwtClaims = jwt
+      .split('.')
+      .nth(1)
+      .and_then(|payload| decode_b64(payload).ok())
+      .and_then(|payload| CredentialJwtClaims::from_json_slice(&payload).ok())
+      .ok_or(ValidationError::MalformedJwt)?;
+
+    let issuer_did: CoreDID = CoreDID::parse(claims.iss.as_str()).map_err(|error| ValidationError::SignerUrl {
+      source: error.into(),
+      signer_ctx: SignerContext::Issuer,
+    })?;
+
+    let issuer: &D = trusted_issuers
+      .iter()
+      .find(|issuer_doc| issuer_doc.did_str() == issuer_did.as_str())
+      .ok_or(ValidationError::DocumentMismatch(SignerContext::Issuer))?;
+
+    let claims: CredentialJwtClaims = issuer
+      .verify_jwt(jwt, options)
+      .map_err(|error| ValidationError::Signature {
+        source: error.into(),
+        signer_ctx: SignerContext::Issuer,
+      })?;
+
+    claims.try_into_credential().map_err(ValidationError::JwtClaims)
+  }
+
+  /// Validate that the [`Credential`] is not revoked according to the [`RevocationBitmap`] referenced by each of its
+  /// [`RevocationBitmapStatus`] statuses, which is published in the DID Document of its trusted issuer.
+  ///
+  /// Statuses of other types are ignored unless `status_check` is [`StatusCheck::Strict`].
+  ///
+  /// # Warning
+  /// The caller must ensure that the DID Documents of the trusted issuers are up-to-date.
+  ///
+  /// [`RevocationBitmap`]: identity_did::revocation::RevocationBitmap
+  pub fn check_revocation_bitmap_status<T, D: ValidatorDocument>(
+    credential: &Credential<T>,
+    trusted_issuers: &[D],
+    status_check: StatusCheck,
+  ) -> ValidationUnitResult {
+    if status_check == StatusCheck::SkipAll {
+      return Ok(());
+    }
+
+    for status in credential.credential_status.iter() {
+      if !status.types.iter().any(|type_| type_ == RevocationBitmapStatus::TYPE) {
+        if status_check == StatusCheck::Strict {
+          return Err(ValidationError::Status {
+            source: identity_credential::Error::InvalidStatus("unsupported type").into(),
+          });
+        }
+        continue;
+      }
+
+      let status: RevocationBitmapStatus =
+        RevocationBitmapStatus::try_from(status).map_err(|error| ValidationError::Status { source: error.into() })?;
+
+      let issuer_did: CoreDID =
+        CoreDID::parse(credential.issuer.url().as_str()).map_err(|error| ValidationError::SignerUrl {
+          source: error.into(),
+          signer_ctx: SignerContext::Issuer,
+        })?;
+
+      // The bitmap must be published by the issuer of the credential.
+      if status.id().did().as_str() != issuer_did.as_str() {
+        return Err(ValidationError::StatusListIssuer);
+      }
+
+      let issuer: &D = trusted_issuers
+        .iter()
+        .find(|issuer_doc| issuer_doc.did_str() == issuer_did.as_str())
+        .ok_or(ValidationError::DocumentMismatch(SignerContext::Issuer))?;
+
+      let is_revoked: bool = issuer
+        .resolve_revocation_bitmap(status.id())
+        .map(|bitmap| bitmap.is_revoked(status.index()))
+        .map_err(|error| ValidationError::Status { source: error.into() })?;
+
+      if is_revoked {
+        return Err(ValidationError::Revoked);
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Validate that the [`Credential`] is neither revoked nor suspended according to the status lists referenced by its
+  /// [`StatusList2021Entry`] statuses, which are retrieved with `fetcher`.
+  ///
+  /// Each status list credential must be issued by the issuer of the credential and its signature is verified with
+  /// the DID Document of that issuer, taken from `trusted_issuers`. Statuses of other types are ignored.
+  ///
+  /// # Warning
+  /// The caller must ensure that the DID Documents of the trusted issuers are up-to-date.
+  pub async fn check_status<T, D: ValidatorDocument, F: StatusListFetcher + ?Sized>(
+    credential: &Credential<T>,
+    trusted_issuers: &[D],
+    fetcher: &F,
+  ) -> ValidationUnitResult {
+    let entries = credential
+      .credential_status
+      .iter()
+      .filter(|status| status.types.iter().any(|type_| type_ == StatusList2021Entry::TYPE));
+
+    for status in entries {
+      let entry: StatusList2021Entry =
+        StatusList2021Entry::try_from(status).map_err(|error| ValidationError::Status { source: error.into() })?;
+
+      let status_list_credential: Credential = fetcher
+        .fetch(entry.status_list_credential())
+        .await
+        .map_err(|source| ValidationError::Status { source })?;
+
+      if status_list_credential.issuer.url() != credential.issuer.url() {
+        return Err(ValidationError::StatusListIssuer);
+      }
+
+      Self::verify_signature(&status_list_credential, trusted_issuers, &VerifierOptions::default())?;
+
+      let is_set: bool = StatusList2021::from_credential(&status_list_credential, entry.purpose())
+        .and_then(|status_list| status_list.get(entry.index()))
+        .map_err(|error| ValidationError::Status { source: error.into() })?;
+
+      if is_set {
+        return Err(match entry.purpose() {
+          StatusPurpose::Revocation => ValidationError::Revoked,
+          StatusPurpose::Suspension => ValidationError::Suspended,
+        });
+      }
+    }
+
+    Ok(())
+  }
+
   /// Validate that the relationship between the `holder` and the credential subjects is in accordance with
   /// `relationship`.
   pub fn check_subject_holder_relationship<T>(
@@ -173,17 +354,67 @@ impl CredentialValidator {
   // This method takes a slice of issuer's instead of a single issuer in order to better accommodate presentation
   // validation. It also validates the relation ship between a holder and the credential subjects when
   // `relationship_criterion` is Some.
-  pub(crate) fn validate_extended<T: Serialize, D: AsRef<IotaDocument>>(
+  pub(crate) fn validate_extended<T: Serialize, D: ValidatorDocument>(
     credential: &Credential<T>,
     issuers: &[D],
     options: &CredentialValidationOptions,
     relationship_criterion: Option<(&Url, SubjectHolderRelationship)>,
     fail_fast: FailFast,
   ) -> CredentialValidationResult {
-    // Run all single concern validations in turn and fail immediately if `fail_fast` is true.
-    let signature_validation =
-      std::iter::once_with(|| Self::verify_signature(credential, issuers, &options.verifier_options));
+    let issuer_validation =
+      std::iter::once_with(|| Self::verify_signature(credential, issuers, &options.verifier_options)).chain(
+        std::iter::once_with(|| Self::check_revocation_bitmap_status(credential, issuers, options.status)),
+      );
+
+    Self::validate_units(
+      credential,
+      options,
+      relationship_criterion,
+      issuer_validation,
+      fail_fast,
+    )
+  }
 
+  // Decodes a VC-JWT and validates the resulting credential like `validate_extended`. The signature is verified while
+  // decoding, so a credential is only returned if it is valid.
+  pub(crate) fn validate_jwt_extended<T: DeserializeOwned, D: ValidatorDocument>(
+    jwt: &str,
+    issuers: &[D],
+    options: &CredentialValidationOptions,
+    relationship_criterion: Option<(&Url, SubjectHolderRelationship)>,
+    fail_fast: FailFast,
+  ) -> std::result::Result<Credential<T>, CompoundCredentialValidationError> {
+    let credential: Credential<T> =
+      Self::verify_jwt_signature(jwt, issuers, &options.verifier_options).map_err(|error| {
+        CompoundCredentialValidationError {
+          validation_errors: vec![error],
+        }
+      })?;
+
+    let issuer_validation =
+      std::iter::once_with(|| Self::check_revocation_bitmap_status(&credential, issuers, options.status));
+
+    Self::validate_units(
+      &credential,
+      options,
+      relationship_criterion,
+      issuer_validation,
+      fail_fast,
+    )?;
+
+    Ok(credential)
+  }
+
+  // Runs all single concern validations that do not depend on the issuer's DID Document in turn, followed by
+  // `issuer_validation`.
+  fn validate_units<T>(
+    credential: &Credential<T>,
+    options: &CredentialValidationOptions,
+    relationship_criterion: Option<(&Url, SubjectHolderRelationship)>,
+    issuer_validation: impl Iterator<Item = ValidationUnitResult>,
+    fail_fast: FailFast,
+  ) -> CredentialValidationResult {
+    // Run all single concern validations in turn and fail immediately if `fail_fast` is true.
     let expiry_date_validation = std::iter::once_with(|| {
       Self::check_expires_on_or_after(credential, options.earliest_expiry_date.unwrap_or_default())
     });
@@ -204,7 +435,7 @@ impl CredentialValidator {
       .chain(expiry_date_validation)
       .chain(structure_validation)
       .chain(subject_holder_validation)
-      .chain(signature_validation)
+      .chain(issuer_validation)
       .filter_map(|result| result.err());
     let validation_errors: Vec<ValidationError> = match fail_fast {
       FailFast::FirstError => validation_units_error_iter.take(1).collect(),
@@ -228,10 +459,19 @@ mod tests {
   use identity_core::common::Timestamp;
   use identity_core::convert::FromJson;
   use identity_core::crypto::KeyPair;
+  #[cfg(feature = "bbs-experimental")]
+  use identity_core::crypto::KeyType;
   use identity_core::crypto::ProofOptions;
+  use identity_credential::credential::Status;
+  use identity_credential::credential::StatusList2021CredentialBuilder;
   use identity_credential::credential::Subject;
   use identity_did::did::DID;
+  use identity_did::revocation::RevocationBitmap;
+  #[cfg(feature = "bbs-experimental")]
+  use identity_did::verification::MethodScope;
   use identity_iota_core::document::IotaDocument;
+  #[cfg(feature = "bbs-experimental")]
+  use identity_iota_core::document::IotaVerificationMethod;
   use proptest::proptest;
 
   use crate::credential::test_utils;
@@ -566,6 +806,345 @@ mod tests {
     assert!(matches!(error, &ValidationError::Signature { .. }));
   }
 
+  #[cfg(feature = "bbs-experimental")]
+  #[test]
+  fn test_verify_selective_disclosure() {
+    let Setup {
+      mut issuer_doc,
+      unsigned_credential: mut credential,
+      ..
+    } = Setup::new();
+
+    let bbs_key: KeyPair = KeyPair::new(KeyType::Bls12381G2).unwrap();
+    let method: IotaVerificationMethod =
+      IotaVerificationMethod::new(issuer_doc.id().clone(), KeyType::Bls12381G2, bbs_key.public(), "bbs-0").unwrap();
+    issuer_doc
+      .insert_method(method, MethodScope::assertion_method())
+      .unwrap();
+    issuer_doc
+      .sign_data(&mut credential, bbs_key.private(), "#bbs-0", ProofOptions::default())
+      .unwrap();
+
+    let disclosed: Credential = credential.derive_disclosure(&["degree"], "challenge").unwrap();
+    assert!(!disclosed
+      .credential_subject
+      .get(0)
+      .unwrap()
+      .properties
+      .contains_key("GPA"));
+
+    // The derived proof is valid for the verifier which supplied the challenge
+    let options: VerifierOptions = VerifierOptions::new().challenge("challenge".to_owned());
+    assert!(CredentialValidator::verify_signature(&disclosed, std::slice::from_ref(&issuer_doc), &options).is_ok());
+
+    // Replaying the proof to a verifier with another challenge fails
+    let options: VerifierOptions = VerifierOptions::new().challenge("other".to_owned());
+    assert!(matches!(
+      CredentialValidator::verify_signature(&disclosed, std::slice::from_ref(&issuer_doc), &options).unwrap_err(),
+      ValidationError::Signature { .. }
+    ));
+
+    // Derived proofs are rejected if the verifier does not supply a challenge
+    assert!(matches!(
+      CredentialValidator::verify_signature(
+        &disclosed,
+        std::slice::from_ref(&issuer_doc),
+        &VerifierOptions::default()
+      )
+      .unwrap_err(),
+      ValidationError::Signature { .. }
+    ));
+  }
+
+  #[test]
+  fn test_full_validation_jwt() {
+    let Setup {
+      issuer_doc,
+      issuer_key,
+      unsigned_credential: credential,
+      issuance_date,
+      expiration_date,
+    } = Setup::new();
+    let claims = CredentialJwtClaims::new(&credential).unwrap();
+    let jwt: String = issuer_doc
+      .sign_jwt(
+        &claims,
+        issuer_key.private(),
+        issuer_doc.default_signing_method().unwrap().id(),
+      )
+      .unwrap();
+
+    let issued_on_or_before = issuance_date.checked_add(Duration::days(14)).unwrap();
+    let expires_on_or_after = expiration_date.checked_sub(Duration::hours(1)).unwrap();
+    let options = CredentialValidationOptions::default()
+      .latest_issuance_date(issued_on_or_before)
+      .earliest_expiry_date(expires_on_or_after);
+
+    let decoded: Credential =
+      CredentialValidator::validate_jwt(&jwt, &issuer_doc, &options, FailFast::FirstError).unwrap();
+    assert_eq!(decoded, credential);
+
+    // the validation checks are applied to the decoded credential
+    let options = options.earliest_expiry_date(expiration_date.checked_add(Duration::hours(1)).unwrap());
+    let validation_errors =
+      CredentialValidator::validate_jwt::<Object, _>(&jwt, &issuer_doc, &options, FailFast::FirstError)
+        .unwrap_err()
+        .validation_errors;
+    assert!(matches!(
+      validation_errors.as_slice(),
+      [ValidationError::ExpirationDate]
+    ));
+  }
+
+  #[test]
+  fn test_verify_jwt_signature_unrelated_issuer() {
+    let Setup {
+      issuer_doc,
+      issuer_key,
+      unsigned_credential: credential,
+      ..
+    } = Setup::new();
+    let (other_doc, other_key) = test_utils::generate_document_with_keys();
+    let claims = CredentialJwtClaims::new(&credential).unwrap();
+
+    // the credential was not issued by this issuer
+    let jwt: String = issuer_doc
+      .sign_jwt(
+        &claims,
+        issuer_key.private(),
+        issuer_doc.default_signing_method().unwrap().id(),
+      )
+      .unwrap();
+    assert!(matches!(
+      CredentialValidator::verify_jwt_signature::<Object, _>(
+        &jwt,
+        std::slice::from_ref(&other_doc),
+        &VerifierOptions::default()
+      )
+      .unwrap_err(),
+      ValidationError::DocumentMismatch { .. }
+    ));
+
+    // the token was signed with other keys
+    let jwt: String = issuer_doc
+      .sign_jwt(
+        &claims,
+        other_key.private(),
+        issuer_doc.default_signing_method().unwrap().id(),
+      )
+      .unwrap();
+    assert!(matches!(
+      CredentialValidator::verify_jwt_signature::<Object, _>(
+        &jwt,
+        std::slice::from_ref(&issuer_doc),
+        &VerifierOptions::default()
+      )
+      .unwrap_err(),
+      ValidationError::Signature { .. }
+    ));
+
+    // the token is not a JWT
+    assert!(matches!(
+      CredentialValidator::verify_jwt_signature::<Object, _>(
+        "not-a-jwt",
+        std::slice::from_ref(&issuer_doc),
+        &VerifierOptions::default()
+      )
+      .unwrap_err(),
+      ValidationError::MalformedJwt
+    ));
+  }
+
+  #[test]
+  fn test_full_validation_revocation_bitmap() {
+    let Setup {
+      mut issuer_doc,
+      issuer_key,
+      unsigned_credential: mut credential,
+      issuance_date,
+      expiration_date,
+    } = Setup::new();
+    let service_id = issuer_doc.id().to_url().join("#revocation").unwrap();
+    assert!(issuer_doc.insert_service(RevocationBitmap::new().to_service(service_id.clone()).unwrap()));
+    credential.credential_status = OneOrMany::One(RevocationBitmapStatus::new(service_id, 5).unwrap().into());
+
+    issuer_doc
+      .sign_data(
+        &mut credential,
+        issuer_key.private(),
+        issuer_doc.default_signing_method().unwrap().id(),
+        ProofOptions::default(),
+      )
+      .unwrap();
+
+    let issued_on_or_before = issuance_date.checked_add(Duration::days(14)).unwrap();
+    let expires_on_or_after = expiration_date.checked_sub(Duration::hours(1)).unwrap();
+    let options = CredentialValidationOptions::default()
+      .latest_issuance_date(issued_on_or_before)
+      .earliest_expiry_date(expires_on_or_after);
+
+    // the credential is not revoked
+    assert!(CredentialValidator::validate(&credential, &issuer_doc, &options, FailFast::FirstError).is_ok());
+
+    // the credential is revoked
+    issuer_doc.revoke_credentials("#revocation", &[5]).unwrap();
+    let validation_errors = CredentialValidator::validate(&credential, &issuer_doc, &options, FailFast::FirstError)
+      .unwrap_err()
+      .validation_errors;
+    assert!(matches!(validation_errors.as_slice(), [ValidationError::Revoked]));
+
+    // the status is not checked
+    let options = options.status_check(StatusCheck::SkipAll);
+    assert!(CredentialValidator::validate(&credential, &issuer_doc, &options, FailFast::FirstError).is_ok());
+
+    // the revocation bitmap is not published by the issuer
+    let (other_doc, _) = test_utils::generate_document_with_keys();
+    credential.credential_status = OneOrMany::One(
+      RevocationBitmapStatus::new(other_doc.id().to_url().join("#revocation").unwrap(), 5)
+        .unwrap()
+        .into(),
+    );
+    assert!(matches!(
+      CredentialValidator::check_revocation_bitmap_status(
+        &credential,
+        std::slice::from_ref(&issuer_doc),
+        StatusCheck::SkipUnsupported
+      )
+      .unwrap_err(),
+      ValidationError::StatusListIssuer
+    ));
+
+    // statuses of other types are only rejected by a strict status check
+    credential.credential_status = OneOrMany::One(Status::new(
+      Url::parse("https://example.edu/status/24").unwrap(),
+      "CredentialStatusList2017".to_owned(),
+    ));
+    assert!(CredentialValidator::check_revocation_bitmap_status(
+      &credential,
+      std::slice::from_ref(&issuer_doc),
+      StatusCheck::SkipUnsupported
+    )
+    .is_ok());
+    assert!(matches!(
+      CredentialValidator::check_revocation_bitmap_status(
+        &credential,
+        std::slice::from_ref(&issuer_doc),
+        StatusCheck::Strict
+      )
+      .unwrap_err(),
+      ValidationError::Status { .. }
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_check_status() {
+    let Setup {
+      issuer_doc,
+      issuer_key,
+      unsigned_credential: mut credential,
+      ..
+    } = Setup::new();
+    let status_list_id = Url::parse("https://example.com/credentials/status/3").unwrap();
+    credential.credential_status = OneOrMany::One(
+      StatusList2021Entry::new(
+        status_list_id.join("#94567").unwrap(),
+        StatusPurpose::Revocation,
+        94567,
+        status_list_id.clone(),
+      )
+      .into(),
+    );
+
+    let build_status_list = |issuer: &IotaDocument, key: &KeyPair, status_list: StatusList2021| -> Credential {
+      let mut status_list_credential: Credential = StatusList2021CredentialBuilder::new(status_list)
+        .id(status_list_id.clone())
+        .issuer(Url::parse(issuer.id().as_str()).unwrap())
+        .build()
+        .unwrap();
+      issuer
+        .sign_data(
+          &mut status_list_credential,
+          key.private(),
+          issuer.default_signing_method().unwrap().id(),
+          ProofOptions::default(),
+        )
+        .unwrap();
+      status_list_credential
+    };
+    let fetcher_for = |status_list_credential: Credential| {
+      move |id: &Url| {
+        assert_eq!(id, &status_list_credential.id.clone().unwrap());
+        let status_list_credential: Credential = status_list_credential.clone();
+        async move { Ok::<_, Box<dyn std::error::Error + Send + Sync + 'static>>(status_list_credential) }
+      }
+    };
+    let issuers = std::slice::from_ref(&issuer_doc);
+
+    // the credential is not revoked
+    let mut status_list = StatusList2021::default();
+    status_list.set(94566, true).unwrap();
+    let fetcher = fetcher_for(build_status_list(&issuer_doc, &issuer_key, status_list.clone()));
+    assert!(CredentialValidator::check_status(&credential, issuers, &fetcher)
+      .await
+      .is_ok());
+
+    // the credential is revoked
+    status_list.set(94567, true).unwrap();
+    let fetcher = fetcher_for(build_status_list(&issuer_doc, &issuer_key, status_list.clone()));
+    assert!(matches!(
+      CredentialValidator::check_status(&credential, issuers, &fetcher)
+        .await
+        .unwrap_err(),
+      ValidationError::Revoked
+    ));
+
+    // the status list was not published by the issuer
+    let (other_doc, other_key) = test_utils::generate_document_with_keys();
+    let fetcher = fetcher_for(build_status_list(&other_doc, &other_key, status_list.clone()));
+    assert!(matches!(
+      CredentialValidator::check_status(&credential, issuers, &fetcher)
+        .await
+        .unwrap_err(),
+      ValidationError::StatusListIssuer
+    ));
+
+    // the status list claims to be issued by the issuer but is signed with another key
+    let fetcher = fetcher_for(build_status_list(&issuer_doc, &other_key, StatusList2021::default()));
+    assert!(matches!(
+      CredentialValidator::check_status(&credential, issuers, &fetcher)
+        .await
+        .unwrap_err(),
+      ValidationError::Signature { .. }
+    ));
+
+    // the status list is not signed
+    let mut unsigned: Credential = build_status_list(&issuer_doc, &issuer_key, StatusList2021::default());
+    unsigned.proof = None;
+    let fetcher = fetcher_for(unsigned);
+    assert!(matches!(
+      CredentialValidator::check_status(&credential, issuers, &fetcher)
+        .await
+        .unwrap_err(),
+      ValidationError::Signature { .. }
+    ));
+
+    // the status list cannot be fetched
+    let fetcher =
+      |_: &Url| async { Err::<Credential, Box<dyn std::error::Error + Send + Sync + 'static>>("not found".into()) };
+    assert!(matches!(
+      CredentialValidator::check_status(&credential, issuers, &fetcher)
+        .await
+        .unwrap_err(),
+      ValidationError::Status { .. }
+    ));
+
+    // statuses of other types are ignored
+    credential.credential_status = OneOrMany::One(Status::new(status_list_id, "CredentialStatusList2017".to_owned()));
+    assert!(CredentialValidator::check_status(&credential, issuers, &fetcher)
+      .await
+      .is_ok());
+  }
+
   #[test]
   fn test_check_subject_holder_relationship() {
     let Setup {
@@ -53,6 +53,29 @@ pub enum ValidationError {
   /// Indicates that the presentation does not have a holder.
   #[error("the presentation has an empty holder property")]
   MissingPresentationHolder,
+  /// Indicates that a JSON Web Token could not be decoded.
+  #[error("the JWT is malformed")]
+  MalformedJwt,
+  /// Indicates that the claims of a JSON Web Token do not represent a valid credential (resp. presentation).
+  #[error("the JWT claims do not represent a valid credential or presentation")]
+  JwtClaims(#[source] identity_credential::Error),
+  /// Indicates that the status of the credential could not be determined, e.g. because its status list could not be
+  /// fetched or decoded.
+  #[error("could not determine the credential's status")]
+  #[non_exhaustive]
+  Status {
+    source: Box<dyn std::error::Error + Send + Sync + 'static>,
+  },
+  /// Indicates that the status list or revocation bitmap referenced by the credential was not published by the
+  /// credential's issuer.
+  #[error("the credential's status was not published by its issuer")]
+  StatusListIssuer,
+  /// Indicates that the credential has been revoked.
+  #[error("the credential has been revoked")]
+  Revoked,
+  /// Indicates that the credential has been suspended.
+  #[error("the credential has been suspended")]
+  Suspended,
 }
 
 #[derive(Debug)]
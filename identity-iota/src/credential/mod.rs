@@ -4,9 +4,11 @@
 mod credential_validator;
 mod errors;
 mod presentation_validator;
+mod status_list_fetcher;
 #[cfg(test)]
 mod test_utils;
 mod validation_options;
+mod validator_document;
 
 pub use self::credential_validator::CredentialValidator;
 pub use self::errors::CompoundCredentialValidationError;
@@ -14,7 +16,10 @@ pub use self::errors::CompoundPresentationValidationError;
 pub use self::errors::SignerContext;
 pub use self::errors::ValidationError;
 pub use self::presentation_validator::PresentationValidator;
+pub use self::status_list_fetcher::StatusListFetcher;
 pub use self::validation_options::CredentialValidationOptions;
 pub use self::validation_options::FailFast;
 pub use self::validation_options::PresentationValidationOptions;
+pub use self::validation_options::StatusCheck;
 pub use self::validation_options::SubjectHolderRelationship;
+pub use self::validator_document::ValidatorDocument;
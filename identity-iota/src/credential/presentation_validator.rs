@@ -3,10 +3,18 @@
 
 use std::collections::BTreeMap;
 
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_core::utils::decode_b64;
+use identity_credential::credential::Credential;
 use identity_credential::presentation::Presentation;
+use identity_credential::presentation::PresentationJwtClaims;
+use identity_did::did::CoreDID;
+use identity_did::did::DID;
 use identity_did::verifiable::VerifierOptions;
-use identity_iota_core::did::IotaDID;
-use identity_iota_core::document::IotaDocument;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use super::errors::CompoundCredentialValidationError;
@@ -15,6 +23,7 @@ use super::errors::SignerContext;
 use super::errors::ValidationError;
 use super::FailFast;
 use super::PresentationValidationOptions;
+use super::ValidatorDocument;
 use crate::credential::credential_validator::CredentialValidator;
 
 /// A struct for validating [`Presentation`]s.
@@ -53,9 +62,9 @@ impl PresentationValidator {
   ///
   /// # Errors
   /// An error is returned whenever a validated condition is not satisfied.
-  pub fn validate<U: Serialize, V: Serialize, D: AsRef<IotaDocument>>(
+  pub fn validate<U: Serialize, V: Serialize, H: ValidatorDocument, D: ValidatorDocument>(
     presentation: &Presentation<U, V>,
-    holder: &D,
+    holder: &H,
     issuers: &[D],
     options: &PresentationValidationOptions,
     fail_fast: FailFast,
@@ -101,6 +110,146 @@ impl PresentationValidator {
     }
   }
 
+  /// Decode and validate a [`Presentation`] encoded as a JSON Web Token (VP-JWT).
+  ///
+  /// The following properties are validated according to `options`:
+  /// - the holder's signature, verified with the verification method referenced by the `kid` header of the token,
+  /// - the `nbf` and `exp` claims of the token, against the current time,
+  /// - the `nonce` and `aud` claims of the token, against the challenge and domain of the verifier options,
+  /// - the semantic structure of the presentation,
+  /// - the relationship between the holder and the credential subjects,
+  /// - the signatures and some properties of the constituent credentials, which may either be embedded or encoded as
+  /// JWTs themselves (see [`CredentialValidator::validate`] and [`CredentialValidator::validate_jwt`]).
+  ///
+  /// # Warning
+  /// The warnings of [`PresentationValidator::validate`] apply equally to this method.
+  ///
+  /// # Errors
+  /// An error is returned whenever the token cannot be decoded or a validated condition is not satisfied.
+  pub fn validate_jwt<
+    U: DeserializeOwned,
+    V: DeserializeOwned + Serialize,
+    H: ValidatorDocument,
+    D: ValidatorDocument,
+  >(
+    jwt: &str,
+    holder: &H,
+    issuers: &[D],
+    options: &PresentationValidationOptions,
+    fail_fast: FailFast,
+  ) -> std::result::Result<Presentation<U, V>, CompoundPresentationValidationError> {
+    // Nothing else can be validated without the verified claims.
+    let mut claims: PresentationJwtClaims =
+      Self::verify_presentation_jwt_signature(jwt, holder, &options.presentation_verifier_options).map_err(
+        |error| CompoundPresentationValidationError {
+          presentation_validation_errors: vec![error],
+          credential_errors: BTreeMap::new(),
+        },
+      )?;
+
+    // Credentials are validated separately as they may be encoded as JWTs.
+    let credentials: Vec<Value> = match claims.vp.remove("verifiableCredential") {
+      Some(Value::Array(credentials)) => credentials,
+      Some(credential) => vec![credential],
+      None => Vec::new(),
+    };
+    let holder_url: Url = claims.iss.clone();
+
+    let now: i64 = Timestamp::now_utc().to_unix();
+    let issuance_date_validation = std::iter::once_with(|| {
+      (claims.nbf.unwrap_or(now) <= now)
+        .then(|| ())
+        .ok_or(ValidationError::IssuanceDate)
+    });
+    let expiry_date_validation = std::iter::once_with(|| {
+      (claims.exp.unwrap_or(now) >= now)
+        .then(|| ())
+        .ok_or(ValidationError::ExpirationDate)
+    });
+
+    let mut presentation_validation_errors: Vec<ValidationError> = issuance_date_validation
+      .chain(expiry_date_validation)
+      .filter_map(|result| result.err())
+      .collect();
+
+    let presentation: Option<Presentation<U, V>> = match claims.try_into_presentation() {
+      Ok(presentation) => {
+        presentation_validation_errors.extend(Self::check_structure(&presentation).err());
+        Some(presentation)
+      }
+      Err(error) => {
+        presentation_validation_errors.push(ValidationError::JwtClaims(error));
+        None
+      }
+    };
+
+    if let FailFast::FirstError = fail_fast {
+      if !presentation_validation_errors.is_empty() {
+        presentation_validation_errors.truncate(1);
+
+        return Err(CompoundPresentationValidationError {
+          presentation_validation_errors,
+          credential_errors: BTreeMap::new(),
+        });
+      }
+    }
+
+    let mut decoded: Vec<Credential<V>> = Vec::with_capacity(credentials.len());
+    let mut credential_errors: BTreeMap<usize, CompoundCredentialValidationError> = BTreeMap::new();
+
+    for (position, credential) in credentials.into_iter().enumerate() {
+      let relationship_criterion = Some((&holder_url, options.subject_holder_relationship));
+
+      let result: std::result::Result<Credential<V>, CompoundCredentialValidationError> = match credential {
+        Value::String(jwt) => CredentialValidator::validate_jwt_extended(
+          &jwt,
+          issuers,
+          &options.shared_validation_options,
+          relationship_criterion,
+          fail_fast,
+        ),
+        credential => Credential::from_json_value(credential)
+          .map_err(|error| CompoundCredentialValidationError {
+            validation_errors: vec![ValidationError::JwtClaims(
+              identity_credential::Error::InvalidJwtClaims("vp", Some(error)),
+            )],
+          })
+          .and_then(|credential| {
+            CredentialValidator::validate_extended(
+              &credential,
+              issuers,
+              &options.shared_validation_options,
+              relationship_criterion,
+              fail_fast,
+            )
+            .map(|_| credential)
+          }),
+      };
+
+      match result {
+        Ok(credential) => decoded.push(credential),
+        Err(error) => {
+          credential_errors.insert(position, error);
+
+          if let FailFast::FirstError = fail_fast {
+            break;
+          }
+        }
+      }
+    }
+
+    match presentation {
+      Some(mut presentation) if presentation_validation_errors.is_empty() && credential_errors.is_empty() => {
+        presentation.verifiable_credential = decoded.into();
+        Ok(presentation)
+      }
+      _ => Err(CompoundPresentationValidationError {
+        presentation_validation_errors,
+        credential_errors,
+      }),
+    }
+  }
+
   /// Verify the presentation's signature using the resolved document of the holder.
   ///
   /// # Warning
@@ -109,26 +258,25 @@ impl PresentationValidator {
   /// # Errors
   /// Fails if the `holder` does not match the `presentation`'s holder property.
   /// Fails if signature verification against the holder document fails.
-  pub fn verify_presentation_signature<U: Serialize, V: Serialize, D: AsRef<IotaDocument>>(
+  pub fn verify_presentation_signature<U: Serialize, V: Serialize, H: ValidatorDocument>(
     presentation: &Presentation<U, V>,
-    holder: &D,
+    holder: &H,
     options: &VerifierOptions,
   ) -> ValidationUnitResult {
-    let did: IotaDID = presentation
+    let did: CoreDID = presentation
       .holder
       .as_ref()
       .ok_or(ValidationError::MissingPresentationHolder)
       .and_then(|value| {
-        IotaDID::parse(value.as_str()).map_err(|error| ValidationError::SignerUrl {
+        CoreDID::parse(value.as_str()).map_err(|error| ValidationError::SignerUrl {
           source: error.into(),
           signer_ctx: SignerContext::Holder,
         })
       })?;
-    if &did != holder.as_ref().id() {
+    if did.as_str() != holder.did_str() {
       return Err(ValidationError::DocumentMismatch(SignerContext::Holder));
     }
     holder
-      .as_ref()
       .verify_data(&presentation, options)
       .map_err(|error| ValidationError::Signature {
         source: error.into(),
@@ -136,6 +284,36 @@ impl PresentationValidator {
       })
   }
 
+  // Decodes the claims of a VP-JWT after verifying its signature using the document of the holder.
+  fn verify_presentation_jwt_signature<H: ValidatorDocument>(
+    jwt: &str,
+    holder: &H,
+    options: &VerifierOptions,
+  ) -> std::result::Result<PresentationJwtClaims, ValidationError> {
+    // The unverified `iss` claim is only compared against the DID of the holder document.
+    let claims: PresentationJwtClaims = jwt
+      .split('.')
+      .nth(1)
+      .and_then(|payload| decode_b64(payload).ok())
+      .and_then(|payload| PresentationJwtClaims::from_json_slice(&payload).ok())
+      .ok_or(ValidationError::MalformedJwt)?;
+
+    let did: CoreDID = CoreDID::parse(claims.iss.as_str()).map_err(|error| ValidationError::SignerUrl {
+      source: error.into(),
+      signer_ctx: SignerContext::Holder,
+    })?;
+    if did.as_str() != holder.did_str() {
+      return Err(ValidationError::DocumentMismatch(SignerContext::Holder));
+    }
+
+    holder
+      .verify_jwt(jwt, options)
+      .map_err(|error| ValidationError::Signature {
+        source: error.into(),
+        signer_ctx: SignerContext::Holder,
+      })
+  }
+
   /// Validates the semantic structure of the [Presentation].
   pub fn check_structure<U, V>(presentation: &Presentation<U, V>) -> ValidationUnitResult {
     presentation
@@ -148,9 +326,9 @@ impl PresentationValidator {
   // The following properties are validated according to `options`:
   // - the semantic structure of the presentation,
   // - the holder's signature,
-  fn validate_presentation_without_credentials<U: Serialize, V: Serialize, D: AsRef<IotaDocument>>(
+  fn validate_presentation_without_credentials<U: Serialize, V: Serialize, H: ValidatorDocument>(
     presentation: &Presentation<U, V>,
-    holder: &D,
+    holder: &H,
     options: &PresentationValidationOptions,
     fail_fast: FailFast,
   ) -> Result<(), Vec<ValidationError>> {
@@ -181,7 +359,7 @@ impl PresentationValidator {
   // - the relationship between the holder and the credential subjects,
   // - the signatures and some properties of the constituent credentials (see
   // [`CredentialValidator::validate`]).
-  fn validate_credentials<U, V: Serialize, D: AsRef<IotaDocument>>(
+  fn validate_credentials<U, V: Serialize, D: ValidatorDocument>(
     presentation: &Presentation<U, V>,
     issuers: &[D],
     options: &PresentationValidationOptions,
@@ -223,12 +401,18 @@ impl PresentationValidator {
 
 #[cfg(test)]
 mod tests {
+  use identity_core::common::Object;
+  use identity_core::common::OneOrMany;
   use identity_core::common::Timestamp;
   use identity_core::common::Url;
   use identity_core::crypto::KeyPair;
+  use identity_core::crypto::KeyType;
   use identity_core::crypto::ProofOptions;
   use identity_credential::credential::Credential;
+  use identity_credential::credential::CredentialJwtClaims;
   use identity_credential::presentation::PresentationBuilder;
+  use identity_did::document::CoreDocument;
+  use identity_did::methods::key::DIDKey;
   use identity_iota_core::document::IotaDocument;
 
   use super::*;
@@ -375,6 +559,242 @@ mod tests {
     .is_ok());
   }
 
+  #[test]
+  fn test_full_validation_did_key_holder() {
+    let TestSetup {
+      credential_foo,
+      credential_bar,
+      issuer_foo_doc,
+      issuer_bar_doc,
+      ..
+    } = TestSetup::new_with_signed_credentials();
+
+    // the holder uses a different DID method than the issuers
+    let holder_key: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
+    let holder_doc: CoreDocument = DIDKey::new(KeyType::Ed25519, holder_key.public())
+      .unwrap()
+      .document()
+      .unwrap();
+    let mut presentation: Presentation = PresentationBuilder::default()
+      .holder(Url::parse(holder_doc.id().as_str()).unwrap())
+      .credential(credential_foo)
+      .credential(credential_bar)
+      .build()
+      .unwrap();
+    holder_doc
+      .signer(holder_key.private())
+      .method(holder_doc.verification_method().head().unwrap().id())
+      .sign(&mut presentation)
+      .unwrap();
+
+    let credential_validation_options = CredentialValidationOptions::default()
+      .earliest_expiry_date(Timestamp::parse("2021-01-01T00:00:00Z").unwrap())
+      .latest_issuance_date(Timestamp::parse("2030-01-01T00:00:00Z").unwrap());
+    let presentation_validation_options = PresentationValidationOptions::default()
+      .shared_validation_options(credential_validation_options)
+      .subject_holder_relationship(SubjectHolderRelationship::Any);
+
+    let trusted_issuers = [issuer_foo_doc, issuer_bar_doc];
+
+    assert!(PresentationValidator::validate(
+      &presentation,
+      &holder_doc,
+      &trusted_issuers,
+      &presentation_validation_options,
+      FailFast::FirstError
+    )
+    .is_ok());
+
+    // the DID Document of an issuer does not match the holder
+    let error: CompoundPresentationValidationError = PresentationValidator::validate(
+      &presentation,
+      &trusted_issuers[0],
+      &trusted_issuers,
+      &presentation_validation_options,
+      FailFast::FirstError,
+    )
+    .unwrap_err();
+    assert!(matches!(
+      error.presentation_validation_errors.as_slice(),
+      [ValidationError::DocumentMismatch(SignerContext::Holder)]
+    ));
+  }
+
+  #[test]
+  fn test_full_validation_jwt() {
+    let TestSetup {
+      subject_foo_doc,
+      subject_foo_key,
+      credential_foo,
+      credential_bar,
+      issuer_foo_doc,
+      issuer_bar_doc,
+      issuer_bar_key,
+      ..
+    } = TestSetup::new_with_signed_credentials();
+
+    // embed credential_foo and encode credential_bar as a JWT
+    let presentation = build_presentation(&subject_foo_doc, [credential_foo.clone()].to_vec());
+    let credential_bar_jwt: String = issuer_bar_doc
+      .sign_jwt(
+        &CredentialJwtClaims::new(&credential_bar).unwrap(),
+        issuer_bar_key.private(),
+        issuer_bar_doc.default_signing_method().unwrap().id(),
+      )
+      .unwrap();
+    let mut claims = PresentationJwtClaims::new(&presentation).unwrap();
+    claims.append_credential_jwt(credential_bar_jwt);
+
+    // sign the presentation using subject_foo's document and private key
+    let jwt: String = subject_foo_doc
+      .sign_jwt(
+        &claims,
+        subject_foo_key.private(),
+        subject_foo_doc.default_signing_method().unwrap().id(),
+      )
+      .unwrap();
+
+    // validate the presentation
+    let issued_before = Timestamp::parse("2030-01-01T00:00:00Z").unwrap();
+    let expires_after = Timestamp::parse("2021-01-01T00:00:00Z").unwrap();
+    let credential_validation_options = CredentialValidationOptions::default()
+      .earliest_expiry_date(expires_after)
+      .latest_issuance_date(issued_before);
+    let presentation_validation_options = PresentationValidationOptions::default()
+      .shared_validation_options(credential_validation_options)
+      .subject_holder_relationship(SubjectHolderRelationship::SubjectOnNonTransferable);
+
+    let trusted_issuers = [issuer_foo_doc, issuer_bar_doc];
+
+    let decoded: Presentation = PresentationValidator::validate_jwt(
+      &jwt,
+      &subject_foo_doc,
+      &trusted_issuers,
+      &presentation_validation_options,
+      FailFast::FirstError,
+    )
+    .unwrap();
+
+    assert_eq!(decoded.holder, presentation.holder);
+    assert_eq!(decoded.verifiable_credential.len(), 2);
+    assert_eq!(decoded.verifiable_credential.get(0), Some(&credential_foo));
+    assert_eq!(decoded.verifiable_credential.get(1).unwrap().id, credential_bar.id);
+
+    // the presentation was not signed by this holder
+    let (other_doc, _) = test_utils::generate_document_with_keys();
+    let error = PresentationValidator::validate_jwt::<Object, Object, _, _>(
+      &jwt,
+      &other_doc,
+      &trusted_issuers,
+      &presentation_validation_options,
+      FailFast::AllErrors,
+    )
+    .unwrap_err();
+
+    assert!(error.credential_errors.is_empty());
+    assert!(matches!(
+      error.presentation_validation_errors.as_slice(),
+      [ValidationError::DocumentMismatch(SignerContext::Holder)]
+    ));
+  }
+
+  #[test]
+  fn test_validate_jwt_expired_presentation() {
+    let TestSetup {
+      subject_foo_doc,
+      subject_foo_key,
+      credential_foo,
+      issuer_foo_doc,
+      ..
+    } = TestSetup::new_with_signed_credentials();
+
+    let presentation = build_presentation(&subject_foo_doc, [credential_foo].to_vec());
+    let mut claims = PresentationJwtClaims::new(&presentation).unwrap();
+    claims.exp = Some(Timestamp::parse("2021-01-01T00:00:00Z").unwrap().to_unix());
+
+    let jwt: String = subject_foo_doc
+      .sign_jwt(
+        &claims,
+        subject_foo_key.private(),
+        subject_foo_doc.default_signing_method().unwrap().id(),
+      )
+      .unwrap();
+
+    let error = PresentationValidator::validate_jwt::<Object, Object, _, _>(
+      &jwt,
+      &subject_foo_doc,
+      std::slice::from_ref(&issuer_foo_doc),
+      &PresentationValidationOptions::default(),
+      FailFast::FirstError,
+    )
+    .unwrap_err();
+
+    // the constituent credentials are not validated after the presentation failed with `FailFast::FirstError`
+    assert!(error.credential_errors.is_empty());
+    assert!(matches!(
+      error.presentation_validation_errors.as_slice(),
+      [ValidationError::ExpirationDate]
+    ));
+  }
+
+  #[test]
+  fn test_validate_jwt_challenge_and_domain() {
+    let TestSetup {
+      subject_foo_doc,
+      subject_foo_key,
+      credential_foo,
+      issuer_foo_doc,
+      ..
+    } = TestSetup::new_with_signed_credentials();
+
+    let presentation = build_presentation(&subject_foo_doc, [credential_foo].to_vec());
+    let mut claims = PresentationJwtClaims::new(&presentation).unwrap();
+    claims.nonce = Some("some challenge".to_owned());
+    claims.aud = Some(OneOrMany::One("https://verifier.example".to_owned()));
+
+    let jwt: String = subject_foo_doc
+      .sign_jwt(
+        &claims,
+        subject_foo_key.private(),
+        subject_foo_doc.default_signing_method().unwrap().id(),
+      )
+      .unwrap();
+
+    let validate = |challenge: &str, domain: &str| {
+      let credential_validation_options = CredentialValidationOptions::default()
+        .earliest_expiry_date(Timestamp::parse("2021-01-01T00:00:00Z").unwrap())
+        .latest_issuance_date(Timestamp::parse("2030-01-01T00:00:00Z").unwrap());
+      let options = PresentationValidationOptions::default()
+        .shared_validation_options(credential_validation_options)
+        .presentation_verifier_options(
+          VerifierOptions::new()
+            .challenge(challenge.to_owned())
+            .domain(domain.to_owned()),
+        );
+      PresentationValidator::validate_jwt::<Object, Object, _, _>(
+        &jwt,
+        &subject_foo_doc,
+        std::slice::from_ref(&issuer_foo_doc),
+        &options,
+        FailFast::FirstError,
+      )
+    };
+
+    assert!(validate("some challenge", "https://verifier.example").is_ok());
+
+    // the token cannot be replayed to another verifier
+    for (challenge, domain) in [
+      ("other challenge", "https://verifier.example"),
+      ("some challenge", "https://other.example"),
+    ] {
+      let error = validate(challenge, domain).unwrap_err();
+      assert!(matches!(
+        error.presentation_validation_errors.as_slice(),
+        [ValidationError::Signature { .. }]
+      ));
+    }
+  }
+
   #[test]
   fn test_full_validation_invalid_holder_signature() {
     let TestSetup {
@@ -0,0 +1,35 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::future::Future;
+
+use async_trait::async_trait;
+use identity_core::common::Url;
+use identity_credential::credential::Credential;
+
+/// Retrieves the status list credentials referenced by the
+/// [`StatusList2021Entry`](identity_credential::credential::StatusList2021Entry)s of a credential, see
+/// [`CredentialValidator::check_status`](super::CredentialValidator::check_status()).
+///
+/// Implemented for closures of the form `Fn(&Url) -> impl Future<Output = Result<Credential, _>>`.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait StatusListFetcher: Send + Sync {
+  /// Returns the status list credential identified by `id`.
+  ///
+  /// The returned credential does not need to be trusted: its signature and issuer are verified by
+  /// [`CredentialValidator::check_status`](super::CredentialValidator::check_status()).
+  async fn fetch(&self, id: &Url) -> Result<Credential, Box<dyn std::error::Error + Send + Sync + 'static>>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<F, Fut> StatusListFetcher for F
+where
+  F: Fn(&Url) -> Fut + Send + Sync,
+  Fut: Future<Output = Result<Credential, Box<dyn std::error::Error + Send + Sync + 'static>>> + Send,
+{
+  async fn fetch(&self, id: &Url) -> Result<Credential, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    self(id).await
+  }
+}
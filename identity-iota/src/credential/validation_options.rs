@@ -25,6 +25,11 @@ pub struct CredentialValidationOptions {
   /// Options which affect the verification of the signature on the credential.
   #[serde(default)]
   pub verifier_options: VerifierOptions,
+
+  /// Declares how the status of the credential is checked.
+  /// Default: [`StatusCheck::SkipUnsupported`].
+  #[serde(default)]
+  pub status: StatusCheck,
 }
 
 impl CredentialValidationOptions {
@@ -52,6 +57,35 @@ impl CredentialValidationOptions {
     self.verifier_options = options;
     self
   }
+
+  /// Declare how the status of the credential is checked.
+  pub fn status_check(mut self, status_check: StatusCheck) -> Self {
+    self.status = status_check;
+    self
+  }
+}
+
+/// Declares how the `credentialStatus` of a credential is checked during validation.
+/// See [`CredentialValidator::check_revocation_bitmap_status`](super::CredentialValidator::check_revocation_bitmap_status()).
+// Need to use serde_repr to make this work with duck typed interfaces in the Wasm bindings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde_repr::Serialize_repr, serde_repr::Deserialize_repr)]
+#[repr(u8)]
+pub enum StatusCheck {
+  /// Check the [`RevocationBitmapStatus`](identity_credential::credential::RevocationBitmapStatus) statuses against
+  /// the issuer's DID Document and fail on any status of another type.
+  Strict = 0,
+  /// Check the [`RevocationBitmapStatus`](identity_credential::credential::RevocationBitmapStatus) statuses against
+  /// the issuer's DID Document and ignore statuses of other types.
+  /// This is the variant returned by [Self::default](Self::default()).
+  SkipUnsupported = 1,
+  /// Do not check the status of the credential.
+  SkipAll = 2,
+}
+
+impl Default for StatusCheck {
+  fn default() -> Self {
+    Self::SkipUnsupported
+  }
 }
 
 /// Declares how credential subjects must relate to the presentation holder during validation.
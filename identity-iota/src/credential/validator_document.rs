@@ -0,0 +1,131 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::crypto::GetSignature;
+use identity_did::did::CoreDIDUrl;
+use identity_did::did::DID;
+use identity_did::document::CoreDocument;
+use identity_did::revocation::RevocationBitmap;
+use identity_did::service::Service;
+use identity_did::utils::Queryable;
+use identity_did::verifiable::VerifierOptions;
+use identity_iota_core::document::IotaDocument;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::Result;
+
+/// A DID Document that can be used to validate credentials and presentations signed by its subject.
+///
+/// This is implemented for [`IotaDocument`]s as well as [`CoreDocument`]s of any other DID method, which allows
+/// validating credentials and presentations whose issuers and holders use different DID methods.
+pub trait ValidatorDocument {
+  /// Returns the string identifying the DID Document.
+  fn did_str(&self) -> &str;
+
+  /// Verifies the signature of the provided data.
+  ///
+  /// # Errors
+  ///
+  /// Fails if an unsupported verification method is used, data serialization fails, or the verification
+  /// operation fails.
+  fn verify_data<X>(&self, data: &X, options: &VerifierOptions) -> Result<()>
+  where
+    X: Serialize + GetSignature;
+
+  /// Decodes the claims of a compact JSON Web Token after verifying its signature was created using a
+  /// verification method in this DID Document.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the token is malformed, the verification operation fails or the claims cannot be deserialized.
+  fn verify_jwt<X>(&self, jwt: &str, options: &VerifierOptions) -> Result<X>
+  where
+    X: DeserializeOwned;
+
+  /// Decodes the [`RevocationBitmap`] published by the service identified by `service_id`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the service does not exist or is not a valid [`RevocationBitmap`] service.
+  fn resolve_revocation_bitmap(&self, service_id: &CoreDIDUrl) -> Result<RevocationBitmap>;
+}
+
+impl ValidatorDocument for IotaDocument {
+  fn did_str(&self) -> &str {
+    self.id().as_str()
+  }
+
+  fn verify_data<X>(&self, data: &X, options: &VerifierOptions) -> Result<()>
+  where
+    X: Serialize + GetSignature,
+  {
+    IotaDocument::verify_data(self, data, options).map_err(Into::into)
+  }
+
+  fn verify_jwt<X>(&self, jwt: &str, options: &VerifierOptions) -> Result<X>
+  where
+    X: DeserializeOwned,
+  {
+    IotaDocument::verify_jwt(self, jwt, options).map_err(Into::into)
+  }
+
+  fn resolve_revocation_bitmap(&self, service_id: &CoreDIDUrl) -> Result<RevocationBitmap> {
+    IotaDocument::resolve_revocation_bitmap(self, service_id).map_err(Into::into)
+  }
+}
+
+impl ValidatorDocument for CoreDocument {
+  fn did_str(&self) -> &str {
+    self.id().as_str()
+  }
+
+  fn verify_data<X>(&self, data: &X, options: &VerifierOptions) -> Result<()>
+  where
+    X: Serialize + GetSignature,
+  {
+    CoreDocument::verify_data(self, data, options).map_err(Into::into)
+  }
+
+  fn verify_jwt<X>(&self, jwt: &str, options: &VerifierOptions) -> Result<X>
+  where
+    X: DeserializeOwned,
+  {
+    CoreDocument::verify_jwt(self, jwt, options).map_err(Into::into)
+  }
+
+  fn resolve_revocation_bitmap(&self, service_id: &CoreDIDUrl) -> Result<RevocationBitmap> {
+    let service: &Service = self
+      .service()
+      .query(service_id)
+      .ok_or(identity_did::Error::ServiceNotFound)?;
+    RevocationBitmap::try_from(service).map_err(Into::into)
+  }
+}
+
+impl<T> ValidatorDocument for &T
+where
+  T: ValidatorDocument + ?Sized,
+{
+  fn did_str(&self) -> &str {
+    (**self).did_str()
+  }
+
+  fn verify_data<X>(&self, data: &X, options: &VerifierOptions) -> Result<()>
+  where
+    X: Serialize + GetSignature,
+  {
+    (**self).verify_data(data, options)
+  }
+
+  fn verify_jwt<X>(&self, jwt: &str, options: &VerifierOptions) -> Result<X>
+  where
+    X: DeserializeOwned,
+  {
+    (**self).verify_jwt(jwt, options)
+  }
+
+  fn resolve_revocation_bitmap(&self, service_id: &CoreDIDUrl) -> Result<RevocationBitmap> {
+    (**self).resolve_revocation_bitmap(service_id)
+  }
+}
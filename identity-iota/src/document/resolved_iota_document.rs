@@ -6,16 +6,25 @@ use core::fmt::Display;
 use core::fmt::Formatter;
 use core::fmt::Result as FmtResult;
 
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde::Serialize;
 
 use identity_core::convert::FmtJson;
+use identity_core::crypto::GetSignature;
+use identity_did::did::CoreDID;
+use identity_did::did::CoreDIDUrl;
+use identity_did::document::CoreDocument;
+use identity_did::revocation::RevocationBitmap;
+use identity_did::verifiable::VerifierOptions;
 use identity_iota_core::did::IotaDID;
 use identity_iota_core::diff::DiffMessage;
+use identity_iota_core::document::IotaCoreDocument;
 use identity_iota_core::document::IotaDocument;
 use identity_iota_core::tangle::MessageId;
 use identity_iota_core::tangle::MessageIdExt;
 
+use crate::credential::ValidatorDocument;
 use crate::error::Result;
 use crate::tangle::TangleRef;
 
@@ -110,6 +119,36 @@ impl AsRef<IotaDocument> for ResolvedIotaDocument {
   }
 }
 
+impl From<ResolvedIotaDocument> for CoreDocument {
+  fn from(resolved: ResolvedIotaDocument) -> Self {
+    IotaCoreDocument::from(resolved.document).map(CoreDID::from, |properties| properties)
+  }
+}
+
+impl ValidatorDocument for ResolvedIotaDocument {
+  fn did_str(&self) -> &str {
+    self.document.did_str()
+  }
+
+  fn verify_data<X>(&self, data: &X, options: &VerifierOptions) -> Result<()>
+  where
+    X: Serialize + GetSignature,
+  {
+    ValidatorDocument::verify_data(&self.document, data, options)
+  }
+
+  fn verify_jwt<X>(&self, jwt: &str, options: &VerifierOptions) -> Result<X>
+  where
+    X: DeserializeOwned,
+  {
+    ValidatorDocument::verify_jwt(&self.document, jwt, options)
+  }
+
+  fn resolve_revocation_bitmap(&self, service_id: &CoreDIDUrl) -> Result<RevocationBitmap> {
+    ValidatorDocument::resolve_revocation_bitmap(&self.document, service_id)
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use identity_core::convert::FromJson;
@@ -30,6 +30,9 @@ pub enum Error {
   InvalidExplorerURL,
   #[error("compression error")]
   CompressionError,
+  /// Caused by a failure to encode a DID message as CBOR.
+  #[error("failed to encode DID message as CBOR")]
+  EncodeCBOR(#[source] serde_cbor::Error),
   #[error("invalid message flags")]
   InvalidMessageFlags,
   /// Caused by a single concern credential or presentation validation method failing.
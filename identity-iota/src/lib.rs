@@ -23,6 +23,8 @@ pub use self::error::Error;
 pub use self::error::Result;
 
 mod resolver;
+#[cfg(test)]
+mod test_utils;
 
 pub mod chain;
 pub mod credential;
@@ -1,6 +1,8 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use core::str::FromStr;
+
 use async_trait::async_trait;
 use identity_did::did::CoreDID;
 use identity_did::document::CoreDocument;
@@ -11,34 +13,69 @@ use identity_did::resolution::InputMetadata;
 use identity_did::resolution::MetaDocument;
 use identity_did::resolution::ResolverMethod;
 use identity_iota_core::did::IotaDID;
-use identity_iota_core::document::IotaCoreDocument;
+use identity_iota_core::tangle::MessageId;
+use identity_iota_core::tangle::MessageIdExt;
 
+use crate::chain::DocumentVersion;
 use crate::document::ResolvedIotaDocument;
 use crate::tangle::Client;
-use crate::tangle::TangleResolve;
 
-#[async_trait(?Send)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl ResolverMethod for Client {
   fn is_supported(&self, did: &CoreDID) -> bool {
     IotaDID::check_validity(did).is_ok()
   }
 
-  async fn read(&self, did: &CoreDID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+  async fn read(&self, did: &CoreDID, input: InputMetadata) -> Result<Option<MetaDocument>> {
     let iota_did: IotaDID = IotaDID::try_from_core(did.clone()).map_err(|_| Error::MissingResolutionDID)?;
-    let resolved: ResolvedIotaDocument = self
-      .resolve(&iota_did)
-      .await
-      .map_err(|_| Error::MissingResolutionDocument)?;
+    let resolved: ResolvedIotaDocument = match document_version(&input)? {
+      Some(version) => match self.resolve_version(&iota_did, &version).await {
+        Ok(resolved) => resolved,
+        Err(crate::Error::DIDNotFound(_)) => return Ok(None),
+        Err(_) => return Err(Error::MissingResolutionDocument),
+      },
+      None => self
+        .read_document(&iota_did)
+        .await
+        .map_err(|_| Error::MissingResolutionDocument)?,
+    };
 
     let mut metadata: DocumentMetadata = DocumentMetadata::new();
     metadata.created = resolved.document.metadata.created;
     metadata.updated = resolved.document.metadata.updated;
+    // IOTA DIDs cannot be deactivated, the latest document on the integration chain is always active.
+    metadata.deactivated = Some(false);
+    // The integration and diff chains only contain messages with valid signatures.
+    metadata.proof_verified = Some(true);
+    metadata.version_id = Some(version_id(&resolved).to_string());
 
-    let core_document: CoreDocument =
-      IotaCoreDocument::from(resolved.document).map(CoreDID::from, |properties| properties);
+    let core_document: CoreDocument = resolved.into();
     Ok(Some(MetaDocument {
       data: core_document,
       meta: metadata,
     }))
   }
 }
+
+/// Returns the [`DocumentVersion`] selected by the `versionId` or `versionTime` input metadata, if any.
+fn document_version(input: &InputMetadata) -> Result<Option<DocumentVersion>> {
+  match (&input.version_id, input.version_time) {
+    (Some(version_id), None) => MessageId::from_str(version_id)
+      .map(|message_id| Some(DocumentVersion::MessageId(message_id)))
+      .map_err(|_| Error::InvalidDIDQuery),
+    (None, Some(version_time)) => Ok(Some(DocumentVersion::Time(version_time))),
+    (None, None) => Ok(None),
+    // The version identifier and time are mutually exclusive.
+    (Some(_), Some(_)) => Err(Error::InvalidDIDQuery),
+  }
+}
+
+/// Returns the [`MessageId`] of the latest message merged into the `resolved` document.
+fn version_id(resolved: &ResolvedIotaDocument) -> &MessageId {
+  if resolved.diff_message_id.is_null() {
+    &resolved.integration_message_id
+  } else {
+    &resolved.diff_message_id
+  }
+}
@@ -0,0 +1,178 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bee_rest_api::types::dtos::LedgerInclusionStateDto;
+use futures::stream::FuturesUnordered;
+use futures::stream::TryStreamExt;
+use identity_iota_core::tangle::Message;
+use identity_iota_core::tangle::MessageId;
+use iota_client::Client as IotaClient;
+use iota_client::Error as IotaClientError;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// A ledger storing indexed messages, such as the IOTA Tangle.
+///
+/// [`Client`](crate::tangle::Client) publishes and reads DID messages through a [`TangleBackend`],
+/// which allows replacing the IOTA network with an alternative implementation such as the
+/// in-memory [`MemoryTangle`](crate::tangle::MemoryTangle).
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait TangleBackend: Debug + Send + Sync {
+  /// Publishes a message with the given `data` to the given `index`.
+  async fn publish_message(&self, index: &str, data: &[u8]) -> Result<Message>;
+
+  /// Waits until the message identified by `message_id` is referenced by a milestone, promoting or
+  /// reattaching it if necessary. `interval` is the number of seconds between attempts.
+  ///
+  /// Returns the included message, which may be a reattachment of the original message.
+  async fn retry_until_included(
+    &self,
+    message_id: &MessageId,
+    interval: Option<u64>,
+    max_attempts: Option<u64>,
+  ) -> Result<Message>;
+
+  /// Fetches the message identified by `message_id`.
+  async fn read_message(&self, message_id: &MessageId) -> Result<Message>;
+
+  /// Fetches all messages published to the given `index`, with no ordering guarantees.
+  async fn read_messages(&self, index: &str) -> Result<Vec<Message>>;
+
+  /// Fetches the ids of all messages published to the given `index`, with no ordering guarantees.
+  ///
+  /// Allows fetching only the messages which were not read before with
+  /// [`TangleBackend::read_message`].
+  async fn read_message_ids(&self, index: &str) -> Result<Vec<MessageId>>;
+
+  /// Returns the index of the milestone referencing the message identified by `message_id`, or
+  /// `None` if it is not referenced by a milestone.
+  async fn milestone_index(&self, message_id: &MessageId) -> Result<Option<u32>>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl TangleBackend for IotaClient {
+  async fn publish_message(&self, index: &str, data: &[u8]) -> Result<Message> {
+    self
+      .message()
+      .with_index(index)
+      .with_data(data.to_vec())
+      .finish()
+      .await
+      .map_err(Into::into)
+  }
+
+  async fn retry_until_included(
+    &self,
+    message_id: &MessageId,
+    interval: Option<u64>,
+    max_attempts: Option<u64>,
+  ) -> Result<Message> {
+    let retry_result: Result<Vec<(MessageId, Message)>, IotaClientError> =
+      IotaClient::retry_until_included(self, message_id, interval, max_attempts).await;
+    let reattached_messages: Vec<(MessageId, Message)> = match retry_result {
+      Ok(reattached_messages) => reattached_messages,
+      Err(inclusion_error @ IotaClientError::TangleInclusionError(_)) => {
+        if is_message_included(self, message_id).await? {
+          return self.get_message().data(message_id).await.map_err(Into::into);
+        } else {
+          return Err(Error::from(inclusion_error));
+        }
+      }
+      Err(error) => {
+        return Err(Error::from(error));
+      }
+    };
+    match reattached_messages.into_iter().next() {
+      Some((_, message)) => Ok(message),
+      None => Err(Error::from(IotaClientError::TangleInclusionError(
+        message_id.to_string(),
+      ))),
+    }
+  }
+
+  async fn read_message(&self, message_id: &MessageId) -> Result<Message> {
+    self.get_message().data(message_id).await.map_err(Into::into)
+  }
+
+  async fn read_messages(&self, index: &str) -> Result<Vec<Message>> {
+    // NOTE: the number of returned message ids is capped at 1000 due to limitations of the current
+    //       API, with no ordering guarantees.
+    let message_ids: Box<[MessageId]> = self.get_message().index(index).await?;
+
+    message_ids
+      .iter()
+      .map(|message_id| self.get_message().data(message_id))
+      .collect::<FuturesUnordered<_>>()
+      .try_collect()
+      .await
+      .map_err(Into::into)
+  }
+
+  async fn read_message_ids(&self, index: &str) -> Result<Vec<MessageId>> {
+    // NOTE: capped at 1000 message ids, see `read_messages`.
+    let message_ids: Box<[MessageId]> = self.get_message().index(index).await?;
+    Ok(message_ids.into_vec())
+  }
+
+  async fn milestone_index(&self, message_id: &MessageId) -> Result<Option<u32>> {
+    self
+      .get_message()
+      .metadata(message_id)
+      .await
+      .map(|metadata| metadata.referenced_by_milestone_index)
+      .map_err(Into::into)
+  }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<T> TangleBackend for Arc<T>
+where
+  T: TangleBackend + ?Sized,
+{
+  async fn publish_message(&self, index: &str, data: &[u8]) -> Result<Message> {
+    (**self).publish_message(index, data).await
+  }
+
+  async fn retry_until_included(
+    &self,
+    message_id: &MessageId,
+    interval: Option<u64>,
+    max_attempts: Option<u64>,
+  ) -> Result<Message> {
+    (**self).retry_until_included(message_id, interval, max_attempts).await
+  }
+
+  async fn read_message(&self, message_id: &MessageId) -> Result<Message> {
+    (**self).read_message(message_id).await
+  }
+
+  async fn read_messages(&self, index: &str) -> Result<Vec<Message>> {
+    (**self).read_messages(index).await
+  }
+
+  async fn read_message_ids(&self, index: &str) -> Result<Vec<MessageId>> {
+    (**self).read_message_ids(index).await
+  }
+
+  async fn milestone_index(&self, message_id: &MessageId) -> Result<Option<u32>> {
+    (**self).milestone_index(message_id).await
+  }
+}
+
+async fn is_message_included(client: &IotaClient, message_id: &MessageId) -> Result<bool> {
+  match client.get_message().metadata(message_id).await?.ledger_inclusion_state {
+    Some(ledger_inclusion_state) => match ledger_inclusion_state {
+      LedgerInclusionStateDto::Included | LedgerInclusionStateDto::NoTransaction => Ok(true),
+      LedgerInclusionStateDto::Conflicting => Ok(false),
+    },
+    None => Ok(false),
+  }
+}
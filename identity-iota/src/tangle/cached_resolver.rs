@@ -0,0 +1,312 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::time::Duration;
+
+use identity_iota_core::did::IotaDID;
+use identity_iota_core::tangle::MessageId;
+use instant::Instant;
+
+use crate::chain::DocumentChain;
+use crate::document::ResolvedIotaDocument;
+use crate::error::Result;
+use crate::tangle::Client;
+use crate::tangle::Resolver;
+use crate::tangle::SharedPtr;
+use crate::tangle::TangleResolve;
+
+const DEFAULT_TTL: Duration = Duration::from_secs(60);
+const DEFAULT_CAPACITY: usize = 1000;
+
+/// A caching layer over a [`Resolver`].
+///
+/// Resolved [`DocumentChains`](DocumentChain) are cached for the configured time-to-live. Resolving
+/// an expired entry only fetches and validates the messages published after the cached chain,
+/// instead of reconstructing the whole chain. When the cache is full, the least recently used
+/// entry is evicted.
+pub struct CachedResolver<C = Arc<Client>>
+where
+  C: SharedPtr<Client>,
+{
+  resolver: Resolver<C>,
+  ttl: Duration,
+  capacity: usize,
+  cache: Mutex<Cache>,
+}
+
+#[derive(Default)]
+struct Cache {
+  entries: HashMap<IotaDID, CacheEntry>,
+  accesses: u64,
+}
+
+struct CacheEntry {
+  chain: DocumentChain,
+  /// The ids of messages on the chain indexes which are not documents or diffs of the DID.
+  spam: HashSet<MessageId>,
+  refreshed: Instant,
+  last_access: u64,
+}
+
+impl<C> CachedResolver<C>
+where
+  C: SharedPtr<Client>,
+{
+  /// Creates a new [`CachedResolver`] over the given [`Resolver`], caching up to 1000 DID
+  /// Documents for 60 seconds.
+  pub fn new(resolver: Resolver<C>) -> Self {
+    Self {
+      resolver,
+      ttl: DEFAULT_TTL,
+      capacity: DEFAULT_CAPACITY,
+      cache: Mutex::new(Cache::default()),
+    }
+  }
+
+  /// Sets the duration for which a resolved DID Document is returned without checking the Tangle
+  /// for updates.
+  #[must_use]
+  pub fn ttl(mut self, ttl: Duration) -> Self {
+    self.ttl = ttl;
+    self
+  }
+
+  /// Sets the maximum number of cached DID Documents.
+  #[must_use]
+  pub fn capacity(mut self, capacity: usize) -> Self {
+    self.capacity = capacity;
+    self
+  }
+
+  /// Returns a reference to the underlying [`Resolver`].
+  pub fn resolver(&self) -> &Resolver<C> {
+    &self.resolver
+  }
+
+  /// Fetches the [`IotaDocument`](identity_iota_core::document::IotaDocument) of the given [`IotaDID`],
+  /// returning the cached DID Document if it has not expired.
+  pub async fn resolve(&self, did: &IotaDID) -> Result<ResolvedIotaDocument> {
+    let cached: Option<(DocumentChain, HashSet<MessageId>)> = {
+      let mut cache: MutexGuard<'_, Cache> = self.lock();
+      cache.accesses += 1;
+      let access: u64 = cache.accesses;
+
+      match cache.entries.get_mut(did) {
+        Some(entry) => {
+          entry.last_access = access;
+          if self.is_fresh(entry.refreshed) {
+            return Ok(entry.chain.current().clone());
+          }
+          Some((entry.chain.clone(), entry.spam.clone()))
+        }
+        None => None,
+      }
+    };
+
+    let client: &Client = self.resolver.get_client_for_did(did)?.deref();
+    let (chain, spam): (DocumentChain, HashSet<MessageId>) = match cached {
+      Some((mut chain, mut spam)) => {
+        chain.extend_from_tangle(client, &mut spam).await?;
+        (chain, spam)
+      }
+      None => (client.read_document_chain(did).await?, HashSet::new()),
+    };
+    let document: ResolvedIotaDocument = chain.current().clone();
+
+    self.insert(did.clone(), chain, spam);
+
+    Ok(document)
+  }
+
+  /// Removes the cached DID Document of the given [`IotaDID`], forcing it to be fully resolved
+  /// again.
+  ///
+  /// Returns `true` if the DID Document was cached.
+  pub fn invalidate(&self, did: &IotaDID) -> bool {
+    self.lock().entries.remove(did).is_some()
+  }
+
+  /// Removes all cached DID Documents.
+  pub fn clear(&self) {
+    self.lock().entries.clear();
+  }
+
+  /// Returns the number of cached DID Documents.
+  pub fn len(&self) -> usize {
+    self.lock().entries.len()
+  }
+
+  /// Returns `true` if no DID Documents are cached.
+  pub fn is_empty(&self) -> bool {
+    self.lock().entries.is_empty()
+  }
+
+  fn insert(&self, did: IotaDID, chain: DocumentChain, spam: HashSet<MessageId>) {
+    if self.capacity == 0 {
+      return;
+    }
+
+    let mut cache: MutexGuard<'_, Cache> = self.lock();
+    cache.accesses += 1;
+    let entry: CacheEntry = CacheEntry {
+      chain,
+      spam,
+      refreshed: Instant::now(),
+      last_access: cache.accesses,
+    };
+
+    if !cache.entries.contains_key(&did) && cache.entries.len() >= self.capacity {
+      let least_recently_used: Option<IotaDID> = cache
+        .entries
+        .iter()
+        .min_by_key(|(_, entry)| entry.last_access)
+        .map(|(did, _)| did.clone());
+      if let Some(evicted) = least_recently_used {
+        cache.entries.remove(&evicted);
+      }
+    }
+
+    cache.entries.insert(did, entry);
+  }
+
+  fn is_fresh(&self, refreshed: Instant) -> bool {
+    refreshed.elapsed() < self.ttl
+  }
+
+  fn lock(&self) -> MutexGuard<'_, Cache> {
+    self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+  }
+}
+
+impl<C> Debug for CachedResolver<C>
+where
+  C: SharedPtr<Client> + Debug,
+{
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("CachedResolver")
+      .field("resolver", &self.resolver)
+      .field("ttl", &self.ttl)
+      .field("capacity", &self.capacity)
+      .field("len", &self.len())
+      .finish()
+  }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<C> TangleResolve for CachedResolver<C>
+where
+  C: SharedPtr<Client>,
+{
+  async fn resolve(&self, did: &IotaDID) -> Result<ResolvedIotaDocument> {
+    self.resolve(did).await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::atomic::Ordering;
+
+  use identity_iota_core::document::IotaDocument;
+
+  use super::*;
+  use crate::tangle::ClientBuilder;
+  use crate::test_utils::CountingTangle;
+  use crate::test_utils::Identity;
+
+  async fn setup() -> (Arc<CountingTangle>, Arc<Client>) {
+    let tangle: Arc<CountingTangle> = Arc::new(CountingTangle::default());
+    let client: Client = ClientBuilder::new().backend(Arc::clone(&tangle)).build().await.unwrap();
+    (tangle, Arc::new(client))
+  }
+
+  async fn build_resolver(client: &Arc<Client>) -> Resolver {
+    Resolver::builder().client(Arc::clone(client)).build().await.unwrap()
+  }
+
+  #[tokio::test]
+  async fn test_cached_resolver_ttl_and_invalidation() {
+    let (tangle, client) = setup().await;
+    let mut identity: Identity = Identity::publish(&client).await;
+    let did: IotaDID = identity.document.id().clone();
+
+    let resolver: CachedResolver = CachedResolver::new(build_resolver(&client).await).ttl(Duration::from_secs(3600));
+    assert!(resolver.is_empty());
+    assert_eq!(resolver.resolve(&did).await.unwrap().document, identity.document);
+    let reads: usize = tangle.reads.load(Ordering::SeqCst);
+
+    // Fresh entries do not touch the Tangle.
+    let original: IotaDocument = identity.document.clone();
+    identity.publish_integration(&client, "linked-domain").await;
+    assert_eq!(resolver.resolve(&did).await.unwrap().document, original);
+    assert_eq!(tangle.reads.load(Ordering::SeqCst), reads);
+    assert_eq!(resolver.len(), 1);
+
+    // Invalidated entries are fully resolved again.
+    assert!(resolver.invalidate(&did));
+    assert!(!resolver.invalidate(&did));
+    assert_eq!(resolver.resolve(&did).await.unwrap().document, identity.document);
+
+    resolver.clear();
+    assert!(resolver.is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_cached_resolver_extends_expired_chain() {
+    let (tangle, client) = setup().await;
+    let mut identity: Identity = Identity::publish(&client).await;
+    let did: IotaDID = identity.document.id().clone();
+
+    let resolver: CachedResolver = CachedResolver::new(build_resolver(&client).await).ttl(Duration::ZERO);
+    assert_eq!(resolver.resolve(&did).await.unwrap().document, identity.document);
+
+    // Diff updates are appended to the cached chain, downloading only the new message.
+    let updated: IotaDocument = identity.publish_diff(&client, "diff").await;
+    let downloads: usize = tangle.downloads.load(Ordering::SeqCst);
+    let resolved: ResolvedIotaDocument = resolver.resolve(&did).await.unwrap();
+    assert_eq!(tangle.downloads.load(Ordering::SeqCst), downloads + 1);
+    assert_eq!(resolved.document.service(), updated.service());
+    assert_eq!(resolved, client.read_document(&did).await.unwrap());
+
+    // Integration updates replace the cached diff chain.
+    identity.publish_integration(&client, "integration").await;
+    let downloads: usize = tangle.downloads.load(Ordering::SeqCst);
+    let resolved: ResolvedIotaDocument = resolver.resolve(&did).await.unwrap();
+    assert_eq!(tangle.downloads.load(Ordering::SeqCst), downloads + 1);
+    assert_eq!(resolved.document, identity.document);
+    assert_eq!(resolved.integration_message_id, identity.message_id);
+
+    // Spam on the index does not break the cached chain, and is only downloaded once.
+    tangle.tangle.insert(did.tag(), b"spam", Some(1)).unwrap();
+    let downloads: usize = tangle.downloads.load(Ordering::SeqCst);
+    assert_eq!(resolver.resolve(&did).await.unwrap().document, identity.document);
+    assert_eq!(tangle.downloads.load(Ordering::SeqCst), downloads + 1);
+    assert_eq!(resolver.resolve(&did).await.unwrap().document, identity.document);
+    assert_eq!(tangle.downloads.load(Ordering::SeqCst), downloads + 1);
+  }
+
+  #[tokio::test]
+  async fn test_cached_resolver_capacity() {
+    let (_, client) = setup().await;
+    let first: Identity = Identity::publish(&client).await;
+    let second: Identity = Identity::publish(&client).await;
+
+    let resolver: CachedResolver = CachedResolver::new(build_resolver(&client).await).capacity(1);
+    resolver.resolve(first.document.id()).await.unwrap();
+    resolver.resolve(second.document.id()).await.unwrap();
+    assert_eq!(resolver.len(), 1);
+    assert!(!resolver.invalidate(first.document.id()));
+    assert!(resolver.invalidate(second.document.id()));
+
+    let resolver: CachedResolver = CachedResolver::new(build_resolver(&client).await).capacity(0);
+    resolver.resolve(first.document.id()).await.unwrap();
+    assert!(resolver.is_empty());
+  }
+}
@@ -1,37 +1,41 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use bee_rest_api::types::dtos::LedgerInclusionStateDto;
-use futures::stream::FuturesUnordered;
-use futures::stream::TryStreamExt;
-use identity_core::convert::ToJson;
+use std::collections::HashSet;
+
+use async_trait::async_trait;
 use identity_iota_core::did::IotaDID;
 use identity_iota_core::diff::DiffMessage;
 use identity_iota_core::document::IotaDocument;
 use identity_iota_core::tangle::Message;
 use identity_iota_core::tangle::MessageId;
 use identity_iota_core::tangle::Network;
-use iota_client::Client as IotaClient;
-use iota_client::Error as IotaClientError;
+use serde::Serialize;
 
 use crate::chain::ChainHistory;
 use crate::chain::DiffChain;
 use crate::chain::DocumentChain;
 use crate::chain::DocumentHistory;
+use crate::chain::DocumentVersion;
 use crate::chain::IntegrationChain;
 use crate::document::ResolvedIotaDocument;
 use crate::error::Error;
 use crate::error::Result;
 use crate::tangle::ClientBuilder;
 use crate::tangle::DIDMessageEncoding;
+use crate::tangle::MessageExt;
 use crate::tangle::Receipt;
+use crate::tangle::TangleBackend;
 use crate::tangle::TangleRef;
 use crate::tangle::TangleResolve;
 
 /// Client for performing IOTA Identity operations on the Tangle.
+///
+/// Messages are published and read through a [`TangleBackend`], which connects to IOTA nodes unless
+/// configured otherwise with [`ClientBuilder::backend`].
 #[derive(Debug)]
 pub struct Client {
-  pub(crate) client: IotaClient,
+  pub(crate) backend: Box<dyn TangleBackend>,
   pub(crate) network: Network,
   pub(crate) encoding: DIDMessageEncoding,
 }
@@ -51,6 +55,14 @@ impl Client {
 
   /// Creates a new [`Client`] based on the [`ClientBuilder`] configuration.
   pub async fn from_builder(builder: ClientBuilder) -> Result<Self> {
+    if let Some(backend) = builder.backend {
+      return Ok(Self {
+        backend,
+        network: builder.network,
+        encoding: builder.encoding,
+      });
+    }
+
     let mut client: iota_client::ClientBuilder = builder.builder;
 
     if !builder.nodeset {
@@ -62,7 +74,7 @@ impl Client {
     }
 
     Ok(Self {
-      client: client.finish().await?,
+      backend: Box::new(client.finish().await?),
       network: builder.network,
       encoding: builder.encoding,
     })
@@ -73,6 +85,17 @@ impl Client {
     self.network.clone()
   }
 
+  /// Returns the [`DIDMessageEncoding`] that the [`Client`] uses to publish messages.
+  pub fn encoding(&self) -> DIDMessageEncoding {
+    self.encoding
+  }
+
+  /// Returns the size in bytes of the message payload that `data` would be published as, using the
+  /// [`DIDMessageEncoding`] of the client.
+  pub fn message_size<T: Serialize>(&self, data: &T) -> Result<usize> {
+    crate::tangle::pack_did_message(data, self.encoding).map(|message_data| message_data.len())
+  }
+
   /// Publishes an [`IotaDocument`] to the Tangle.
   /// This method calls `publish_json_with_retry` with its default `interval` and `max_attempts` values for increasing
   /// the probability that the message will be referenced by a milestone.
@@ -110,24 +133,21 @@ impl Client {
       .await
   }
 
-  /// Compresses and publishes arbitrary JSON data to the specified index on the Tangle.
-  pub async fn publish_json<T: ToJson>(&self, index: &str, data: &T) -> Result<Receipt> {
+  /// Encodes and publishes arbitrary data to the specified index on the Tangle, using the
+  /// [`DIDMessageEncoding`] of the client.
+  pub async fn publish_json<T: Serialize>(&self, index: &str, data: &T) -> Result<Receipt> {
     let message_data: Vec<u8> = crate::tangle::pack_did_message(data, self.encoding)?;
     self
-      .client
-      .message()
-      .with_index(index)
-      .with_data(message_data)
-      .finish()
+      .backend
+      .publish_message(index, &message_data)
       .await
-      .map_err(Into::into)
       .map(|message| Receipt::new(self.network.clone(), message))
   }
 
   /// Publishes arbitrary JSON data to the specified index on the Tangle.
   /// Retries (promotes or reattaches) the message until it’s included (referenced by a milestone).
   /// Default interval is 5 seconds and max attempts is 40.
-  pub async fn publish_json_with_retry<T: ToJson>(
+  pub async fn publish_json_with_retry<T: Serialize>(
     &self,
     index: &str,
     data: &T,
@@ -135,29 +155,11 @@ impl Client {
     max_attempts: Option<u64>,
   ) -> Result<Receipt> {
     let receipt: Receipt = self.publish_json(index, data).await?;
-    let retry_result: Result<Vec<(MessageId, Message)>, IotaClientError> = self
-      .client
+    let message: Message = self
+      .backend
       .retry_until_included(receipt.message_id(), interval, max_attempts)
-      .await;
-    let reattached_messages: Vec<(MessageId, Message)> = match retry_result {
-      Ok(reattached_messages) => reattached_messages,
-      Err(inclusion_error @ IotaClientError::TangleInclusionError(_)) => {
-        if self.is_message_included(receipt.message_id()).await? {
-          return Ok(receipt);
-        } else {
-          return Err(Error::from(inclusion_error));
-        }
-      }
-      Err(error) => {
-        return Err(Error::from(error));
-      }
-    };
-    match reattached_messages.into_iter().next() {
-      Some((_, message)) => Ok(Receipt::new(self.network.clone(), message)),
-      None => Err(Error::from(IotaClientError::TangleInclusionError(
-        receipt.message_id().to_string(),
-      ))),
-    }
+      .await?;
+    Ok(Receipt::new(self.network.clone(), message))
   }
 
   /// Fetch the [`IotaDocument`] specified by the given [`IotaDID`].
@@ -168,13 +170,7 @@ impl Client {
   /// Fetches a [`DocumentChain`] given an [`IotaDID`].
   pub async fn read_document_chain(&self, did: &IotaDID) -> Result<DocumentChain> {
     log::trace!("Read Document Chain: {}", did);
-    if did.network_str() != self.network.name_str() {
-      return Err(Error::DIDNotFound(format!(
-        "DID network '{}' does not match client network '{}'",
-        did.network_str(),
-        self.network.name_str()
-      )));
-    }
+    self.check_network(did)?;
 
     // Fetch all messages for the integration chain.
     log::trace!("Integration Chain Address: {}", did.tag());
@@ -210,11 +206,122 @@ impl Client {
     DocumentChain::new_with_diff_chain(integration_chain, diff_chain)
   }
 
+  /// Fetches the messages published after the state of the given [`DocumentChain`] and appends the
+  /// valid ones to it.
+  ///
+  /// Only the ids of the messages on the chain indexes are fetched, and only the messages which are
+  /// not already part of the chain are downloaded with [`TangleBackend::read_message`]. Only
+  /// integration messages continuing the chain and the diff chain of the latest integration
+  /// message are validated, which is considerably cheaper than [`Client::read_document_chain`]
+  /// for DID Documents with a long history.
+  pub async fn update_document_chain(&self, chain: &mut DocumentChain) -> Result<()> {
+    log::trace!("Update Document Chain: {}", chain.id());
+    self.check_network(chain.id())?;
+
+    chain.extend_from_tangle(self, &mut HashSet::new()).await
+  }
+
+  /// Fetches the [`DocumentChain`] of the given [`IotaDID`] starting from a previously resolved
+  /// integration message, such as the last integration message id tracked by an account's chain
+  /// state.
+  ///
+  /// Only the signature of the integration message is verified, against the previous integration
+  /// document or, for the root document, against itself. The history before it is not validated
+  /// and not part of the returned chain. The following messages are downloaded as in
+  /// [`Client::update_document_chain`].
+  ///
+  /// NOTE: the integration message is not checked to be part of the chain resolved by
+  /// [`Client::read_document_chain`]. If it lost a conflict against another message referencing the
+  /// same previous message, e.g. one referenced by an earlier milestone, the returned chain continues
+  /// from the losing branch. Only start from messages previously resolved as part of the chain.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::DIDNotFound`] if the message is not an integration message of the DID, and
+  /// [`Error::ChainError`] if its signature is invalid.
+  pub async fn read_document_chain_from(
+    &self,
+    did: &IotaDID,
+    integration_message_id: &MessageId,
+  ) -> Result<DocumentChain> {
+    log::trace!("Read Document Chain: {} from {}", did, integration_message_id);
+    self.check_network(did)?;
+
+    let message: Message = self.read_message(integration_message_id).await?;
+    let document: ResolvedIotaDocument = message
+      .try_extract_document(did)
+      .filter(|document| document.integration_message_id == *integration_message_id)
+      .ok_or_else(|| {
+        Error::DIDNotFound(format!(
+          "message '{}' is not an integration message of '{}'",
+          integration_message_id, did
+        ))
+      })?;
+
+    // Integration documents are signed by a method of the previous integration document.
+    let verified: bool = if document.previous_message_id().is_null() {
+      IotaDocument::verify_root_document(&document.document).is_ok()
+    } else {
+      let previous: Message = self.read_message(document.previous_message_id()).await?;
+      previous.try_extract_document(did).map_or(false, |previous| {
+        previous.document.verify_document(&document.document).is_ok()
+      })
+    };
+    if !verified {
+      return Err(Error::ChainError {
+        error: "Invalid Signature",
+      });
+    }
+
+    let mut chain: DocumentChain = DocumentChain::new(IntegrationChain::new_unchecked(document));
+    chain.extend_from_tangle(self, &mut HashSet::new()).await?;
+    Ok(chain)
+  }
+
   /// Returns the [`DocumentHistory`] of the given [`IotaDID`].
   pub async fn resolve_history(&self, did: &IotaDID) -> Result<DocumentHistory> {
     DocumentHistory::read(self, did).await
   }
 
+  /// Fetches the state of the DID Document identified by the given [`IotaDID`] at a historical
+  /// [`DocumentVersion`].
+  ///
+  /// This allows verifying signatures, e.g. on old credentials, against the verification methods of
+  /// the DID Document at the time of signing.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::DIDNotFound`] if the version is not part of the history of the DID Document.
+  pub async fn resolve_version(&self, did: &IotaDID, version: &DocumentVersion) -> Result<ResolvedIotaDocument> {
+    log::trace!("Resolve Document Version: {} {}", did, version);
+    self.check_network(did)?;
+
+    let messages: Vec<Message> = self.read_messages(did.tag()).await?;
+    let integration_chain: IntegrationChain = IntegrationChain::try_from_messages(did, &messages, self).await?;
+    let documents: Vec<ResolvedIotaDocument> = Vec::from(integration_chain);
+
+    // Avoid fetching diff chains when the version is an integration message.
+    if let DocumentVersion::MessageId(message_id) = version {
+      if let Some(document) = documents.iter().find(|document| document.message_id() == message_id) {
+        return Ok(document.clone());
+      }
+    }
+
+    // Search the most recent integration documents and their diff chains first.
+    for document in version.candidates(&documents) {
+      let diffs: ChainHistory<DiffMessage> = self.resolve_diff_history(document).await?;
+
+      if let Some(resolved) = version.select(document, &diffs.chain_data)? {
+        return Ok(resolved);
+      }
+    }
+
+    Err(Error::DIDNotFound(format!(
+      "DID Document of '{}' has no version with {}",
+      did, version
+    )))
+  }
+
   /// Returns the [`ChainHistory`] of a diff chain starting from an [`IotaDocument`] on the
   /// integration chain.
   ///
@@ -226,54 +333,225 @@ impl Client {
     ChainHistory::try_from_raw_messages(document, &diff_messages, self).await
   }
 
-  /// Fetch all [`Messages`][Message] from the given index on the IOTA Tangle.
-  pub(crate) async fn read_messages(&self, index: &str) -> Result<Vec<Message>> {
-    let message_ids: Box<[MessageId]> = Self::read_message_index(&self.client, index).await?;
-    let messages: Vec<Message> = Self::read_message_data(&self.client, &message_ids).await?;
+  /// Checks that the given [`IotaDID`] belongs to the network of the client.
+  fn check_network(&self, did: &IotaDID) -> Result<()> {
+    if did.network_str() != self.network.name_str() {
+      return Err(Error::DIDNotFound(format!(
+        "DID network '{}' does not match client network '{}'",
+        did.network_str(),
+        self.network.name_str()
+      )));
+    }
 
-    Ok(messages)
+    Ok(())
   }
+}
 
-  /// Read the [`MessageIds`][MessageId] from the given index on the IOTA Tangle.
-  ///
-  /// NOTE: the number of returned [`MessageIds`][MessageId] is capped at 1000 due to limitations of
-  ///       the current API, with no ordering guarantees.
-  async fn read_message_index(client: &IotaClient, index: &str) -> Result<Box<[MessageId]>> {
-    client.get_message().index(index).await.map_err(Into::into)
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl TangleBackend for Client {
+  async fn publish_message(&self, index: &str, data: &[u8]) -> Result<Message> {
+    self.backend.publish_message(index, data).await
   }
 
-  /// Fetch the associated [`Messages`][Message] given a list of [MessageIds][`MessageId`] from
-  /// the Tangle.
-  async fn read_message_data(client: &IotaClient, messages: &[MessageId]) -> Result<Vec<Message>> {
-    messages
-      .iter()
-      .map(|message_id| client.get_message().data(message_id))
-      .collect::<FuturesUnordered<_>>()
-      .try_collect()
+  async fn retry_until_included(
+    &self,
+    message_id: &MessageId,
+    interval: Option<u64>,
+    max_attempts: Option<u64>,
+  ) -> Result<Message> {
+    self
+      .backend
+      .retry_until_included(message_id, interval, max_attempts)
       .await
-      .map_err(Into::into)
   }
 
-  async fn is_message_included(&self, message_id: &MessageId) -> Result<bool> {
-    match self
-      .client
-      .get_message()
-      .metadata(message_id)
-      .await?
-      .ledger_inclusion_state
-    {
-      Some(ledger_inclusion_state) => match ledger_inclusion_state {
-        LedgerInclusionStateDto::Included | LedgerInclusionStateDto::NoTransaction => Ok(true),
-        LedgerInclusionStateDto::Conflicting => Ok(false),
-      },
-      None => Ok(false),
-    }
+  async fn read_message(&self, message_id: &MessageId) -> Result<Message> {
+    self.backend.read_message(message_id).await
+  }
+
+  async fn read_messages(&self, index: &str) -> Result<Vec<Message>> {
+    self.backend.read_messages(index).await
+  }
+
+  async fn read_message_ids(&self, index: &str) -> Result<Vec<MessageId>> {
+    self.backend.read_message_ids(index).await
+  }
+
+  async fn milestone_index(&self, message_id: &MessageId) -> Result<Option<u32>> {
+    self.backend.milestone_index(message_id).await
   }
 }
 
-#[async_trait::async_trait(?Send)]
+#[async_trait(?Send)]
 impl TangleResolve for Client {
   async fn resolve(&self, did: &IotaDID) -> Result<ResolvedIotaDocument> {
     self.read_document(did).await
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use std::sync::atomic::Ordering;
+  use std::sync::Arc;
+
+  use identity_core::crypto::KeyPair;
+  use identity_core::crypto::KeyType;
+  use iota_client::bee_message::payload::Payload;
+
+  use super::*;
+  use crate::tangle::MemoryTangle;
+  use crate::test_utils::add_service;
+  use crate::test_utils::generate_document;
+  use crate::test_utils::sign;
+  use crate::test_utils::CountingTangle;
+
+  /// Publishes an integration update of `document` adding a service.
+  async fn publish_integration(
+    client: &Client,
+    document: &IotaDocument,
+    message_id: &MessageId,
+    keypair: &KeyPair,
+    fragment: &str,
+  ) -> (IotaDocument, MessageId) {
+    let mut updated: IotaDocument = add_service(document, fragment);
+    updated.metadata.previous_message_id = *message_id;
+    sign(&mut updated, keypair);
+    let receipt: Receipt = client.publish_document(&updated).await.unwrap();
+    (updated, *receipt.message_id())
+  }
+
+  #[tokio::test]
+  async fn test_update_document_chain() {
+    let tangle: Arc<CountingTangle> = Arc::new(CountingTangle::default());
+    let client: Client = Client::builder().backend(Arc::clone(&tangle)).build().await.unwrap();
+
+    let (document, keypair): (IotaDocument, KeyPair) = generate_document();
+    let message_id: MessageId = *client.publish_document(&document).await.unwrap().message_id();
+    let did: IotaDID = document.id().clone();
+
+    // Messages which are already part of the chain are not downloaded again.
+    let mut chain: DocumentChain = client.read_document_chain(&did).await.unwrap();
+    let downloads: usize = tangle.downloads.load(Ordering::SeqCst);
+    client.update_document_chain(&mut chain).await.unwrap();
+    assert_eq!(tangle.downloads.load(Ordering::SeqCst), downloads);
+    assert_eq!(chain.current().document, document);
+
+    // Integration updates.
+    let (document, message_id) = publish_integration(&client, &document, &message_id, &keypair, "one").await;
+    let (document, message_id) = publish_integration(&client, &document, &message_id, &keypair, "two").await;
+    let downloads: usize = tangle.downloads.load(Ordering::SeqCst);
+    client.update_document_chain(&mut chain).await.unwrap();
+    assert_eq!(tangle.downloads.load(Ordering::SeqCst), downloads + 2);
+    assert_eq!(chain.current().document, document);
+    assert_eq!(chain.integration_message_id(), &message_id);
+    assert_eq!(chain.integration_chain().history().unwrap().len(), 2);
+
+    // Diff updates, followed by spam.
+    let updated: IotaDocument = add_service(&document, "diff");
+    let diff: DiffMessage = document
+      .diff(
+        &updated,
+        message_id,
+        keypair.private(),
+        document.default_signing_method().unwrap().id(),
+      )
+      .unwrap();
+    let diff_message_id: MessageId = *client.publish_diff(&message_id, &diff).await.unwrap().message_id();
+    tangle
+      .tangle
+      .insert(&IotaDocument::diff_index(&message_id).unwrap(), b"spam", Some(1))
+      .unwrap();
+    let downloads: usize = tangle.downloads.load(Ordering::SeqCst);
+    client.update_document_chain(&mut chain).await.unwrap();
+    assert_eq!(tangle.downloads.load(Ordering::SeqCst), downloads + 2);
+    assert_eq!(chain.current().document.service(), updated.service());
+    assert_eq!(chain.diff_message_id(), &diff_message_id);
+    assert_eq!(
+      chain.current(),
+      &client.read_document_chain(&did).await.unwrap().fold().unwrap()
+    );
+  }
+
+  #[tokio::test]
+  async fn test_message_size() {
+    let (document, _): (IotaDocument, KeyPair) = generate_document();
+
+    for encoding in [
+      DIDMessageEncoding::Json,
+      DIDMessageEncoding::JsonBrotli,
+      DIDMessageEncoding::Cbor,
+      DIDMessageEncoding::CborZstd,
+    ] {
+      let tangle: Arc<MemoryTangle> = Arc::new(MemoryTangle::new());
+      let client: Client = Client::builder()
+        .backend(Arc::clone(&tangle))
+        .encoding(encoding)
+        .build()
+        .await
+        .unwrap();
+      assert_eq!(client.encoding(), encoding);
+
+      let receipt: Receipt = client.publish_document(&document).await.unwrap();
+      let message: Message = client.backend.read_message(receipt.message_id()).await.unwrap();
+      let data_len: usize = match message.payload() {
+        Some(Payload::Indexation(payload)) => payload.data().len(),
+        _ => panic!("expected an indexation payload"),
+      };
+      assert_eq!(client.message_size(&document).unwrap(), data_len);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_read_document_chain_from() {
+    let tangle: Arc<CountingTangle> = Arc::new(CountingTangle::default());
+    let client: Client = Client::builder().backend(Arc::clone(&tangle)).build().await.unwrap();
+
+    let (document, keypair): (IotaDocument, KeyPair) = generate_document();
+    let root_message_id: MessageId = *client.publish_document(&document).await.unwrap().message_id();
+    let did: IotaDID = document.id().clone();
+
+    let (document, known_message_id) = publish_integration(&client, &document, &root_message_id, &keypair, "one").await;
+    let (document, message_id) = publish_integration(&client, &document, &known_message_id, &keypair, "two").await;
+
+    // Only the updates after the known message are part of the chain. The known message and its
+    // previous message, which signed it, are downloaded along with the unknown messages on the index.
+    let downloads: usize = tangle.downloads.load(Ordering::SeqCst);
+    let chain: DocumentChain = client.read_document_chain_from(&did, &known_message_id).await.unwrap();
+    assert_eq!(tangle.downloads.load(Ordering::SeqCst), downloads + 4);
+    assert_eq!(chain.current().document, document);
+    assert_eq!(chain.integration_message_id(), &message_id);
+    assert_eq!(chain.integration_chain().history().unwrap().len(), 1);
+
+    // The known message must be an integration message of the DID.
+    let spam: MessageId = tangle.tangle.insert(did.tag(), b"spam", Some(1)).unwrap().id().0;
+    assert!(matches!(
+      client.read_document_chain_from(&did, &spam).await,
+      Err(Error::DIDNotFound(_))
+    ));
+    let other: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
+    let other: IotaDID = IotaDID::new(other.public().as_ref()).unwrap();
+    assert!(matches!(
+      client.read_document_chain_from(&other, &root_message_id).await,
+      Err(Error::DIDNotFound(_))
+    ));
+    assert!(client
+      .read_document_chain_from(&did, &MessageId::new([0xff; 32]))
+      .await
+      .is_err());
+
+    // The known message must be signed by the previous integration document.
+    let (_, forged) = publish_integration(
+      &client,
+      &document,
+      &message_id,
+      &KeyPair::new(KeyType::Ed25519).unwrap(),
+      "forged",
+    )
+    .await;
+    assert!(matches!(
+      client.read_document_chain_from(&did, &forged).await,
+      Err(Error::ChainError { .. })
+    ));
+  }
+}
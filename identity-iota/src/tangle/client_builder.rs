@@ -10,6 +10,7 @@ use identity_iota_core::tangle::Network;
 use crate::error::Result;
 use crate::tangle::Client;
 use crate::tangle::DIDMessageEncoding;
+use crate::tangle::TangleBackend;
 
 const DEFAULT_LOCAL_POW: bool = false;
 
@@ -19,6 +20,7 @@ pub struct ClientBuilder {
   pub(super) network: Network,
   pub(super) builder: iota_client::ClientBuilder,
   pub(super) encoding: DIDMessageEncoding,
+  pub(super) backend: Option<Box<dyn TangleBackend>>,
 }
 
 impl ClientBuilder {
@@ -29,6 +31,7 @@ impl ClientBuilder {
       network: Default::default(),
       builder: iota_client::ClientBuilder::new().with_local_pow(DEFAULT_LOCAL_POW),
       encoding: DIDMessageEncoding::JsonBrotli,
+      backend: None,
     }
   }
 
@@ -41,12 +44,24 @@ impl ClientBuilder {
   }
 
   /// Sets the DID message encoding used when publishing to the Tangle.
+  ///
+  /// Messages are decoded regardless of their encoding when reading from the Tangle.
   #[must_use]
   pub fn encoding(mut self, encoding: DIDMessageEncoding) -> Self {
     self.encoding = encoding;
     self
   }
 
+  /// Sets the [`TangleBackend`] used to publish and read messages, such as a
+  /// [`MemoryTangle`](crate::tangle::MemoryTangle).
+  ///
+  /// When set, the configured IOTA nodes are not used.
+  #[must_use]
+  pub fn backend<B: TangleBackend + 'static>(mut self, backend: B) -> Self {
+    self.backend = Some(Box::new(backend));
+    self
+  }
+
   /// Adds an IOTA node by its URL.
   pub fn node(mut self, url: &str) -> Result<Self> {
     self.builder = self.builder.with_node(url)?;
@@ -0,0 +1,338 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+use std::sync::RwLockReadGuard;
+use std::sync::RwLockWriteGuard;
+
+use async_trait::async_trait;
+use futures::channel::mpsc;
+use futures::channel::mpsc::UnboundedSender;
+use futures::stream::BoxStream;
+use futures::stream::StreamExt;
+use identity_iota_core::tangle::Message;
+use identity_iota_core::tangle::MessageId;
+use iota_client::bee_message::prelude::IndexationPayload;
+use iota_client::bee_message::prelude::MessageBuilder;
+use iota_client::bee_message::prelude::Parents;
+use iota_client::bee_message::prelude::Payload;
+use iota_client::Error as IotaClientError;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::tangle::TangleBackend;
+use crate::tangle::TangleSubscriber;
+
+/// An in-memory [`TangleBackend`] for testing publishing and resolution without an IOTA node.
+///
+/// Published messages are immediately referenced by a new milestone, in order of publication.
+/// Arbitrary data, such as spam or invalid DID messages, can be inserted with [`MemoryTangle::insert`]
+/// and the milestones referencing messages can be overridden with [`MemoryTangle::set_milestone_index`].
+///
+/// Subscribers registered through [`TangleSubscriber`] receive every message inserted afterwards.
+#[derive(Debug, Default)]
+pub struct MemoryTangle {
+  state: RwLock<MemoryState>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryState {
+  indexes: HashMap<String, Vec<MessageId>>,
+  messages: HashMap<MessageId, Message>,
+  milestones: HashMap<MessageId, Option<u32>>,
+  last_milestone_index: u32,
+  last_message_id: Option<MessageId>,
+  subscribers: HashMap<String, Vec<UnboundedSender<Message>>>,
+}
+
+impl MemoryTangle {
+  /// Creates a new, empty [`MemoryTangle`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Inserts a message with the given `data` on the given `index`, which will be referenced by the
+  /// milestone with the given `milestone_index`, or by no milestone if `None`.
+  ///
+  /// The data is stored as-is, which allows publishing spam or malformed DID messages.
+  pub fn insert(&self, index: &str, data: &[u8], milestone_index: Option<u32>) -> Result<Message> {
+    let mut state: RwLockWriteGuard<'_, MemoryState> = self.write();
+
+    let parent: MessageId = state.last_message_id.unwrap_or_else(MessageId::null);
+    let nonce: u64 = state.messages.len() as u64;
+    let message: Message = MessageBuilder::<u64>::new()
+      .with_network_id(0)
+      .with_parents(Parents::new(vec![parent]).map_err(IotaClientError::from)?)
+      .with_payload(Payload::Indexation(Box::new(
+        IndexationPayload::new(index.as_bytes(), data).map_err(IotaClientError::from)?,
+      )))
+      .with_nonce_provider(nonce, 0f64)
+      .finish()
+      .map_err(IotaClientError::from)?;
+    let message_id: MessageId = message.id().0;
+
+    state.indexes.entry(index.to_owned()).or_default().push(message_id);
+    state.messages.insert(message_id, message.clone());
+    state.milestones.insert(message_id, milestone_index);
+    state.last_message_id = Some(message_id);
+    if let Some(milestone_index) = milestone_index {
+      state.last_milestone_index = state.last_milestone_index.max(milestone_index);
+    }
+    if let Some(subscribers) = state.subscribers.get_mut(index) {
+      // Drop the subscribers whose streams were closed.
+      subscribers.retain(|subscriber| subscriber.unbounded_send(message.clone()).is_ok());
+    }
+
+    Ok(message)
+  }
+
+  /// Sets the index of the milestone referencing the message identified by `message_id`, or marks it
+  /// as not referenced by any milestone if `None`.
+  ///
+  /// Returns `false` if no such message exists.
+  pub fn set_milestone_index(&self, message_id: &MessageId, milestone_index: Option<u32>) -> bool {
+    match self.write().milestones.get_mut(message_id) {
+      Some(milestone) => {
+        *milestone = milestone_index;
+        true
+      }
+      None => false,
+    }
+  }
+
+  /// Returns the message identified by `message_id`, if any.
+  pub fn message(&self, message_id: &MessageId) -> Option<Message> {
+    self.read().messages.get(message_id).cloned()
+  }
+
+  /// Returns the number of messages on the ledger.
+  pub fn len(&self) -> usize {
+    self.read().messages.len()
+  }
+
+  /// Returns `true` if the ledger contains no messages.
+  pub fn is_empty(&self) -> bool {
+    self.read().messages.is_empty()
+  }
+
+  fn read(&self) -> RwLockReadGuard<'_, MemoryState> {
+    self.state.read().unwrap_or_else(PoisonError::into_inner)
+  }
+
+  fn write(&self) -> RwLockWriteGuard<'_, MemoryState> {
+    self.state.write().unwrap_or_else(PoisonError::into_inner)
+  }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl TangleBackend for MemoryTangle {
+  async fn publish_message(&self, index: &str, data: &[u8]) -> Result<Message> {
+    let milestone_index: u32 = self.read().last_milestone_index + 1;
+    self.insert(index, data, Some(milestone_index))
+  }
+
+  async fn retry_until_included(
+    &self,
+    message_id: &MessageId,
+    _interval: Option<u64>,
+    _max_attempts: Option<u64>,
+  ) -> Result<Message> {
+    let state: RwLockReadGuard<'_, MemoryState> = self.read();
+
+    match (state.messages.get(message_id), state.milestones.get(message_id)) {
+      (Some(message), Some(Some(_))) => Ok(message.clone()),
+      _ => Err(Error::from(IotaClientError::TangleInclusionError(
+        message_id.to_string(),
+      ))),
+    }
+  }
+
+  async fn read_message(&self, message_id: &MessageId) -> Result<Message> {
+    self.message(message_id).ok_or_else(|| not_found(message_id))
+  }
+
+  async fn read_messages(&self, index: &str) -> Result<Vec<Message>> {
+    let state: RwLockReadGuard<'_, MemoryState> = self.read();
+
+    Ok(
+      state
+        .indexes
+        .get(index)
+        .into_iter()
+        .flatten()
+        .filter_map(|message_id| state.messages.get(message_id))
+        .cloned()
+        .collect(),
+    )
+  }
+
+  async fn read_message_ids(&self, index: &str) -> Result<Vec<MessageId>> {
+    Ok(self.read().indexes.get(index).cloned().unwrap_or_default())
+  }
+
+  async fn milestone_index(&self, message_id: &MessageId) -> Result<Option<u32>> {
+    self
+      .read()
+      .milestones
+      .get(message_id)
+      .copied()
+      .ok_or_else(|| not_found(message_id))
+  }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl TangleSubscriber for MemoryTangle {
+  async fn subscribe(&self, index: &str) -> Result<BoxStream<'static, Message>> {
+    let (sender, receiver) = mpsc::unbounded();
+    self
+      .write()
+      .subscribers
+      .entry(index.to_owned())
+      .or_default()
+      .push(sender);
+    Ok(receiver.boxed())
+  }
+}
+
+/// Returns the error a node responds with for an unknown message.
+fn not_found(message_id: &MessageId) -> Error {
+  Error::from(IotaClientError::ResponseError(
+    404,
+    format!("message {} not found", message_id),
+  ))
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use identity_iota_core::did::IotaDID;
+  use identity_iota_core::document::IotaDocument;
+
+  use super::*;
+  use crate::chain::DocumentHistory;
+  use crate::document::ResolvedIotaDocument;
+  use crate::tangle::pack_did_message;
+  use crate::tangle::Client;
+  use crate::tangle::ClientBuilder;
+  use crate::tangle::DIDMessageEncoding;
+  use crate::tangle::Receipt;
+  use crate::test_utils::add_service;
+  use crate::test_utils::generate_document;
+  use crate::test_utils::sign;
+
+  async fn client(tangle: &Arc<MemoryTangle>) -> Client {
+    ClientBuilder::new().backend(Arc::clone(tangle)).build().await.unwrap()
+  }
+
+  fn insert_document(tangle: &MemoryTangle, document: &IotaDocument, milestone_index: Option<u32>) -> MessageId {
+    let data: Vec<u8> = pack_did_message(document, DIDMessageEncoding::JsonBrotli).unwrap();
+    tangle
+      .insert(document.integration_index(), &data, milestone_index)
+      .unwrap()
+      .id()
+      .0
+  }
+
+  #[tokio::test]
+  async fn test_publish_and_read_document() {
+    let tangle: Arc<MemoryTangle> = Arc::new(MemoryTangle::new());
+    let client: Client = client(&tangle).await;
+
+    let (document, keypair) = generate_document();
+    let receipt: Receipt = client.publish_document(&document).await.unwrap();
+    assert_eq!(tangle.len(), 1);
+    assert!(tangle.message(receipt.message_id()).is_some());
+
+    let resolved: ResolvedIotaDocument = client.read_document(document.id()).await.unwrap();
+    assert_eq!(resolved.document, document);
+    assert_eq!(&resolved.integration_message_id, receipt.message_id());
+
+    // Publish an integration update.
+    let mut document: IotaDocument = add_service(&document, "linked-domain");
+    document.metadata.previous_message_id = *receipt.message_id();
+    sign(&mut document, &keypair);
+    let receipt: Receipt = client.publish_document(&document).await.unwrap();
+
+    let resolved: ResolvedIotaDocument = client.read_document(document.id()).await.unwrap();
+    assert_eq!(resolved.document, document);
+    assert_eq!(&resolved.integration_message_id, receipt.message_id());
+    assert_eq!(
+      client
+        .resolve_history(document.id())
+        .await
+        .unwrap()
+        .integration_chain_data
+        .len(),
+      2
+    );
+  }
+
+  #[tokio::test]
+  async fn test_spam_and_invalid_messages() {
+    let tangle: Arc<MemoryTangle> = Arc::new(MemoryTangle::new());
+    let client: Client = client(&tangle).await;
+
+    let (document, keypair) = generate_document();
+    let did: &IotaDID = document.id();
+    let receipt: Receipt = client.publish_document(&document).await.unwrap();
+
+    // Arbitrary data.
+    let spam: MessageId = tangle.insert(did.tag(), b"spam", Some(100)).unwrap().id().0;
+    // A root document of another DID.
+    let (other, _) = generate_document();
+    let data: Vec<u8> = pack_did_message(&other, DIDMessageEncoding::Json).unwrap();
+    let foreign: MessageId = tangle.insert(did.tag(), &data, Some(0)).unwrap().id().0;
+    // A root document with an invalid signature.
+    let forged: IotaDocument = add_service(&document, "forged");
+    let forged: MessageId = insert_document(&tangle, &forged, Some(0));
+    // A valid root document that is not referenced by a milestone.
+    let mut unconfirmed: IotaDocument = add_service(&document, "unconfirmed");
+    sign(&mut unconfirmed, &keypair);
+    let unconfirmed: MessageId = insert_document(&tangle, &unconfirmed, None);
+
+    let resolved: ResolvedIotaDocument = client.read_document(did).await.unwrap();
+    assert_eq!(resolved.document, document);
+
+    // Any backend can be used to read the history.
+    let history: DocumentHistory = DocumentHistory::read(tangle.as_ref(), did).await.unwrap();
+    assert_eq!(history.integration_chain_data.len(), 1);
+    assert_eq!(
+      &history.integration_chain_data[0].integration_message_id,
+      receipt.message_id()
+    );
+    assert_eq!(history.integration_chain_spam.len(), 4);
+    for message_id in [spam, foreign, forged, unconfirmed] {
+      assert!(history.integration_chain_spam.contains(&message_id));
+    }
+  }
+
+  #[tokio::test]
+  async fn test_milestone_ordering() {
+    let tangle: Arc<MemoryTangle> = Arc::new(MemoryTangle::new());
+    let client: Client = client(&tangle).await;
+
+    // Two conflicting root documents; the one referenced by the oldest milestone wins.
+    let (first, keypair) = generate_document();
+    let mut second: IotaDocument = add_service(&first, "linked-domain");
+    sign(&mut second, &keypair);
+    let first_id: MessageId = insert_document(&tangle, &first, Some(2));
+    let second_id: MessageId = insert_document(&tangle, &second, Some(1));
+
+    let resolved: ResolvedIotaDocument = client.read_document(first.id()).await.unwrap();
+    assert_eq!(resolved.integration_message_id, second_id);
+
+    assert!(tangle.set_milestone_index(&second_id, None));
+    let resolved: ResolvedIotaDocument = client.read_document(first.id()).await.unwrap();
+    assert_eq!(resolved.integration_message_id, first_id);
+    assert!(!tangle.set_milestone_index(&MessageId::null(), None));
+
+    // Messages not referenced by a milestone are never included.
+    assert!(tangle.retry_until_included(&second_id, None, None).await.is_err());
+    assert!(tangle.retry_until_included(&first_id, None, None).await.is_ok());
+  }
+}
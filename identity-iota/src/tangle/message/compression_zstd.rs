@@ -0,0 +1,81 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::io::Read;
+
+use crate::error::Error;
+use crate::error::Result;
+
+#[cfg(not(target_arch = "wasm32"))]
+const LEVEL: i32 = 19; // compression level
+
+/// Upper bound on the size of decompressed message data. A Tangle message payload is at most
+/// 32 KiB, so larger output is far beyond any legitimate DID message and likely a decompression bomb.
+const MAX_DECOMPRESSED_SIZE: u64 = 1024 * 1024;
+
+// The `zstd` crate binds to the C library, which cannot be built for WebAssembly, so the
+// pure-Rust `ruzstd` encoder is used there instead. Both produce standard Zstandard frames.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn compress_zstd<T: AsRef<[u8]>>(input: T) -> Result<Vec<u8>> {
+  zstd::stream::encode_all(input.as_ref(), LEVEL).map_err(|_| Error::CompressionError)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn compress_zstd<T: AsRef<[u8]>>(input: T) -> Result<Vec<u8>> {
+  Ok(ruzstd::encoding::compress_to_vec(
+    input.as_ref(),
+    ruzstd::encoding::CompressionLevel::Fastest,
+  ))
+}
+
+pub(crate) fn decompress_zstd<T: AsRef<[u8]> + ?Sized>(input: &T) -> Result<Vec<u8>> {
+  let decoder = ruzstd::decoding::StreamingDecoder::new(input.as_ref()).map_err(|_| Error::CompressionError)?;
+  let mut buf = Vec::new();
+  // Read one byte past the limit to detect oversized output without decompressing all of it.
+  decoder
+    .take(MAX_DECOMPRESSED_SIZE + 1)
+    .read_to_end(&mut buf)
+    .map_err(|_| Error::CompressionError)?;
+  if buf.len() as u64 > MAX_DECOMPRESSED_SIZE {
+    return Err(Error::CompressionError);
+  }
+  Ok(buf)
+}
+
+#[cfg(test)]
+mod test {
+  use identity_core::crypto::KeyPair;
+  use identity_core::crypto::KeyType;
+  use identity_iota_core::document::IotaDocument;
+
+  use super::*;
+
+  #[test]
+  fn test_zstd() {
+    let keypair: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
+    let mut document: IotaDocument = IotaDocument::new(&keypair).unwrap();
+    document
+      .sign_self(
+        keypair.private(),
+        document.default_signing_method().unwrap().id().clone(),
+      )
+      .unwrap();
+
+    let data: Vec<u8> = serde_cbor::to_vec(&document).unwrap();
+    let compressed: Vec<u8> = compress_zstd(&data).unwrap();
+    let decompressed: Vec<u8> = decompress_zstd(&compressed).unwrap();
+
+    assert_eq!(decompressed, data);
+  }
+
+  #[test]
+  fn test_zstd_decompressed_size_limit() {
+    let data: Vec<u8> = vec![0; MAX_DECOMPRESSED_SIZE as usize];
+    let compressed: Vec<u8> = compress_zstd(&data).unwrap();
+    assert_eq!(decompress_zstd(&compressed).unwrap().len(), data.len());
+
+    let data: Vec<u8> = vec![0; MAX_DECOMPRESSED_SIZE as usize + 1];
+    let compressed: Vec<u8> = compress_zstd(&data).unwrap();
+    assert!(matches!(decompress_zstd(&compressed), Err(Error::CompressionError)));
+  }
+}
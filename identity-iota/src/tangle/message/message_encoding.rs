@@ -8,8 +8,14 @@ use crate::Error;
 /// Indicates the encoding and compression of a DID Message.
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, num_derive::FromPrimitive)]
 pub enum DIDMessageEncoding {
+  /// Plaintext JSON.
   Json = 0,
+  /// JSON compressed with Brotli.
   JsonBrotli = 1,
+  /// Binary CBOR.
+  Cbor = 2,
+  /// CBOR compressed with Zstandard.
+  CborZstd = 3,
 }
 
 impl TryFrom<u8> for DIDMessageEncoding {
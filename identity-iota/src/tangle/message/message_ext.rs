@@ -10,10 +10,14 @@ use identity_iota_core::tangle::Message;
 use identity_iota_core::tangle::MessageId;
 use iota_client::bee_message::payload::transaction::Essence;
 use iota_client::bee_message::payload::Payload;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 
 use crate::document::ResolvedIotaDocument;
+use crate::error::Error;
 use crate::error::Result;
 use crate::tangle::message::compression_brotli;
+use crate::tangle::message::compression_zstd;
 use crate::tangle::DIDMessageEncoding;
 use crate::tangle::DIDMessageVersion;
 use crate::tangle::TangleRef;
@@ -21,10 +25,8 @@ use crate::tangle::TangleRef;
 /// Magic bytes used to mark DID messages.
 const DID_MESSAGE_MARKER: &[u8] = b"DID";
 
-fn parse_message<T: FromJson + TangleRef>(message: &Message, did: &IotaDID) -> Option<T> {
-  let message_id: MessageId = message.id().0;
-  let payload: Option<&Payload> = message.payload().as_ref();
-  let resource: T = parse_payload(message_id, payload)?;
+fn parse_message<T: DeserializeOwned + TangleRef>(message: &Message, did: &IotaDID) -> Option<T> {
+  let resource: T = try_parse_message(message)?;
 
   if did.authority() != resource.did().authority() {
     return None;
@@ -33,7 +35,14 @@ fn parse_message<T: FromJson + TangleRef>(message: &Message, did: &IotaDID) -> O
   Some(resource)
 }
 
-fn parse_payload<T: FromJson + TangleRef>(message_id: MessageId, payload: Option<&Payload>) -> Option<T> {
+/// Decodes the DID message contained in the payload of the `message`, regardless of its DID.
+pub(crate) fn try_parse_message<T: DeserializeOwned + TangleRef>(message: &Message) -> Option<T> {
+  let message_id: MessageId = message.id().0;
+  let payload: Option<&Payload> = message.payload().as_ref();
+  parse_payload(message_id, payload)
+}
+
+fn parse_payload<T: DeserializeOwned + TangleRef>(message_id: MessageId, payload: Option<&Payload>) -> Option<T> {
   match payload {
     Some(Payload::Indexation(indexation)) => parse_data(message_id, indexation.data()),
     Some(Payload::Transaction(transaction)) => match transaction.essence() {
@@ -47,7 +56,7 @@ fn parse_payload<T: FromJson + TangleRef>(message_id: MessageId, payload: Option
 }
 
 // TODO: allow this to return errors?
-fn parse_data<T: FromJson + TangleRef>(message_id: MessageId, data: &[u8]) -> Option<T> {
+fn parse_data<T: DeserializeOwned + TangleRef>(message_id: MessageId, data: &[u8]) -> Option<T> {
   // Check version.
   let version: DIDMessageVersion = DIDMessageVersion::try_from(*data.get(0)?).ok()?;
   if version != DIDMessageVersion::V1 {
@@ -64,20 +73,25 @@ fn parse_data<T: FromJson + TangleRef>(message_id: MessageId, data: &[u8]) -> Op
   let encoding: DIDMessageEncoding = DIDMessageEncoding::try_from(*data.get(4)?).ok()?;
   let inner: &[u8] = data.get(5..)?;
   let mut resource: T = match encoding {
-    DIDMessageEncoding::Json => T::from_json_slice(inner),
-    DIDMessageEncoding::JsonBrotli => T::from_json_slice(&compression_brotli::decompress_brotli(inner).ok()?),
-  }
-  .ok()?;
+    DIDMessageEncoding::Json => T::from_json_slice(inner).ok()?,
+    DIDMessageEncoding::JsonBrotli => T::from_json_slice(&compression_brotli::decompress_brotli(inner).ok()?).ok()?,
+    DIDMessageEncoding::Cbor => serde_cbor::from_slice(inner).ok()?,
+    DIDMessageEncoding::CborZstd => serde_cbor::from_slice(&compression_zstd::decompress_zstd(inner).ok()?).ok()?,
+  };
   resource.set_message_id(message_id);
   Some(resource)
 }
 
 /// Encodes the data and prepends the current message version and encoding flags to it.
-pub(crate) fn pack_did_message<T: ToJson>(data: &T, encoding: DIDMessageEncoding) -> Result<Vec<u8>> {
+pub(crate) fn pack_did_message<T: Serialize>(data: &T, encoding: DIDMessageEncoding) -> Result<Vec<u8>> {
   // Encode data.
   let encoded_message_data: Vec<u8> = match encoding {
     DIDMessageEncoding::Json => data.to_json_vec()?,
     DIDMessageEncoding::JsonBrotli => compression_brotli::compress_brotli(&data.to_json()?)?,
+    DIDMessageEncoding::Cbor => serde_cbor::to_vec(data).map_err(Error::EncodeCBOR)?,
+    DIDMessageEncoding::CborZstd => {
+      compression_zstd::compress_zstd(&serde_cbor::to_vec(data).map_err(Error::EncodeCBOR)?)?
+    }
   };
 
   // Prepend flags.
@@ -150,6 +164,13 @@ mod test {
 
   use super::*;
 
+  const ENCODINGS: [DIDMessageEncoding; 4] = [
+    DIDMessageEncoding::Json,
+    DIDMessageEncoding::JsonBrotli,
+    DIDMessageEncoding::Cbor,
+    DIDMessageEncoding::CborZstd,
+  ];
+
   #[test]
   fn test_pack_did_message() {
     let keypair: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
@@ -161,7 +182,7 @@ mod test {
       )
       .unwrap();
 
-    for encoding in [DIDMessageEncoding::Json, DIDMessageEncoding::JsonBrotli] {
+    for encoding in ENCODINGS {
       let encoded: Vec<u8> = pack_did_message(&document, encoding).unwrap();
       assert_eq!(encoded[0], DIDMessageVersion::CURRENT as u8);
       assert_eq!(&encoded[1..4], DID_MESSAGE_MARKER);
@@ -204,7 +225,7 @@ mod test {
       )
       .unwrap();
 
-    for encoding in [DIDMessageEncoding::Json, DIDMessageEncoding::JsonBrotli] {
+    for encoding in ENCODINGS {
       let encoded: Vec<u8> = pack_did_message(&diff, encoding).unwrap();
       assert_eq!(encoded[0], DIDMessageVersion::CURRENT as u8);
       assert_eq!(&encoded[1..4], DID_MESSAGE_MARKER);
@@ -214,4 +235,78 @@ mod test {
       assert_eq!(decoded, diff);
     }
   }
+
+  #[test]
+  fn test_pack_did_message_sizes() {
+    let keypair: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
+
+    for count in [0, 1, 5, 20] {
+      let mut document: IotaDocument = IotaDocument::new(&keypair).unwrap();
+      for index in 0..count {
+        let method_keypair: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
+        document
+          .insert_method(
+            IotaVerificationMethod::new(
+              document.id().clone(),
+              KeyType::Ed25519,
+              method_keypair.public(),
+              &format!("key-{}", index),
+            )
+            .unwrap(),
+            MethodScope::authentication(),
+          )
+          .unwrap();
+        assert!(document.insert_service(
+          ServiceBuilder::default()
+            .id(
+              document
+                .id()
+                .to_url()
+                .join(format!("#linked-domain-{}", index))
+                .unwrap()
+            )
+            .service_endpoint(ServiceEndpoint::One(
+              Url::parse(format!("https://example-{}.com/", index)).unwrap()
+            ))
+            .type_("LinkedDomains")
+            .build()
+            .unwrap()
+        ));
+      }
+      document
+        .sign_self(
+          keypair.private(),
+          document.default_signing_method().unwrap().id().clone(),
+        )
+        .unwrap();
+
+      let size = |encoding: DIDMessageEncoding| -> usize {
+        let encoded: Vec<u8> = pack_did_message(&document, encoding).unwrap();
+        let decoded: ResolvedIotaDocument = parse_data(MessageId::null(), &encoded).unwrap();
+        assert_eq!(decoded.document, document);
+        assert!(decoded.document.verify_document(&document).is_ok());
+        encoded.len()
+      };
+      let json: usize = size(DIDMessageEncoding::Json);
+      let json_brotli: usize = size(DIDMessageEncoding::JsonBrotli);
+      let cbor: usize = size(DIDMessageEncoding::Cbor);
+      let cbor_zstd: usize = size(DIDMessageEncoding::CborZstd);
+
+      assert!(cbor < json, "methods: {}, json: {}, cbor: {}", count, json, cbor);
+      assert!(
+        json_brotli < json,
+        "methods: {}, json: {}, json+brotli: {}",
+        count,
+        json,
+        json_brotli
+      );
+      assert!(
+        cbor_zstd < cbor,
+        "methods: {}, cbor: {}, cbor+zstd: {}",
+        count,
+        cbor,
+        cbor_zstd
+      );
+    }
+  }
 }
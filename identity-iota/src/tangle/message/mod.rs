@@ -3,12 +3,14 @@
 
 pub use self::message_encoding::DIDMessageEncoding;
 pub(crate) use self::message_ext::pack_did_message;
+pub(crate) use self::message_ext::try_parse_message;
 pub use self::message_ext::MessageExt;
 pub use self::message_ext::TryFromMessage;
 pub use self::message_index::MessageIndex;
 pub use self::message_version::DIDMessageVersion;
 
 mod compression_brotli;
+mod compression_zstd;
 mod message_encoding;
 mod message_ext;
 mod message_index;
@@ -1,10 +1,14 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub use self::backend::TangleBackend;
+pub use self::cached_resolver::CachedResolver;
 pub use self::client::Client;
 pub use self::client_builder::ClientBuilder;
 pub use self::explorer::ExplorerUrl;
+pub use self::memory_tangle::MemoryTangle;
 pub(crate) use self::message::pack_did_message;
+pub(crate) use self::message::try_parse_message;
 pub use self::message::DIDMessageEncoding;
 pub use self::message::DIDMessageVersion;
 pub use self::message::MessageExt;
@@ -14,15 +18,21 @@ pub use self::publish::PublishType;
 pub use self::receipt::Receipt;
 pub use self::resolver::Resolver;
 pub use self::resolver::ResolverBuilder;
+pub use self::subscription::DocumentSubscription;
+pub use self::subscription::TangleSubscriber;
 pub use self::traits::SharedPtr;
 pub use self::traits::TangleRef;
 pub use self::traits::TangleResolve;
 
+mod backend;
+mod cached_resolver;
 mod client;
 mod client_builder;
 mod explorer;
+mod memory_tangle;
 mod message;
 mod publish;
 mod receipt;
 mod resolver;
+mod subscription;
 mod traits;
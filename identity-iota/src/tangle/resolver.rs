@@ -1,13 +1,22 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use core::fmt::Debug;
+use core::fmt::Formatter;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use futures::stream::StreamExt;
 use identity_core::common::Url;
 use identity_credential::credential::Credential;
 use identity_credential::presentation::Presentation;
+use identity_did::did::CoreDID;
+use identity_did::did::DID;
+use identity_did::document::CoreDocument;
+use identity_did::resolution::InputMetadata;
+use identity_did::resolution::MetaDocument;
+use identity_did::resolution::ResolverMethod;
 use identity_iota_core::did::IotaDID;
 use identity_iota_core::diff::DiffMessage;
 use identity_iota_core::tangle::NetworkName;
@@ -15,9 +24,12 @@ use serde::Serialize;
 
 use crate::chain::ChainHistory;
 use crate::chain::DocumentHistory;
+use crate::chain::DocumentVersion;
 use crate::credential::FailFast;
 use crate::credential::PresentationValidationOptions;
 use crate::credential::PresentationValidator;
+use crate::credential::SignerContext;
+use crate::credential::ValidatorDocument;
 use crate::document::ResolvedIotaDocument;
 use crate::error::Error;
 use crate::error::Result;
@@ -26,17 +38,22 @@ use crate::tangle::ClientBuilder;
 use crate::tangle::SharedPtr;
 use crate::tangle::TangleResolve;
 
+type BoxedResolverMethod = Box<dyn ResolverMethod>;
+
 /// A `Resolver` supports resolving DID Documents across different Tangle networks using
 /// multiple [`Clients`][Client].
 ///
+/// DIDs of other DID methods can be resolved as [`CoreDocuments`](CoreDocument) by registering a
+/// [`ResolverMethod`] for each method with [`ResolverBuilder::method`].
+///
 /// Also provides convenience functions for resolving DID Documents associated with
 /// verifiable [`Credentials`][Credential] and [`Presentations`][Presentation].
-#[derive(Debug)]
 pub struct Resolver<C = Arc<Client>>
 where
   C: SharedPtr<Client>,
 {
   client_map: HashMap<NetworkName, C>,
+  method_map: HashMap<String, BoxedResolverMethod>,
 }
 
 impl<C> Resolver<C>
@@ -52,7 +69,10 @@ where
 
     let mut client_map: HashMap<NetworkName, C> = HashMap::new();
     client_map.insert(client.network.name(), C::from(client));
-    Ok(Self { client_map })
+    Ok(Self {
+      client_map,
+      method_map: HashMap::new(),
+    })
   }
 
   /// Returns a new [`ResolverBuilder`] with no configured [`Clients`](Client).
@@ -65,8 +85,16 @@ where
     self.client_map.get(network_name)
   }
 
+  /// Returns the [`ResolverMethod`] registered for the given DID method name if one exists.
+  pub fn get_method(&self, method_name: &str) -> Option<&dyn ResolverMethod> {
+    self
+      .method_map
+      .get(method_name)
+      .map(|method| method.as_ref() as &dyn ResolverMethod)
+  }
+
   /// Returns the [`Client`] corresponding to the [`NetworkName`] on the given ['IotaDID'].
-  fn get_client_for_did(&self, did: &IotaDID) -> Result<&C> {
+  pub(crate) fn get_client_for_did(&self, did: &IotaDID) -> Result<&C> {
     self.get_client(&did.network()?.name()).ok_or_else(|| {
       Error::DIDNotFound(format!(
         "DID network '{}' does not match any resolver client network",
@@ -81,12 +109,74 @@ where
     client.read_document(did).await
   }
 
+  /// Fetches the DID Documents of the given [`IotaDIDs`](IotaDID) concurrently, resolving at most
+  /// `limit` DIDs at a time. A `limit` of `0` is treated as `1`, resolving the DIDs one at a time.
+  ///
+  /// Duplicate DIDs are only resolved once. The result of each unique DID is returned separately,
+  /// so a DID that fails to resolve does not affect the others.
+  pub async fn resolve_many<'a, I>(&self, dids: I, limit: usize) -> HashMap<IotaDID, Result<ResolvedIotaDocument>>
+  where
+    I: IntoIterator<Item = &'a IotaDID>,
+  {
+    let dids: HashSet<&IotaDID> = dids.into_iter().collect();
+
+    futures::stream::iter(dids)
+      .map(|did| async move { (did.clone(), self.resolve(did).await) })
+      .buffer_unordered(limit.max(1))
+      .collect()
+      .await
+  }
+
+  /// Fetches the DID Document of the given [`CoreDID`] of any DID method.
+  ///
+  /// The DID is resolved with the [`ResolverMethod`] registered for its DID method. DIDs of the `iota`
+  /// method are otherwise resolved with the configured [`Clients`](Client).
+  ///
+  /// # Errors
+  ///
+  /// Errors if the DID method is not supported by the resolver or DID resolution fails.
+  pub async fn resolve_document(&self, did: &CoreDID) -> Result<CoreDocument> {
+    if let Some(method) = self.method_map.get(did.method()) {
+      if !method.is_supported(did) {
+        return Err(Error::DIDNotFound(format!(
+          "DID '{}' is not supported by the resolver for the '{}' method",
+          did,
+          did.method()
+        )));
+      }
+
+      return method
+        .read(did, InputMetadata::new())
+        .await?
+        .map(|document: MetaDocument| document.data)
+        .ok_or_else(|| Error::DIDNotFound(format!("DID Document of '{}' not found", did)));
+    }
+
+    if did.method() == IotaDID::METHOD {
+      let did: IotaDID = IotaDID::try_from_core(did.clone())?;
+      return self.resolve(&did).await.map(Into::into);
+    }
+
+    Err(Error::DIDNotFound(format!(
+      "DID method '{}' does not match any resolver method",
+      did.method()
+    )))
+  }
+
   /// Fetches the [`DocumentHistory`] of the given [`IotaDID`].
   pub async fn resolve_history(&self, did: &IotaDID) -> Result<DocumentHistory> {
     let client: &Client = self.get_client_for_did(did)?.deref();
     client.resolve_history(did).await
   }
 
+  /// Fetches the state of the DID Document of the given [`IotaDID`] at a historical [`DocumentVersion`].
+  ///
+  /// See [`Client::resolve_version`].
+  pub async fn resolve_version(&self, did: &IotaDID, version: &DocumentVersion) -> Result<ResolvedIotaDocument> {
+    let client: &Client = self.get_client_for_did(did)?.deref();
+    client.resolve_version(did, version).await
+  }
+
   /// Fetches the [`ChainHistory`] of a diff chain starting from an [`IotaDocument`] on the
   /// integration chain.
   ///
@@ -165,6 +255,62 @@ where
     self.resolve(&holder).await
   }
 
+  /// Fetches the DID Document of the issuer on a [`Credential`] of any DID method.
+  ///
+  /// See [`Resolver::resolve_document`].
+  ///
+  /// # Errors
+  ///
+  /// Errors if the issuer URL is not a valid DID or DID resolution fails.
+  pub async fn resolve_credential_issuer_document<U: Serialize>(
+    &self,
+    credential: &Credential<U>,
+  ) -> Result<CoreDocument> {
+    let issuer: CoreDID = parse_signer_did(credential.issuer.url(), SignerContext::Issuer)?;
+    self.resolve_document(&issuer).await
+  }
+
+  /// Fetches the DID Documents of all [`Credential`] issuers contained in a [`Presentation`], which may use any
+  /// DID method. Issuer documents are returned in arbitrary order.
+  ///
+  /// See [`Resolver::resolve_document`].
+  ///
+  /// # Errors
+  ///
+  /// Errors if any issuer URL is not a valid DID or DID resolution fails.
+  pub async fn resolve_presentation_issuer_documents<U, V: Serialize>(
+    &self,
+    presentation: &Presentation<U, V>,
+  ) -> Result<Vec<CoreDocument>> {
+    // Extract unique issuers.
+    let issuers: HashSet<CoreDID> = presentation
+      .verifiable_credential
+      .iter()
+      .map(|credential| parse_signer_did(credential.issuer.url(), SignerContext::Issuer))
+      .collect::<Result<_>>()?;
+
+    // Resolve issuers concurrently.
+    futures::future::try_join_all(issuers.iter().map(|issuer| self.resolve_document(issuer))).await
+  }
+
+  /// Fetches the DID Document of the holder of a [`Presentation`], which may use any DID method.
+  ///
+  /// See [`Resolver::resolve_document`].
+  ///
+  /// # Errors
+  ///
+  /// Errors if the holder URL is missing, is not a valid DID, or DID resolution fails.
+  pub async fn resolve_presentation_holder_document<U, V>(
+    &self,
+    presentation: &Presentation<U, V>,
+  ) -> Result<CoreDocument> {
+    let holder_url: &Url = presentation.holder.as_ref().ok_or(Error::IsolatedValidationError(
+      crate::credential::ValidationError::MissingPresentationHolder,
+    ))?;
+    let holder: CoreDID = parse_signer_did(holder_url, SignerContext::Holder)?;
+    self.resolve_document(&holder).await
+  }
+
   /// Verifies a [`Presentation`].
   ///
   /// # Important
@@ -172,39 +318,41 @@ where
   /// get validated and what is expected of the optional arguments `holder` and `issuer`.
   ///
   /// # Resolution
-  /// The DID Documents for the `holder` and `issuers` are optionally resolved if not given.
+  /// The DID Documents for the `holder` and `issuers` are optionally resolved if not given, in which case
+  /// the holder and issuers may use any DID method supported by the resolver.
   /// If you already have up-to-date versions of these DID Documents, you may want
   /// to use [`PresentationValidator::validate`].
-  /// See also [`Resolver::resolve_presentation_issuers`] and [`Resolver::resolve_presentation_holder`].
+  /// See also [`Resolver::resolve_presentation_issuer_documents`] and
+  /// [`Resolver::resolve_presentation_holder_document`].
   ///
   /// # Errors
   /// Errors from resolving the holder and issuer DID Documents, if not provided, will be returned immediately.
   /// Otherwise, errors from validating the presentation and its credentials will be returned
   /// according to the `fail_fast` parameter.
-  pub async fn verify_presentation<U: Serialize, V: Serialize>(
+  pub async fn verify_presentation<U: Serialize, V: Serialize, H: ValidatorDocument, I: ValidatorDocument>(
     &self,
     presentation: &Presentation<U, V>,
     options: &PresentationValidationOptions,
     fail_fast: FailFast,
-    holder: Option<&ResolvedIotaDocument>,
-    issuers: Option<&[ResolvedIotaDocument]>,
+    holder: Option<&H>,
+    issuers: Option<&[I]>,
   ) -> Result<()> {
     match (holder, issuers) {
       (Some(holder), Some(issuers)) => {
         PresentationValidator::validate(presentation, holder, issuers, options, fail_fast)
       }
       (Some(holder), None) => {
-        let issuers: Vec<ResolvedIotaDocument> = self.resolve_presentation_issuers(presentation).await?;
+        let issuers: Vec<CoreDocument> = self.resolve_presentation_issuer_documents(presentation).await?;
         PresentationValidator::validate(presentation, holder, &issuers, options, fail_fast)
       }
       (None, Some(issuers)) => {
-        let holder: ResolvedIotaDocument = self.resolve_presentation_holder(presentation).await?;
+        let holder: CoreDocument = self.resolve_presentation_holder_document(presentation).await?;
         PresentationValidator::validate(presentation, &holder, issuers, options, fail_fast)
       }
       (None, None) => {
-        let (holder, issuers): (ResolvedIotaDocument, Vec<ResolvedIotaDocument>) = futures::future::try_join(
-          self.resolve_presentation_holder(presentation),
-          self.resolve_presentation_issuers(presentation),
+        let (holder, issuers): (CoreDocument, Vec<CoreDocument>) = futures::future::try_join(
+          self.resolve_presentation_holder_document(presentation),
+          self.resolve_presentation_issuer_documents(presentation),
         )
         .await?;
         PresentationValidator::validate(presentation, &holder, &issuers, options, fail_fast)
@@ -214,13 +362,37 @@ where
   }
 }
 
-/// Builder for configuring [`Clients`][Client] when constructing a [`Resolver`].
+impl<C> Debug for Resolver<C>
+where
+  C: SharedPtr<Client> + Debug,
+{
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("Resolver")
+      .field("client_map", &self.client_map)
+      .field("method_map", &self.method_map.keys().collect::<Vec<_>>())
+      .finish()
+  }
+}
+
+/// Parses the URL of the signer of a credential or presentation as a [`CoreDID`].
+fn parse_signer_did(url: &Url, signer_ctx: SignerContext) -> Result<CoreDID> {
+  CoreDID::parse(url.as_str()).map_err(|error| {
+    Error::IsolatedValidationError(crate::credential::ValidationError::SignerUrl {
+      signer_ctx,
+      source: error.into(),
+    })
+  })
+}
+
+/// Builder for configuring [`Clients`][Client] and [`ResolverMethods`](ResolverMethod) when constructing a
+/// [`Resolver`].
 #[derive(Default)]
 pub struct ResolverBuilder<C = Arc<Client>>
 where
   C: SharedPtr<Client>,
 {
   clients: HashMap<NetworkName, ClientOrBuilder<C>>,
+  methods: HashMap<String, BoxedResolverMethod>,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -237,6 +409,7 @@ where
   pub fn new() -> Self {
     Self {
       clients: Default::default(),
+      methods: Default::default(),
     }
   }
 
@@ -261,6 +434,20 @@ where
     self
   }
 
+  /// Inserts a [`ResolverMethod`] used to resolve DIDs of the DID method `method_name`, e.g. `"key"` or `"web"`.
+  ///
+  /// NOTE: replaces any previous [`ResolverMethod`] with the same method name. A [`ResolverMethod`] registered
+  /// for the `iota` method takes precedence over the configured [`Clients`](Client) in
+  /// [`Resolver::resolve_document`].
+  #[must_use]
+  pub fn method<M>(mut self, method_name: impl Into<String>, method: M) -> Self
+  where
+    M: ResolverMethod + 'static,
+  {
+    self.methods.insert(method_name.into(), Box::new(method));
+    self
+  }
+
   /// Constructs a new [`Resolver`] based on the builder configuration.
   pub async fn build(self) -> Result<Resolver<C>> {
     let mut client_map: HashMap<NetworkName, C> = HashMap::new();
@@ -272,7 +459,10 @@ where
       client_map.insert(network_name, client);
     }
 
-    Ok(Resolver { client_map })
+    Ok(Resolver {
+      client_map,
+      method_map: self.methods,
+    })
   }
 }
 
@@ -282,3 +472,185 @@ impl TangleResolve for Resolver {
     self.resolve(did).await
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use core::sync::atomic::Ordering;
+  use identity_core::common::Timestamp;
+  use identity_core::convert::FromJson;
+  use identity_core::crypto::KeyPair;
+  use identity_core::crypto::KeyType;
+  use identity_core::json;
+  use identity_credential::credential::CredentialBuilder;
+  use identity_credential::credential::Subject;
+  use identity_credential::presentation::PresentationBuilder;
+  use identity_did::methods::key::DIDKey;
+  use identity_did::methods::key::KeyResolver;
+  use identity_iota_core::document::IotaDocument;
+
+  use super::*;
+  use crate::test_utils::generate_document;
+  use crate::test_utils::CountingTangle;
+  use crate::test_utils::Identity;
+
+  fn did_key_document() -> (CoreDocument, KeyPair) {
+    let keypair: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
+    let document: CoreDocument = DIDKey::new(KeyType::Ed25519, keypair.public())
+      .unwrap()
+      .document()
+      .unwrap();
+    (document, keypair)
+  }
+
+  #[test]
+  fn test_resolver_send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Resolver>();
+    assert_send_sync::<ResolverBuilder>();
+  }
+
+  #[tokio::test]
+  async fn test_verify_presentation_send() {
+    fn assert_send<T: Send>(_: T) {}
+
+    let resolver: Resolver = Resolver::builder()
+      .method(DIDKey::METHOD, KeyResolver::new())
+      .build()
+      .await
+      .unwrap();
+    let presentation: Presentation = PresentationBuilder::default().build().unwrap();
+    let options: PresentationValidationOptions = PresentationValidationOptions::default();
+
+    assert_send(resolver.verify_presentation(
+      &presentation,
+      &options,
+      FailFast::FirstError,
+      None::<&CoreDocument>,
+      None::<&[CoreDocument]>,
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_resolve_document_dispatch() {
+    let resolver: Resolver = Resolver::builder()
+      .method(DIDKey::METHOD, KeyResolver::new())
+      .build()
+      .await
+      .unwrap();
+    assert!(resolver.get_method(DIDKey::METHOD).is_some());
+
+    let (document, _) = did_key_document();
+    assert_eq!(resolver.resolve_document(document.id()).await.unwrap(), document);
+
+    // No resolver method is registered for did:web.
+    let did: CoreDID = CoreDID::parse("did:web:example.com").unwrap();
+    assert!(matches!(
+      resolver.resolve_document(&did).await,
+      Err(Error::DIDNotFound(_))
+    ));
+
+    // IOTA DIDs are dispatched to the clients, of which none are configured.
+    let did: CoreDID = generate_document().0.id().clone().into();
+    assert!(matches!(
+      resolver.resolve_document(&did).await,
+      Err(Error::DIDNotFound(_))
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_verify_presentation_did_key() {
+    let (issuer, issuer_key) = did_key_document();
+    let (holder, holder_key) = did_key_document();
+
+    let mut credential: Credential = CredentialBuilder::default()
+      .issuer(Url::parse(issuer.id().as_str()).unwrap())
+      .type_("UniversityDegreeCredential")
+      .subject(Subject::from_json_value(json!({ "id": holder.id().as_str() })).unwrap())
+      .issuance_date(Timestamp::parse("2020-01-01T00:00:00Z").unwrap())
+      .build()
+      .unwrap();
+    issuer
+      .signer(issuer_key.private())
+      .method(issuer.verification_method().head().unwrap().id())
+      .sign(&mut credential)
+      .unwrap();
+
+    let mut presentation: Presentation = PresentationBuilder::default()
+      .holder(Url::parse(holder.id().as_str()).unwrap())
+      .credential(credential)
+      .build()
+      .unwrap();
+    holder
+      .signer(holder_key.private())
+      .method(holder.verification_method().head().unwrap().id())
+      .sign(&mut presentation)
+      .unwrap();
+
+    let resolver: Resolver = Resolver::builder()
+      .method(DIDKey::METHOD, KeyResolver::new())
+      .build()
+      .await
+      .unwrap();
+    assert!(resolver
+      .verify_presentation(
+        &presentation,
+        &PresentationValidationOptions::default(),
+        FailFast::FirstError,
+        None::<&CoreDocument>,
+        None::<&[CoreDocument]>,
+      )
+      .await
+      .is_ok());
+
+    // The DID Documents cannot be resolved without a resolver method for did:key.
+    let resolver: Resolver = Resolver::builder().build().await.unwrap();
+    assert!(resolver
+      .verify_presentation(
+        &presentation,
+        &PresentationValidationOptions::default(),
+        FailFast::FirstError,
+        None::<&CoreDocument>,
+        None::<&[CoreDocument]>,
+      )
+      .await
+      .is_err());
+  }
+
+  #[tokio::test]
+  async fn test_resolve_many() {
+    let tangle: Arc<CountingTangle> = Arc::new(CountingTangle::default());
+    let client: Client = ClientBuilder::new().backend(Arc::clone(&tangle)).build().await.unwrap();
+
+    let mut documents: Vec<IotaDocument> = Vec::new();
+    for _ in 0..4 {
+      documents.push(Identity::publish(&client).await.document);
+    }
+    let missing: IotaDID = generate_document().0.id().clone();
+
+    let resolver: Resolver = Resolver::builder().client(Arc::new(client)).build().await.unwrap();
+
+    // Duplicate DIDs are resolved once and an unresolvable DID does not affect the others.
+    let dids: Vec<&IotaDID> = documents
+      .iter()
+      .map(IotaDocument::id)
+      .chain(documents.iter().map(IotaDocument::id))
+      .chain(core::iter::once(&missing))
+      .collect();
+    let results: HashMap<IotaDID, Result<ResolvedIotaDocument>> = resolver.resolve_many(dids.clone(), 2).await;
+    assert_eq!(results.len(), documents.len() + 1);
+    for document in documents.iter() {
+      assert_eq!(results[document.id()].as_ref().unwrap().document, *document);
+    }
+    assert!(results[&missing].is_err());
+
+    // Each resolution reads the integration and diff chains, which never exceeds the limit.
+    assert_eq!(tangle.max_active.load(Ordering::SeqCst), 2);
+
+    // A limit of zero resolves one DID at a time.
+    tangle.max_active.store(0, Ordering::SeqCst);
+    let results: HashMap<IotaDID, Result<ResolvedIotaDocument>> = resolver.resolve_many(dids, 0).await;
+    assert_eq!(results.len(), documents.len() + 1);
+    assert_eq!(tangle.max_active.load(Ordering::SeqCst), 1);
+  }
+}
@@ -0,0 +1,438 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::future::Either;
+use futures::stream::BoxStream;
+use futures::stream::Fuse;
+use futures::stream::FusedStream;
+use futures::stream::Stream;
+use futures::stream::StreamExt;
+use identity_iota_core::did::IotaDID;
+use identity_iota_core::diff::DiffMessage;
+use identity_iota_core::document::IotaDocument;
+use identity_iota_core::tangle::Message;
+use identity_iota_core::tangle::MessageId;
+
+use crate::chain::DocumentChain;
+use crate::document::ResolvedIotaDocument;
+use crate::error::Result;
+use crate::tangle::Client;
+use crate::tangle::MessageExt;
+
+/// A source of messages as they are published to the Tangle, such as the MQTT event API of a node.
+///
+/// [`DocumentSubscription`] watches the indexes of a DID Document through a [`TangleSubscriber`],
+/// which allows replacing the transport with an alternative implementation such as the in-memory
+/// [`MemoryTangle`](crate::tangle::MemoryTangle).
+///
+/// With the `subscription` feature, [`iota_client::Client`] subscribes through the MQTT event API of
+/// its nodes. This is unavailable on WebAssembly.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait TangleSubscriber: Debug + Send + Sync {
+  /// Returns a stream of the messages published to the given `index` from now on.
+  ///
+  /// The stream ends when the transport is closed.
+  async fn subscribe(&self, index: &str) -> Result<BoxStream<'static, Message>>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<T> TangleSubscriber for Arc<T>
+where
+  T: TangleSubscriber + ?Sized,
+{
+  async fn subscribe(&self, index: &str) -> Result<BoxStream<'static, Message>> {
+    (**self).subscribe(index).await
+  }
+}
+
+#[cfg(all(feature = "subscription", not(target_arch = "wasm32")))]
+mod mqtt {
+  use core::pin::Pin;
+  use core::task::Context;
+  use core::task::Poll;
+  use std::sync::Mutex;
+  use std::sync::PoisonError;
+
+  use async_trait::async_trait;
+  use bee_rest_api::types::dtos::MessageDto;
+  use futures::channel::mpsc;
+  use futures::channel::mpsc::Receiver;
+  use futures::channel::mpsc::Sender;
+  use futures::stream::BoxStream;
+  use futures::stream::Stream;
+  use futures::stream::StreamExt;
+  use identity_core::convert::FromJson;
+  use identity_iota_core::tangle::Message;
+  use iota_client::Client as IotaClient;
+  use iota_client::Topic;
+  use iota_client::TopicEvent;
+
+  use super::TangleSubscriber;
+  use crate::error::Result;
+
+  /// The maximum number of received messages buffered until the stream is polled.
+  const BUFFER_SIZE: usize = 256;
+
+  /// Messages received while [`BUFFER_SIZE`] messages are buffered are dropped, so a stream which
+  /// is not polled does not grow without bounds.
+  #[async_trait]
+  impl TangleSubscriber for IotaClient {
+    async fn subscribe(&self, index: &str) -> Result<BoxStream<'static, Message>> {
+      // Indexes are hex-encoded in the MQTT topics of a node.
+      let index: String = index.bytes().map(|byte| format!("{:02x}", byte)).collect();
+      let topic: Topic = Topic::new(format!("messages/indexation/{}", index))?;
+
+      // Every subscription keeps its own connection to the broker, closed when the stream is dropped.
+      let mut client: IotaClient = self.clone();
+      let (sender, receiver) = mpsc::channel(BUFFER_SIZE);
+      let sender: Mutex<Sender<Message>> = Mutex::new(sender);
+      client
+        .subscriber()
+        .with_topics(vec![topic])
+        .subscribe(move |event: &TopicEvent| {
+          // Events which are not valid messages are skipped.
+          let message: Option<Message> = MessageDto::from_json(&event.payload)
+            .ok()
+            .and_then(|dto| Message::try_from(&dto).ok());
+          if let Some(message) = message {
+            let _ = sender.lock().unwrap_or_else(PoisonError::into_inner).try_send(message);
+          }
+        })
+        .await?;
+
+      let stream: MqttStream = MqttStream {
+        _client: client,
+        receiver,
+      };
+      Ok(stream.boxed())
+    }
+  }
+
+  /// The messages received through the MQTT subscription of an [`IotaClient`], which is kept
+  /// alive for as long as the stream.
+  struct MqttStream {
+    _client: IotaClient,
+    receiver: Receiver<Message>,
+  }
+
+  impl Stream for MqttStream {
+    type Item = Message;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+      self.receiver.poll_next_unpin(cx)
+    }
+  }
+}
+
+/// Watches the integration index and the diff index of the latest integration message of a DID
+/// Document, yielding the updated [`ResolvedIotaDocument`] whenever a valid message continues its
+/// [`DocumentChain`].
+///
+/// Messages which do not continue the chain, such as spam, are ignored.
+///
+/// # Confirmation
+///
+/// Messages are validated as they are received, before they are referenced by a milestone. A
+/// yielded document is therefore unconfirmed: it may never be confirmed, or lose a conflict against
+/// another message continuing the chain which a milestone references first. Resolve the DID Document
+/// with [`Client::read_document`] once the message is confirmed before relying on an update.
+///
+/// A [`TangleSubscriber`] may drop messages, e.g. when its buffer is full, in which case later
+/// updates do not continue the chain and are ignored as well. Create a new [`DocumentSubscription`]
+/// to catch up with the Tangle.
+pub struct DocumentSubscription<'a, S: ?Sized> {
+  client: &'a Client,
+  subscriber: &'a S,
+  chain: DocumentChain,
+  integration: Fuse<BoxStream<'static, Message>>,
+  diff: Fuse<BoxStream<'static, Message>>,
+}
+
+impl<'a, S> DocumentSubscription<'a, S>
+where
+  S: TangleSubscriber + ?Sized,
+{
+  /// Subscribes to the DID Document identified by `did`, starting from its current state on the
+  /// Tangle.
+  pub async fn new(client: &'a Client, subscriber: &'a S, did: &IotaDID) -> Result<Self> {
+    // Subscribe before reading the chain so no message is missed in between.
+    let integration: BoxStream<'static, Message> = subscriber.subscribe(did.tag()).await?;
+    let mut chain: DocumentChain = client.read_document_chain(did).await?;
+    let diff: BoxStream<'static, Message> = Self::subscribe_diff(client, subscriber, &mut chain).await?;
+
+    Ok(Self {
+      client,
+      subscriber,
+      chain,
+      integration: integration.fuse(),
+      diff: diff.fuse(),
+    })
+  }
+
+  /// Returns the [`DocumentChain`] of the DID Document as of the last update.
+  pub fn chain(&self) -> &DocumentChain {
+    &self.chain
+  }
+
+  /// Returns the DID Document as of the last update.
+  pub fn current(&self) -> &ResolvedIotaDocument {
+    self.chain.current()
+  }
+
+  /// Waits for the next valid update of the DID Document and returns the updated document, which
+  /// may not be confirmed yet. See [Confirmation](DocumentSubscription#confirmation).
+  ///
+  /// Returns `None` once both the integration and the diff streams have ended. The diff stream is
+  /// replaced by a new subscription whenever an integration update is received.
+  pub async fn next_update(&mut self) -> Option<Result<ResolvedIotaDocument>> {
+    loop {
+      let did: IotaDID = self.chain.id().clone();
+
+      match self.next_message().await? {
+        Either::Left(message) => {
+          let document: ResolvedIotaDocument = match message.try_extract_document(&did) {
+            Some(document) => document,
+            None => continue,
+          };
+          if self.chain.try_push_integration(document).is_err() {
+            continue;
+          }
+
+          // Diffs only apply to the integration document they were published for.
+          return match Self::subscribe_diff(self.client, self.subscriber, &mut self.chain).await {
+            Ok(diff) => {
+              self.diff = diff.fuse();
+              Some(Ok(self.current().clone()))
+            }
+            Err(error) => Some(Err(error)),
+          };
+        }
+        Either::Right(message) => {
+          let diff: DiffMessage = match message.try_extract_diff(&did) {
+            Some(diff) => diff,
+            None => continue,
+          };
+          if self.chain.try_push_diff(diff).is_err() {
+            continue;
+          }
+
+          return Some(Ok(self.current().clone()));
+        }
+      }
+    }
+  }
+
+  /// Waits for the next message on either the integration (`Left`) or the diff (`Right`) stream,
+  /// continuing with the other stream if one of them ends.
+  async fn next_message(&mut self) -> Option<Either<Message, Message>> {
+    loop {
+      let message: Option<Either<Message, Message>> =
+        match (self.integration.is_terminated(), self.diff.is_terminated()) {
+          (true, true) => return None,
+          (false, true) => self.integration.next().await.map(Either::Left),
+          (true, false) => self.diff.next().await.map(Either::Right),
+          (false, false) => match futures::future::select(self.integration.next(), self.diff.next()).await {
+            Either::Left((message, _)) => message.map(Either::Left),
+            Either::Right((message, _)) => message.map(Either::Right),
+          },
+        };
+
+      // A stream which ended is terminated now and no longer polled.
+      if message.is_some() {
+        return message;
+      }
+    }
+  }
+
+  /// Converts the subscription into a [`Stream`] of updates of the DID Document.
+  pub fn into_stream(self) -> impl Stream<Item = Result<ResolvedIotaDocument>> + 'a
+  where
+    S: 'a,
+  {
+    futures::stream::unfold(self, |mut subscription| async move {
+      let update: Result<ResolvedIotaDocument> = subscription.next_update().await?;
+      Some((update, subscription))
+    })
+  }
+
+  /// Subscribes to the diff index of the latest integration message of the `chain`, appending the
+  /// messages published before the subscription started.
+  async fn subscribe_diff(
+    client: &Client,
+    subscriber: &S,
+    chain: &mut DocumentChain,
+  ) -> Result<BoxStream<'static, Message>> {
+    loop {
+      let integration_message_id: MessageId = *chain.integration_message_id();
+      let diff: BoxStream<'static, Message> = subscriber
+        .subscribe(&IotaDocument::diff_index(&integration_message_id)?)
+        .await?;
+
+      client.update_document_chain(chain).await?;
+
+      // Subscribe again if the integration chain advanced in the meantime.
+      if chain.integration_message_id() == &integration_message_id {
+        return Ok(diff);
+      }
+    }
+  }
+}
+
+impl<'a, S> Debug for DocumentSubscription<'a, S>
+where
+  S: Debug + ?Sized,
+{
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("DocumentSubscription")
+      .field("subscriber", &self.subscriber)
+      .field("chain", &self.chain)
+      .finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use futures::FutureExt;
+
+  use super::*;
+  use crate::tangle::ClientBuilder;
+  use crate::tangle::MemoryTangle;
+  use crate::test_utils::Identity;
+
+  async fn setup() -> (Arc<MemoryTangle>, Client) {
+    let tangle: Arc<MemoryTangle> = Arc::new(MemoryTangle::new());
+    let client: Client = ClientBuilder::new().backend(Arc::clone(&tangle)).build().await.unwrap();
+    (tangle, client)
+  }
+
+  #[tokio::test]
+  async fn test_subscription_yields_updates() {
+    let (tangle, client) = setup().await;
+    let mut identity: Identity = Identity::publish(&client).await;
+
+    let mut subscription: DocumentSubscription<'_, MemoryTangle> =
+      DocumentSubscription::new(&client, &*tangle, identity.document.id())
+        .await
+        .unwrap();
+    assert_eq!(subscription.current().document, identity.document);
+    assert!(subscription.next_update().now_or_never().is_none());
+
+    // Spam and messages of other DID Documents are ignored.
+    tangle.insert(identity.document.id().tag(), b"spam", Some(100)).unwrap();
+    let other: Identity = Identity::publish(&client).await;
+    client
+      .publish_json(identity.document.id().tag(), &other.document)
+      .await
+      .unwrap();
+    assert!(subscription.next_update().now_or_never().is_none());
+
+    let expected: IotaDocument = identity.publish_integration(&client, "integration").await;
+    let update: ResolvedIotaDocument = subscription.next_update().await.unwrap().unwrap();
+    assert_eq!(update.document, expected);
+    assert_eq!(update.integration_message_id, identity.message_id);
+
+    let expected: IotaDocument = identity.publish_diff(&client, "diff").await;
+    let update: ResolvedIotaDocument = subscription.next_update().await.unwrap().unwrap();
+    assert_eq!(update.document.service(), expected.service());
+    assert_eq!(subscription.chain().diff().len(), 1);
+    assert!(subscription.next_update().now_or_never().is_none());
+  }
+
+  #[tokio::test]
+  async fn test_subscription_starts_from_current_state() {
+    let (tangle, client) = setup().await;
+    let mut identity: Identity = Identity::publish(&client).await;
+    identity.publish_integration(&client, "integration").await;
+    let expected: IotaDocument = identity.publish_diff(&client, "diff").await;
+
+    let subscription: DocumentSubscription<'_, Arc<MemoryTangle>> =
+      DocumentSubscription::new(&client, &tangle, identity.document.id())
+        .await
+        .unwrap();
+    assert_eq!(subscription.current().document.service(), expected.service());
+    assert_eq!(subscription.chain().diff().len(), 1);
+
+    // The stream yields the updates published after subscribing.
+    let mut stream = Box::pin(subscription.into_stream());
+    assert!(stream.next().now_or_never().is_none());
+
+    let expected: IotaDocument = identity.publish_integration(&client, "rotated").await;
+    let update: ResolvedIotaDocument = stream.next().await.unwrap().unwrap();
+    assert_eq!(update.document, expected);
+  }
+
+  /// Subscribes through a [`MemoryTangle`], ending the streams of the `closed` indexes immediately.
+  #[derive(Debug)]
+  struct ClosingSubscriber {
+    tangle: Arc<MemoryTangle>,
+    closed: Vec<String>,
+  }
+
+  #[async_trait]
+  impl TangleSubscriber for ClosingSubscriber {
+    async fn subscribe(&self, index: &str) -> Result<BoxStream<'static, Message>> {
+      if self.closed.iter().any(|closed| closed == index) {
+        Ok(futures::stream::empty().boxed())
+      } else {
+        self.tangle.subscribe(index).await
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn test_subscription_continues_after_a_stream_ends() {
+    let (tangle, client) = setup().await;
+    let mut identity: Identity = Identity::publish(&client).await;
+    let diff_index: String = IotaDocument::diff_index(&identity.message_id).unwrap();
+
+    // Diffs are still received after the integration stream ended.
+    let subscriber: ClosingSubscriber = ClosingSubscriber {
+      tangle: Arc::clone(&tangle),
+      closed: vec![identity.document.id().tag().to_owned()],
+    };
+    let mut subscription: DocumentSubscription<'_, ClosingSubscriber> =
+      DocumentSubscription::new(&client, &subscriber, identity.document.id())
+        .await
+        .unwrap();
+    let expected: IotaDocument = identity.publish_diff(&client, "diff").await;
+    let update: ResolvedIotaDocument = subscription.next_update().await.unwrap().unwrap();
+    assert_eq!(update.document.service(), expected.service());
+
+    // Integrations are still received after the diff stream ended, renewing the diff stream.
+    let subscriber: ClosingSubscriber = ClosingSubscriber {
+      tangle: Arc::clone(&tangle),
+      closed: vec![diff_index],
+    };
+    let mut subscription: DocumentSubscription<'_, ClosingSubscriber> =
+      DocumentSubscription::new(&client, &subscriber, identity.document.id())
+        .await
+        .unwrap();
+    let expected: IotaDocument = identity.publish_integration(&client, "integration").await;
+    let update: ResolvedIotaDocument = subscription.next_update().await.unwrap().unwrap();
+    assert_eq!(update.document, expected);
+    let expected: IotaDocument = identity.publish_diff(&client, "renewed").await;
+    let update: ResolvedIotaDocument = subscription.next_update().await.unwrap().unwrap();
+    assert_eq!(update.document.service(), expected.service());
+
+    // The subscription ends once both streams ended.
+    let subscriber: ClosingSubscriber = ClosingSubscriber {
+      tangle: Arc::clone(&tangle),
+      closed: vec![
+        identity.document.id().tag().to_owned(),
+        IotaDocument::diff_index(&identity.message_id).unwrap(),
+      ],
+    };
+    let mut subscription: DocumentSubscription<'_, ClosingSubscriber> =
+      DocumentSubscription::new(&client, &subscriber, identity.document.id())
+        .await
+        .unwrap();
+    assert!(subscription.next_update().await.is_none());
+  }
+}
@@ -0,0 +1,181 @@
+// Copyright 2020-2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use async_trait::async_trait;
+use identity_core::common::Url;
+use identity_core::crypto::KeyPair;
+use identity_core::crypto::KeyType;
+use identity_did::did::DID;
+use identity_did::service::ServiceBuilder;
+use identity_did::service::ServiceEndpoint;
+use identity_iota_core::diff::DiffMessage;
+use identity_iota_core::document::IotaDocument;
+use identity_iota_core::tangle::Message;
+use identity_iota_core::tangle::MessageId;
+
+use crate::error::Result;
+use crate::tangle::Client;
+use crate::tangle::MemoryTangle;
+use crate::tangle::Receipt;
+use crate::tangle::TangleBackend;
+
+/// Creates a new DID Document signed with its default signing method.
+pub(crate) fn generate_document() -> (IotaDocument, KeyPair) {
+  let keypair: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
+  let mut document: IotaDocument = IotaDocument::new(&keypair).unwrap();
+  sign(&mut document, &keypair);
+  (document, keypair)
+}
+
+/// Signs the `document` with its default signing method.
+pub(crate) fn sign(document: &mut IotaDocument, keypair: &KeyPair) {
+  document
+    .sign_self(
+      keypair.private(),
+      document.default_signing_method().unwrap().id().clone(),
+    )
+    .unwrap();
+}
+
+/// Returns a copy of the `document` with an added `LinkedDomains` service with the given `fragment`.
+pub(crate) fn add_service(document: &IotaDocument, fragment: &str) -> IotaDocument {
+  let mut updated: IotaDocument = document.clone();
+  assert!(updated.insert_service(
+    ServiceBuilder::default()
+      .id(updated.id().to_url().join(format!("#{}", fragment)).unwrap())
+      .service_endpoint(ServiceEndpoint::One(Url::parse("https://example.com/").unwrap()))
+      .type_("LinkedDomains")
+      .build()
+      .unwrap()
+  ));
+  updated
+}
+
+/// A published DID Document along with its key and latest integration message id.
+pub(crate) struct Identity {
+  pub(crate) document: IotaDocument,
+  pub(crate) keypair: KeyPair,
+  pub(crate) message_id: MessageId,
+}
+
+impl Identity {
+  /// Generates a new DID Document and publishes it with the `client`.
+  pub(crate) async fn publish(client: &Client) -> Self {
+    let (document, keypair): (IotaDocument, KeyPair) = generate_document();
+    let receipt: Receipt = client.publish_document(&document).await.unwrap();
+
+    Self {
+      document,
+      keypair,
+      message_id: *receipt.message_id(),
+    }
+  }
+
+  /// Publishes an integration update adding a service with the given `fragment`, and returns the
+  /// updated DID Document.
+  pub(crate) async fn publish_integration(&mut self, client: &Client, fragment: &str) -> IotaDocument {
+    let mut updated: IotaDocument = add_service(&self.document, fragment);
+    updated.metadata.previous_message_id = self.message_id;
+    sign(&mut updated, &self.keypair);
+    let receipt: Receipt = client.publish_document(&updated).await.unwrap();
+    self.document = updated.clone();
+    self.message_id = *receipt.message_id();
+    updated
+  }
+
+  /// Publishes a diff update adding a service with the given `fragment`, and returns the updated
+  /// DID Document.
+  pub(crate) async fn publish_diff(&self, client: &Client, fragment: &str) -> IotaDocument {
+    let updated: IotaDocument = add_service(&self.document, fragment);
+    let diff: DiffMessage = self
+      .document
+      .diff(
+        &updated,
+        self.message_id,
+        self.keypair.private(),
+        self.document.default_signing_method().unwrap().id(),
+      )
+      .unwrap();
+    client.publish_diff(&self.message_id, &diff).await.unwrap();
+    updated
+  }
+}
+
+/// A [`MemoryTangle`] counting the number of index lookups and downloaded messages, and tracking
+/// the maximum number of concurrent index reads.
+#[derive(Debug, Default)]
+pub(crate) struct CountingTangle {
+  pub(crate) tangle: MemoryTangle,
+  pub(crate) reads: AtomicUsize,
+  pub(crate) downloads: AtomicUsize,
+  pub(crate) active: AtomicUsize,
+  pub(crate) max_active: AtomicUsize,
+}
+
+/// Yields to the executor once before completing.
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+  type Output = ();
+
+  fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+    if self.0 {
+      Poll::Ready(())
+    } else {
+      self.0 = true;
+      context.waker().wake_by_ref();
+      Poll::Pending
+    }
+  }
+}
+
+#[async_trait]
+impl TangleBackend for CountingTangle {
+  async fn publish_message(&self, index: &str, data: &[u8]) -> Result<Message> {
+    self.tangle.publish_message(index, data).await
+  }
+
+  async fn retry_until_included(
+    &self,
+    message_id: &MessageId,
+    interval: Option<u64>,
+    max_attempts: Option<u64>,
+  ) -> Result<Message> {
+    self
+      .tangle
+      .retry_until_included(message_id, interval, max_attempts)
+      .await
+  }
+
+  async fn read_message(&self, message_id: &MessageId) -> Result<Message> {
+    self.downloads.fetch_add(1, Ordering::SeqCst);
+    self.tangle.read_message(message_id).await
+  }
+
+  async fn read_messages(&self, index: &str) -> Result<Vec<Message>> {
+    self.reads.fetch_add(1, Ordering::SeqCst);
+    let active: usize = self.active.fetch_add(1, Ordering::SeqCst) + 1;
+    self.max_active.fetch_max(active, Ordering::SeqCst);
+    YieldNow(false).await;
+    self.active.fetch_sub(1, Ordering::SeqCst);
+    let messages: Vec<Message> = self.tangle.read_messages(index).await?;
+    self.downloads.fetch_add(messages.len(), Ordering::SeqCst);
+    Ok(messages)
+  }
+
+  async fn read_message_ids(&self, index: &str) -> Result<Vec<MessageId>> {
+    self.reads.fetch_add(1, Ordering::SeqCst);
+    self.tangle.read_message_ids(index).await
+  }
+
+  async fn milestone_index(&self, message_id: &MessageId) -> Result<Option<u32>> {
+    self.tangle.milestone_index(message_id).await
+  }
+}
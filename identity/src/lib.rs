@@ -64,7 +64,9 @@ pub mod did {
 
   pub use identity_did::did::*;
 
+  pub use identity_did::methods;
   pub use identity_did::resolution;
+  pub use identity_did::revocation;
   pub use identity_did::verifiable;
 }
 